@@ -0,0 +1,147 @@
+use crate::error::Error;
+use crate::{Document, Rect, UnoArgs};
+
+/// A comment of a document, see [Document::annotations]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Annotation {
+    /// The id of the comment, unique in the document, e.g. to reply to it
+    pub id: i32,
+    pub author: String,
+    pub text: String,
+    /// When the comment was written, e.g. `2023-05-04T10:20:30`,
+    /// empty when the document doesn't tell
+    pub date: String,
+    /// The anchor (in twips) of the comment: the commented text, cell or shape
+    pub rect: Rect,
+}
+
+impl Document {
+    /// Returns the comments of the document, with their replies, in document order.
+    ///
+    /// They are read from the values of `.uno:ViewAnnotations`. The anchor of comments
+    /// on text is the start of the commented text, the anchor of comments on cells is
+    /// the cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/comment.odt")?;
+    /// let doc = office.document_load(doc_url)?;
+    ///
+    /// for annotation in doc.annotations()? {
+    ///     println!("{}: {}", annotation.author, annotation.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn annotations(&self) -> Result<Vec<Annotation>, Error> {
+        let values = self
+            .get_command_values(".uno:ViewAnnotations")
+            .ok_or_else(|| {
+                Error::new("No comments were returned for .uno:ViewAnnotations".into())
+            })?;
+        let json: serde_json::Value = serde_json::from_str(&values)
+            .map_err(|ex| Error::new(format!("Failed to parse the comments! {ex}")))?;
+
+        // e.g. `{"comments": [{"id": "1", "author": "...", "text": "...",
+        // "dateTime": "2023-05-04T10:20:30", "anchorPos": "1418, 1418, 0, 276"}]}`,
+        // comments on cells have a `cellPos` instead
+        let Some(comments) = json["comments"].as_array() else {
+            return Ok(Vec::new());
+        };
+        comments.iter().map(parse_annotation).collect()
+    }
+
+    /// Comments the current selection, or the cursor position, via `.uno:InsertAnnotation`.
+    ///
+    /// # Arguments
+    /// * `text` - the text of the comment
+    /// * `author` - the author of the comment, e.g. the user of a review tool
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.initialize_for_rendering("");
+    ///
+    /// doc.insert_annotation("Please check this", "Jane Reviewer")?;
+    /// assert_eq!("Please check this", doc.annotations()?[0].text);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn insert_annotation(&mut self, text: &str, author: &str) -> Result<(), Error> {
+        self.check_annotation(text)?;
+        if author.contains('\0') {
+            return Err(Error::new("Comment author contains a NUL character".into()));
+        }
+
+        let args = UnoArgs::new().string("Text", text).string("Author", author);
+        self.post_uno_command(".uno:InsertAnnotation", args, false)
+    }
+
+    /// Replies to a comment, via `.uno:ReplyComment`. The reply is a comment too,
+    /// listed after the comment by [Document::annotations].
+    ///
+    /// # Arguments
+    /// * `id` - the id of the comment to reply to, see [Annotation::id]
+    /// * `text` - the text of the reply
+    pub fn reply_annotation(&mut self, id: i32, text: &str) -> Result<(), Error> {
+        self.check_annotation(text)?;
+
+        let args = UnoArgs::new()
+            .string("Id", &id.to_string())
+            .string("Text", text);
+        self.post_uno_command(".uno:ReplyComment", args, false)
+    }
+
+    /// Checks that a comment can be added to the document
+    fn check_annotation(&self, text: &str) -> Result<(), Error> {
+        if text.contains('\0') {
+            return Err(Error::new("Comment text contains a NUL character".into()));
+        }
+        if self.is_view_read_only() {
+            return Err(Error::new(format!(
+                "The view of the document {} is read-only, comments can't be added",
+                self.url
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Parses a comment of the values of `.uno:ViewAnnotations`
+fn parse_annotation(comment: &serde_json::Value) -> Result<Annotation, Error> {
+    let id = match &comment["id"] {
+        serde_json::Value::Number(id) => id.as_i64().map(|id| id as i32),
+        serde_json::Value::String(id) => id.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| Error::new(format!("The comment {comment} has no id")))?;
+
+    let anchor = comment["anchorPos"]
+        .as_str()
+        .or_else(|| comment["cellPos"].as_str())
+        .unwrap_or_default();
+    let rect = Rect::parse_list(anchor)?
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+
+    let field = |name: &str| comment[name].as_str().unwrap_or_default().to_string();
+    Ok(Annotation {
+        id,
+        author: field("author"),
+        text: field("text"),
+        date: field("dateTime"),
+        rect,
+    })
+}
@@ -0,0 +1,125 @@
+use std::path::{Path, PathBuf};
+
+use url::Url;
+
+use crate::error::Error;
+use crate::{LibreOfficeKitOptionalFeatures, Office};
+
+/// Sets up an [Office] in one go, see [Office::builder]
+#[derive(Debug, Clone, Default)]
+pub struct OfficeBuilder {
+    features: Vec<LibreOfficeKitOptionalFeatures>,
+    user_profile: Option<PathBuf>,
+    temp_dir: Option<PathBuf>,
+}
+
+impl Office {
+    /// Returns a builder setting up an [Office], for the settings that must be
+    /// applied before loading the first document, or even before initializing
+    /// LibreOfficeKit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{LibreOfficeKitOptionalFeatures, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::builder()
+    ///     .feature(LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD)
+    ///     .temp_dir("/var/tmp")
+    ///     .build("/usr/lib/libreoffice/program")?;
+    ///
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// office.document_load(doc_url)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> OfficeBuilder {
+        OfficeBuilder::default()
+    }
+}
+
+impl OfficeBuilder {
+    /// Sets an optional feature, along with the other features given,
+    /// see [Office::set_optional_features]
+    ///
+    /// # Arguments
+    /// * `feature` - the feature to set
+    pub fn feature(mut self, feature: LibreOfficeKitOptionalFeatures) -> OfficeBuilder {
+        if !self.features.contains(&feature) {
+            self.features.push(feature);
+        }
+        self
+    }
+
+    /// Uses the user profile in the given directory, instead of the profile of the
+    /// user running the process, e.g. to isolate the settings of a service.
+    /// LibreOffice creates the profile when the directory is missing or empty.
+    ///
+    /// LibreOfficeKit only takes a user profile when it's initialized, so [OfficeBuilder::build]
+    /// fails when it's already initialized.
+    ///
+    /// # Arguments
+    /// * `dir` - the absolute path of the profile directory
+    pub fn user_profile<P: AsRef<Path>>(mut self, dir: P) -> OfficeBuilder {
+        self.user_profile = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the directory LibreOffice writes its temporary files to, e.g. when the default
+    /// one isn't writable in a read-only container, which makes loads fail.
+    ///
+    /// LibreOffice reads the temporary directory from the environment (`TMPDIR`, or `TMP`
+    /// and `TEMP` on Windows), so [OfficeBuilder::build] sets the environment variables of
+    /// the process before initializing LibreOfficeKit, while none of its threads are
+    /// running yet. The temporary exports of this crate (e.g. [crate::Document::export])
+    /// follow them too. Setting `TMPDIR` before starting the process works as well.
+    /// [OfficeBuilder::build] fails when LibreOfficeKit is already initialized.
+    ///
+    /// There is no `Office::set_temp_dir`: once an [Office] exists, the threads of
+    /// LibreOffice may read the environment while it's being set, and LibreOffice may
+    /// already have chosen its temporary directory, which it doesn't change afterwards.
+    ///
+    /// # Arguments
+    /// * `dir` - an existing directory, which must be writable
+    pub fn temp_dir<P: AsRef<Path>>(mut self, dir: P) -> OfficeBuilder {
+        self.temp_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Initializes LibreOfficeKit, or returns a handle to the initialized instance
+    /// like [Office::new], and applies the settings.
+    ///
+    /// The temporary directory is set first, so that it covers the initialization too,
+    /// then LibreOfficeKit is initialized with the user profile and the optional
+    /// features are set. Fails when LibreOfficeKit is already initialized and a user
+    /// profile or a temporary directory is set, which it can't switch to.
+    ///
+    /// # Arguments
+    ///  * `install_path` - The path to the LibreOffice installation.
+    pub fn build(self, install_path: &str) -> Result<Office, Error> {
+        let user_profile_url = match &self.user_profile {
+            Some(dir) => Some(
+                Url::from_directory_path(dir)
+                    .map_err(|_| {
+                        Error::new(format!(
+                            "The user profile directory {} must be absolute!",
+                            dir.display()
+                        ))
+                    })?
+                    .to_string(),
+            ),
+            None => None,
+        };
+        let mut office = Office::init(
+            install_path,
+            user_profile_url.as_deref(),
+            self.temp_dir.as_deref(),
+        )?;
+
+        if !self.features.is_empty() {
+            office.set_optional_features(self.features)?;
+        }
+        Ok(office)
+    }
+}
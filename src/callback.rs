@@ -0,0 +1,347 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::{Arc, Condvar, Mutex, TryLockError, Weak};
+use std::time::{Duration, Instant};
+
+use crate::coalesce::InvalidationCoalescer;
+use crate::enums::CallbackType;
+use crate::rect::Rect;
+
+/// How long to wait for the callback reporting the result of a command
+pub(crate) const CALLBACK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The latest payload of every callback type a document view received,
+/// and the latest cursor of every other view of the document.
+///
+/// A boxed instance is handed to LibreOfficeKit as the callback data,
+/// so it must outlive the document it is registered with.
+#[derive(Default)]
+pub(crate) struct CallbackBuffer {
+    payloads: Mutex<HashMap<c_int, String>>,
+    /// Notified whenever a payload is kept, see [CallbackBuffer::wait_for]
+    arrived: Condvar,
+    view_cursors: Mutex<BTreeMap<i32, Rect>>,
+    command_states: Mutex<HashMap<String, String>>,
+    /// The `(classification, message)` of the `ERROR` callbacks received
+    /// since [CallbackBuffer::collect_warnings], if collecting
+    warnings: Mutex<Option<Vec<(String, String)>>>,
+    /// Locked only while it runs, so that it can call back into the document
+    size_listener: Mutex<Option<Arc<Mutex<SizeListener>>>>,
+    invalidations: SharedInvalidations,
+}
+
+/// Listener of `DOCUMENT_SIZE_CHANGED` callbacks, receiving the new `(width, height)` in twips
+pub(crate) type SizeListener = Box<dyn FnMut(i64, i64) + Send>;
+
+/// Listener of the coalesced `INVALIDATE_TILES` callbacks, receiving the merged rectangles
+pub(crate) type InvalidationListener = Box<dyn FnMut(&[Rect]) + Send>;
+
+/// The invalidations merged for the invalidation listener, shared with the timers
+/// delivering them once their window is over
+type SharedInvalidations = Arc<Mutex<Option<Invalidations>>>;
+
+struct Invalidations {
+    coalescer: InvalidationCoalescer,
+    /// Locked only while it runs, not along with the coalescer,
+    /// so that it can call back into the document
+    listener: Arc<Mutex<InvalidationListener>>,
+}
+
+impl CallbackBuffer {
+    /// Returns the payload of the last callback of the given type, if any arrived
+    pub(crate) fn last_payload(&self, ty: CallbackType) -> Option<String> {
+        let payloads = self.payloads.lock().unwrap_or_else(|e| e.into_inner());
+        payloads.get(&(ty as c_int)).cloned()
+    }
+
+    /// Waits up to `timeout` for the kept payloads to hold what `found` looks for, and returns it.
+    ///
+    /// LibreOffice delivers the callbacks from its main loop, once the call causing them
+    /// returned, so they arrive on another thread after a while.
+    pub(crate) fn wait_for<T>(
+        &self,
+        timeout: Duration,
+        mut found: impl FnMut(&HashMap<c_int, String>) -> Option<T>,
+    ) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        let mut payloads = self.payloads.lock().unwrap_or_else(|e| e.into_inner());
+        loop {
+            if let Some(value) = found(&payloads) {
+                return Some(value);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return None;
+            }
+            payloads = self
+                .arrived
+                .wait_timeout(payloads, remaining)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+    }
+
+    /// Forgets the last payload of the given type, so a fresh one can be awaited
+    pub(crate) fn clear(&self, ty: CallbackType) {
+        let mut payloads = self.payloads.lock().unwrap_or_else(|e| e.into_inner());
+        payloads.remove(&(ty as c_int));
+    }
+
+    /// Returns the latest cursor of every other view, ordered by view id
+    pub(crate) fn view_cursors(&self) -> Vec<(i32, Rect)> {
+        let cursors = self.view_cursors.lock().unwrap_or_else(|e| e.into_inner());
+        cursors.iter().map(|(view, rect)| (*view, *rect)).collect()
+    }
+
+    /// Keeps the cursor of the view an `INVALIDATE_VIEW_CURSOR` payload is about,
+    /// e.g. `{"viewId": "1", "rectangle": "1418, 1418, 0, 276"}`.
+    /// An `EMPTY` rectangle removes the cursor of the view.
+    fn record_view_cursor(&self, payload: &str) {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(payload) else {
+            return;
+        };
+        let view = match &json["viewId"] {
+            serde_json::Value::Number(id) => id.as_i64().map(|id| id as i32),
+            serde_json::Value::String(id) => id.parse().ok(),
+            _ => None,
+        };
+        let Some(view) = view else {
+            return;
+        };
+
+        let mut cursors = self.view_cursors.lock().unwrap_or_else(|e| e.into_inner());
+        match json["rectangle"].as_str().map(str::parse::<Rect>) {
+            Some(Ok(rect)) => {
+                cursors.insert(view, rect);
+            }
+            _ => {
+                cursors.remove(&view);
+            }
+        }
+    }
+
+    /// Returns the last state broadcast for a UNO command, e.g. `true` for `.uno:EditDoc`
+    pub(crate) fn command_state(&self, command: &str) -> Option<String> {
+        let states = self
+            .command_states
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        states.get(command).cloned()
+    }
+
+    /// Keeps the state of the command a `STATE_CHANGED` payload is about,
+    /// either `.uno:EditDoc=true` or `{"commandName": ".uno:EditDoc", "state": "true"}`
+    fn record_command_state(&self, payload: &str) {
+        let state = match serde_json::from_str::<serde_json::Value>(payload) {
+            Ok(json) => match (json["commandName"].as_str(), &json["state"]) {
+                (Some(command), serde_json::Value::String(state)) => {
+                    (command.to_string(), state.clone())
+                }
+                _ => return,
+            },
+            Err(_) => match payload.split_once('=') {
+                Some((command, state)) => (command.to_string(), state.to_string()),
+                None => return,
+            },
+        };
+
+        let mut states = self
+            .command_states
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        states.insert(state.0, state.1);
+    }
+
+    /// Starts collecting the warnings of `ERROR` callbacks, dropping any uncollected ones
+    pub(crate) fn collect_warnings(&self) {
+        let mut warnings = self.warnings.lock().unwrap_or_else(|e| e.into_inner());
+        *warnings = Some(Vec::new());
+    }
+
+    /// Stops collecting warnings, returning the `(classification, message)` of the ones
+    /// received meanwhile, the classification being e.g. `warning`, `error`, or empty if unknown
+    pub(crate) fn take_warnings(&self) -> Vec<(String, String)> {
+        let mut warnings = self.warnings.lock().unwrap_or_else(|e| e.into_inner());
+        warnings.take().unwrap_or_default()
+    }
+
+    /// Keeps the classification and message of an `ERROR` payload, e.g.
+    /// `{"classification": "warning", "cmd": "saveas", "kind": "io", "code": "...", "message": "..."}`.
+    /// Payloads that aren't JSON are kept as they are, without classification.
+    fn record_warning(&self, payload: &str) {
+        let mut warnings = self.warnings.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(warnings) = warnings.as_mut() else {
+            return;
+        };
+
+        match serde_json::from_str::<serde_json::Value>(payload) {
+            Ok(json) => {
+                let classification = json["classification"].as_str().unwrap_or_default();
+                let message = match json["message"].as_str() {
+                    Some(message) if !message.is_empty() => message,
+                    _ => payload,
+                };
+                warnings.push((classification.to_string(), message.to_string()));
+            }
+            Err(_) => warnings.push((String::new(), payload.to_string())),
+        }
+    }
+
+    /// Sets the listener called with the new size of the document, replacing any previous one
+    pub(crate) fn set_size_listener(&self, listener: SizeListener) {
+        let mut size_listener = self.size_listener.lock().unwrap_or_else(|e| e.into_inner());
+        *size_listener = Some(Arc::new(Mutex::new(listener)));
+    }
+
+    /// Hands the size of a `DOCUMENT_SIZE_CHANGED` payload, e.g. `12240, 15840`,
+    /// to the size listener, unless it's already running
+    fn notify_size_changed(&self, payload: &str) {
+        let mut values = payload.split(',').map(|value| value.trim().parse::<i64>());
+        let (width, height) = match (values.next(), values.next()) {
+            (Some(Ok(width)), Some(Ok(height))) => (width, height),
+            _ => return,
+        };
+
+        let listener = {
+            let size_listener = self.size_listener.lock().unwrap_or_else(|e| e.into_inner());
+            match size_listener.as_ref() {
+                Some(listener) => Arc::clone(listener),
+                None => return,
+            }
+        };
+        // a size change the listener itself caused isn't reported again
+        let mut listener = match listener.try_lock() {
+            Ok(listener) => listener,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => return,
+        };
+        listener(width, height);
+    }
+
+    /// Sets the listener of the invalidations merged by `coalescer`, replacing any previous one
+    pub(crate) fn set_invalidation_listener(
+        &self,
+        coalescer: InvalidationCoalescer,
+        listener: InvalidationListener,
+    ) {
+        let mut invalidations = self.invalidations.lock().unwrap_or_else(|e| e.into_inner());
+        *invalidations = Some(Invalidations {
+            coalescer,
+            listener: Arc::new(Mutex::new(listener)),
+        });
+    }
+
+    /// Hands the pending invalidations to the invalidation listener right away
+    pub(crate) fn flush_invalidations(&self) {
+        deliver_invalidations(&self.invalidations, |coalescer, _| Some(coalescer.flush()));
+    }
+
+    fn record(&self, ty: c_int, payload: String) {
+        if ty == CallbackType::InvalidateViewCursor as c_int {
+            self.record_view_cursor(&payload);
+        } else if ty == CallbackType::DocumentSizeChanged as c_int {
+            self.notify_size_changed(&payload);
+        } else if ty == CallbackType::StateChanged as c_int {
+            self.record_command_state(&payload);
+        } else if ty == CallbackType::Error as c_int {
+            self.record_warning(&payload);
+        }
+
+        if ty == CallbackType::InvalidateTiles as c_int {
+            deliver_invalidations(&self.invalidations, |coalescer, now| {
+                let opening = coalescer.deadline().is_none();
+                let rects = coalescer.push(&payload, now);
+                if let Some(deadline) = coalescer.deadline().filter(|_| opening) {
+                    schedule_invalidations(&self.invalidations, deadline);
+                }
+                rects
+            });
+        }
+        let mut payloads = self.payloads.lock().unwrap_or_else(|e| e.into_inner());
+        payloads.insert(ty, payload);
+        self.arrived.notify_all();
+    }
+}
+
+/// Takes the invalidations `take` returns out of the coalescer, and hands them to the
+/// invalidation listener, if any.
+///
+/// The coalescer isn't locked while the listener runs. When the listener is already running,
+/// e.g. it called back into the document, which reported more invalidations, they are
+/// merged back into the coalescer, and delivered once the window is over again.
+fn deliver_invalidations(
+    invalidations: &SharedInvalidations,
+    take: impl FnOnce(&mut InvalidationCoalescer, Instant) -> Option<Vec<Rect>>,
+) {
+    let now = Instant::now();
+    let (rects, listener) = {
+        let mut state = invalidations.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(state) = state.as_mut() else {
+            return;
+        };
+        let rects = take(&mut state.coalescer, now);
+        (rects, Arc::clone(&state.listener))
+    };
+    let Some(rects) = rects.filter(|rects| !rects.is_empty()) else {
+        return;
+    };
+
+    let mut listener = match listener.try_lock() {
+        Ok(listener) => listener,
+        Err(TryLockError::Poisoned(e)) => e.into_inner(),
+        Err(TryLockError::WouldBlock) => {
+            let mut state = invalidations.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(state) = state.as_mut() {
+                let opening = state.coalescer.deadline().is_none();
+                state.coalescer.requeue(rects, now);
+                if let Some(deadline) = state.coalescer.deadline().filter(|_| opening) {
+                    schedule_invalidations(invalidations, deadline);
+                }
+            }
+            return;
+        }
+    };
+    listener(&rects);
+}
+
+/// Delivers the invalidations once their window is over, on a thread of its own,
+/// unless other invalidations delivered them meanwhile
+fn schedule_invalidations(invalidations: &SharedInvalidations, deadline: Instant) {
+    let invalidations: Weak<_> = Arc::downgrade(invalidations);
+    std::thread::spawn(move || {
+        std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+        // the document is gone
+        let Some(invalidations) = invalidations.upgrade() else {
+            return;
+        };
+        deliver_invalidations(&invalidations, |coalescer, now| {
+            coalescer.is_due(now).then(|| coalescer.flush())
+        });
+    });
+}
+
+/// Callback that Libreoffice will invoke for a document view,
+/// the data value is the document's [CallbackBuffer]
+///
+/// LibreOfficeKitCallback typedef (int nType, const char* pPayload, void* pData);
+pub(crate) unsafe extern "C" fn document_callback_shim(
+    ty: c_int,
+    payload: *const c_char,
+    data: *mut c_void,
+) {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!("libreoffice_document_callback", ty).entered();
+
+    let buffer: *const CallbackBuffer = data.cast();
+    let payload = if payload.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(payload).to_string_lossy().into_owned()
+    };
+
+    // Catch panics rather than unwinding into LibreOffice
+    _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+        (*buffer).record(ty, payload);
+    }));
+}
@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A flag to cancel a running operation from another thread, e.g. loading a document
+/// with [crate::Office::document_load_cancellable]
+///
+/// Clones share the same flag.
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let canceller = token.clone();
+/// assert!(!token.is_cancelled());
+///
+/// canceller.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a token that isn't cancelled
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Request the cancellation of the operations using this token or its clones
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Whether the cancellation was requested
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+}
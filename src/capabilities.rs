@@ -0,0 +1,131 @@
+use crate::error::Error;
+use crate::{LibreOfficeKitOptionalFeatures, Office, VersionInfo};
+
+/// Every optional feature, with the first LibreOffice version expected to know it.
+///
+/// LibreOfficeKit has no way to list the features an instance knows, so these come from
+/// LibreOffice's headers rather than from the instance: `setOptionalFeatures` and the
+/// flags it was introduced with are documented `@since LibreOffice 6.0` in
+/// `include/LibreOfficeKit/LibreOfficeKit.hxx`, and the later flags with the release
+/// whose `include/LibreOfficeKit/LibreOfficeKitEnums.h` first defines them.
+const OPTIONAL_FEATURES: [(LibreOfficeKitOptionalFeatures, (u32, u32)); 6] = [
+    // LibreOfficeKit.hxx: setOptionalFeatures, `@since LibreOffice 6.0`
+    (
+        LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD,
+        (6, 0),
+    ),
+    (
+        LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD_TO_MODIFY,
+        (6, 0),
+    ),
+    (
+        LibreOfficeKitOptionalFeatures::LOK_FEATURE_PART_IN_INVALIDATION_CALLBACK,
+        (6, 0),
+    ),
+    (
+        LibreOfficeKitOptionalFeatures::LOK_FEATURE_NO_TILED_ANNOTATIONS,
+        (6, 0),
+    ),
+    // LibreOfficeKitEnums.h of the libreoffice-6-1 branch
+    (
+        LibreOfficeKitOptionalFeatures::LOK_FEATURE_RANGE_HEADERS,
+        (6, 1),
+    ),
+    // LibreOfficeKitEnums.h of the libreoffice-6-2 branch
+    (
+        LibreOfficeKitOptionalFeatures::LOK_FEATURE_VIEWID_IN_VISCURSOR_INVALIDATION_CALLBACK,
+        (6, 2),
+    ),
+];
+
+/// The version of a LibreOfficeKit instance and the optional features expected from it,
+/// see [Office::new_with_capabilities]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    pub version: VersionInfo,
+    /// The optional features the version of the instance is expected to know,
+    /// see [Office::set_optional_features]
+    pub expected_features: Vec<LibreOfficeKitOptionalFeatures>,
+}
+
+impl Capabilities {
+    /// Returns the capabilities of a LibreOffice version.
+    ///
+    /// LibreOfficeKit accepts any optional feature without telling whether it knows
+    /// it, so nothing is probed: the expected features are the ones LibreOffice's
+    /// headers document for the version, and a build may still differ.
+    ///
+    /// # Arguments
+    /// * `version` - the version of LibreOffice, e.g. [Office::get_version_info]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Capabilities, LibreOfficeKitOptionalFeatures, VersionInfo};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let version = VersionInfo::from_json(
+    ///     r#"{"ProductName": "LibreOffice", "ProductVersion": "6.0", "ProductExtension": ".7.3", "BuildId": ""}"#,
+    /// )?;
+    /// let capabilities = Capabilities::from_version(version)?;
+    ///
+    /// assert!(capabilities.expects(LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD));
+    /// assert!(!capabilities.expects(LibreOfficeKitOptionalFeatures::LOK_FEATURE_RANGE_HEADERS));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_version(version: VersionInfo) -> Result<Capabilities, Error> {
+        let version_number = version.major_minor()?;
+        let expected_features = OPTIONAL_FEATURES
+            .iter()
+            .filter(|(_, since)| version_number >= *since)
+            .map(|(feature, _)| *feature)
+            .collect();
+
+        Ok(Capabilities {
+            version,
+            expected_features,
+        })
+    }
+
+    /// Whether the version of the instance is expected to know an optional feature
+    ///
+    /// # Arguments
+    /// * `feature` - the optional feature
+    pub fn expects(&self, feature: LibreOfficeKitOptionalFeatures) -> bool {
+        self.expected_features.contains(&feature)
+    }
+}
+
+impl Office {
+    /// Create a new LibreOfficeKit instance, see [Office::new], along with its version
+    /// and the optional features expected from it, see [Capabilities::from_version],
+    /// to e.g. only enable the optional features it should know.
+    ///
+    /// # Arguments
+    ///
+    ///  * `install_path` - The path to the LibreOffice installation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{LibreOfficeKitOptionalFeatures, Office};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (mut office, capabilities) =
+    ///     Office::new_with_capabilities("/usr/lib/libreoffice/program")?;
+    /// println!("LibreOffice {}", capabilities.version.full_version());
+    ///
+    /// let feature = LibreOfficeKitOptionalFeatures::LOK_FEATURE_RANGE_HEADERS;
+    /// if capabilities.expects(feature) {
+    ///     office.set_optional_features([feature])?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_capabilities(install_path: &str) -> Result<(Office, Capabilities), Error> {
+        let office = Office::new(install_path)?;
+        let capabilities = Capabilities::from_version(office.get_version_info()?)?;
+        Ok((office, capabilities))
+    }
+}
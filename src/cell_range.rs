@@ -0,0 +1,73 @@
+use std::fmt;
+
+/// A rectangular range of cells of a spreadsheet, e.g. `B2:D4`, with the columns
+/// and rows numbered from 0
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::CellRange;
+///
+/// let range = CellRange::new(1, 1, 3, 3);
+/// assert_eq!((3, 3), (range.columns(), range.rows()));
+/// assert_eq!("B2:D4", range.to_string());
+/// assert_eq!("F6", CellRange::new(5, 5, 5, 5).to_string());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CellRange {
+    pub start_column: u32,
+    pub start_row: u32,
+    pub end_column: u32,
+    pub end_row: u32,
+}
+
+impl CellRange {
+    /// Create a range from two opposite corner cells, in any order
+    ///
+    /// # Arguments
+    /// * `start_column`, `start_row` - a corner cell
+    /// * `end_column`, `end_row` - the opposite corner cell, included in the range
+    pub fn new(start_column: u32, start_row: u32, end_column: u32, end_row: u32) -> CellRange {
+        CellRange {
+            start_column: start_column.min(end_column),
+            start_row: start_row.min(end_row),
+            end_column: start_column.max(end_column),
+            end_row: start_row.max(end_row),
+        }
+    }
+
+    /// The number of columns of the range
+    pub fn columns(&self) -> u32 {
+        self.end_column - self.start_column + 1
+    }
+
+    /// The number of rows of the range
+    pub fn rows(&self) -> u32 {
+        self.end_row - self.start_row + 1
+    }
+}
+
+/// Writes the range in A1 notation, e.g. `B2:D4`, or `B2` for a single cell
+impl fmt::Display for CellRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_cell(f, self.start_column, self.start_row)?;
+        if self.end_column != self.start_column || self.end_row != self.start_row {
+            f.write_str(":")?;
+            write_cell(f, self.end_column, self.end_row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the A1 address of a cell, e.g. `AA10` for column 26 and row 9
+fn write_cell(f: &mut fmt::Formatter<'_>, column: u32, row: u32) -> fmt::Result {
+    let mut letters = Vec::new();
+    let mut column = column + 1;
+    while column > 0 {
+        column -= 1;
+        letters.push(char::from(b'A' + (column % 26) as u8));
+        column /= 26;
+    }
+    letters.reverse();
+    write!(f, "{}{}", letters.into_iter().collect::<String>(), row + 1)
+}
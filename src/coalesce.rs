@@ -0,0 +1,137 @@
+use std::time::{Duration, Instant};
+
+use crate::rect::Rect;
+
+/// The rectangle covering a whole document, which LibreOffice invalidates
+/// as `EMPTY` or, in newer versions, as this rectangle
+pub const WHOLE_DOCUMENT: Rect = Rect {
+    x: 0,
+    y: 0,
+    width: 1_000_000_000,
+    height: 1_000_000_000,
+};
+
+/// Buffers the rectangles of `INVALIDATE_TILES` callbacks and merges them,
+/// to repaint at most once per time window rather than once per callback.
+///
+/// The first buffered invalidation opens a window, the invalidations arriving within it
+/// are merged: overlapping or touching rectangles become their bounding rectangle.
+/// The merged rectangles are handed out by the first invalidation after the window,
+/// or by [InvalidationCoalescer::flush] once [InvalidationCoalescer::deadline] is passed.
+///
+/// @see [crate::Document::register_coalescing_callback]
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, Instant};
+/// use libreoffice_rs::{InvalidationCoalescer, Rect};
+///
+/// let mut coalescer = InvalidationCoalescer::new(Duration::from_millis(100));
+/// let start = Instant::now();
+///
+/// assert_eq!(None, coalescer.push("0, 0, 100, 100", start));
+/// assert_eq!(None, coalescer.push("50, 50, 100, 100", start + Duration::from_millis(10)));
+/// assert_eq!(
+///     Some(vec![Rect::new(0, 0, 150, 150), Rect::new(500, 500, 10, 10)]),
+///     coalescer.push("500, 500, 10, 10", start + Duration::from_millis(100))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct InvalidationCoalescer {
+    window: Duration,
+    /// When the first pending invalidation arrived
+    opened: Option<Instant>,
+    pending: Vec<Rect>,
+}
+
+impl InvalidationCoalescer {
+    /// Create a coalescer flushing at most once per `window`
+    pub fn new(window: Duration) -> InvalidationCoalescer {
+        InvalidationCoalescer {
+            window,
+            opened: None,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Buffers the rectangle of an `INVALIDATE_TILES` payload, returning all the merged
+    /// rectangles once the window is over.
+    ///
+    /// The payload is `x, y, width, height`, optionally followed by the part (which is ignored,
+    /// the rectangles of all the parts are merged), or `EMPTY` for the [WHOLE_DOCUMENT].
+    /// Payloads that can't be parsed invalidate the whole document.
+    ///
+    /// # Arguments
+    /// * `payload` - the payload of the callback
+    /// * `now` - when the callback arrived
+    pub fn push(&mut self, payload: &str, now: Instant) -> Option<Vec<Rect>> {
+        self.merge(parse_invalidation(payload));
+        let opened = *self.opened.get_or_insert(now);
+        if now.duration_since(opened) >= self.window {
+            return Some(self.flush());
+        }
+        None
+    }
+
+    /// Returns whether invalidations are pending and their window is over
+    ///
+    /// # Arguments
+    /// * `now` - the current time
+    pub fn is_due(&self, now: Instant) -> bool {
+        match self.opened {
+            Some(opened) => now.duration_since(opened) >= self.window,
+            None => false,
+        }
+    }
+
+    /// Returns when the window of the pending invalidations is over, `None` when
+    /// no invalidation is pending
+    pub fn deadline(&self) -> Option<Instant> {
+        self.opened.map(|opened| opened + self.window)
+    }
+
+    /// Returns the merged pending rectangles right away, and closes the window
+    pub fn flush(&mut self) -> Vec<Rect> {
+        self.opened = None;
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Buffers rectangles that were already merged, e.g. flushed while they couldn't
+    /// be delivered, opening a window if none is
+    pub(crate) fn requeue(&mut self, rects: Vec<Rect>, now: Instant) {
+        for rect in rects {
+            self.merge(rect);
+        }
+        self.opened.get_or_insert(now);
+    }
+
+    /// Adds a rectangle, merging it with the pending rectangles it overlaps or touches
+    fn merge(&mut self, mut rect: Rect) {
+        while let Some(i) = self.pending.iter().position(|other| touches(&rect, other)) {
+            rect = rect.union(&self.pending.swap_remove(i));
+        }
+        self.pending.push(rect);
+        self.pending.sort_by_key(|rect| (rect.y, rect.x));
+    }
+}
+
+fn parse_invalidation(payload: &str) -> Rect {
+    let values = payload
+        .split(',')
+        .take(4)
+        .map(|value| value.trim().parse::<i32>())
+        .collect::<Result<Vec<_>, _>>();
+    match values.as_deref() {
+        Ok([x, y, width, height]) => Rect::new(*x, *y, *width, *height),
+        _ => WHOLE_DOCUMENT,
+    }
+}
+
+/// Whether two rectangles overlap or share an edge
+fn touches(a: &Rect, b: &Rect) -> bool {
+    a.x as i64 <= b.x as i64 + b.width as i64
+        && b.x as i64 <= a.x as i64 + a.width as i64
+        && a.y as i64 <= b.y as i64 + b.height as i64
+        && b.y as i64 <= a.y as i64 + a.height as i64
+}
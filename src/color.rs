@@ -0,0 +1,71 @@
+use std::fmt;
+
+use crate::error::Error;
+
+/// An RGB color, as used by the `.uno:` color commands
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::Color;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let red = Color::from_hex("#ff0000")?;
+///
+/// assert_eq!(Color::from_rgb(255, 0, 0), red);
+/// assert_eq!(0xff0000, red.to_lok_value());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+    /// Create a color from its red, green and blue components
+    pub fn from_rgb(red: u8, green: u8, blue: u8) -> Color {
+        Color { red, green, blue }
+    }
+
+    /// Parse a color from its hex notation, `#RRGGBB` or `#RGB`, the `#` is optional
+    ///
+    /// # Arguments
+    /// * `hex` - The color, e.g. `#1e90ff`
+    pub fn from_hex(hex: &str) -> Result<Color, Error> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || Error::new(format!("Invalid hex color {hex}!"));
+
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let component = |s: &str| u8::from_str_radix(s, 16).map_err(|_| invalid());
+        match digits.len() {
+            6 => Ok(Color::from_rgb(
+                component(&digits[0..2])?,
+                component(&digits[2..4])?,
+                component(&digits[4..6])?,
+            )),
+            3 => Ok(Color::from_rgb(
+                component(&digits[0..1])? * 0x11,
+                component(&digits[1..2])? * 0x11,
+                component(&digits[2..3])? * 0x11,
+            )),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// The integer LibreOffice encodes colors as, `0xRRGGBB`
+    pub fn to_lok_value(self) -> u32 {
+        (self.red as u32) << 16 | (self.green as u32) << 8 | self.blue as u32
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
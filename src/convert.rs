@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::{urls, Office, OutputFormat};
+
+impl Office {
+    /// Converts every file of a directory, e.g. to PDF, continuing past the files that
+    /// fail to load or to save.
+    ///
+    /// The files are converted in the order of their names, subdirectories are skipped.
+    /// Every file is loaded and saved with [crate::Document::save_as] into `output_dir`,
+    /// under its name with the extension of `format`, e.g. `report.docx` into `report.pdf`,
+    /// overwriting existing files. A file isn't converted when its output would
+    /// overwrite it, or the output of a file converted before, e.g. `report.odt`
+    /// after `report.docx`.
+    ///
+    /// Returns every file with the path of its output, or why it wasn't converted.
+    /// Fails when the input directory can't be read or the output directory created.
+    ///
+    /// # Arguments
+    /// * `input_dir` - the directory of the files to convert
+    /// * `output_dir` - the directory to save the outputs to, created if missing
+    /// * `format` - the format to convert to
+    /// * `filter` - the filter options, see [crate::Document::save_as]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, OutputFormat};
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let results = office.convert_dir(
+    ///     Path::new("./test_data"),
+    ///     Path::new("/tmp/converted"),
+    ///     OutputFormat::Pdf,
+    ///     None,
+    /// )?;
+    /// for (input, result) in results {
+    ///     match result {
+    ///         Ok(output) => println!("{} -> {}", input.display(), output.display()),
+    ///         Err(err) => eprintln!("{}: {err}", input.display()),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn convert_dir(
+        &mut self,
+        input_dir: &Path,
+        output_dir: &Path,
+        format: OutputFormat,
+        filter: Option<&str>,
+    ) -> Result<Vec<(PathBuf, Result<PathBuf, Error>)>, Error> {
+        let read_error = |ex: std::io::Error| {
+            Error::new(format!("Failed to read {}! {ex}", input_dir.display()))
+        };
+        let mut inputs = Vec::new();
+        for entry in std::fs::read_dir(input_dir).map_err(read_error)? {
+            let path = entry.map_err(read_error)?.path();
+            if path.is_file() {
+                inputs.push(path);
+            }
+        }
+        inputs.sort();
+
+        // canonical, to compare the outputs with the inputs
+        let output_dir = std::fs::create_dir_all(output_dir)
+            .and_then(|_| std::fs::canonicalize(output_dir))
+            .map_err(|ex| {
+                Error::new(format!(
+                    "Failed to create directory {}! {ex}",
+                    output_dir.display()
+                ))
+            })?;
+
+        let mut outputs = HashSet::new();
+        let results = inputs
+            .into_iter()
+            .map(|input| {
+                let result = self.convert_file(&input, &output_dir, format, filter, &mut outputs);
+                (input, result)
+            })
+            .collect();
+        Ok(results)
+    }
+
+    /// Converts a file of [Office::convert_dir], `outputs` are the outputs converted before
+    fn convert_file(
+        &mut self,
+        input: &Path,
+        output_dir: &Path,
+        format: OutputFormat,
+        filter: Option<&str>,
+        outputs: &mut HashSet<PathBuf>,
+    ) -> Result<PathBuf, Error> {
+        let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+        let output = output_dir.join(format!("{stem}.{}", format.extension()));
+
+        let input_url = urls::local_into_abs(input.display().to_string())?;
+        if input_url.to_file_path().as_deref() == Some(output.as_path()) {
+            return Err(Error::new(format!(
+                "The output of {} would overwrite it",
+                input.display()
+            )));
+        }
+        if !outputs.insert(output.clone()) {
+            return Err(Error::new(format!(
+                "The output {} of {} was already converted from another file",
+                output.display(),
+                input.display()
+            )));
+        }
+
+        let mut doc = self.document_load(input_url)?;
+        if doc.save_as(&output.display().to_string(), format.extension(), filter) {
+            Ok(output)
+        } else {
+            Err(Error::new(format!(
+                "Failed to save {} as {format}",
+                output.display()
+            )))
+        }
+    }
+}
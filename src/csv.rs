@@ -0,0 +1,358 @@
+use crate::error::Error;
+use crate::urls::DocUrl;
+use crate::{Document, DocumentType, LanguageTag, Office};
+
+/// The `UTF-8` text encoding of the CSV filter options
+const CSV_UTF8: u32 = 76;
+
+/// The cell format code of text columns in the CSV filter options
+const CSV_TEXT_COLUMN: u32 = 2;
+
+/// The LibreOffice language identifiers (the Windows LCIDs) of common locales,
+/// which the CSV import filter takes to recognize numbers and dates
+const LANGUAGE_IDS: [(&str, u32); 28] = [
+    ("cs-CZ", 0x0405),
+    ("da-DK", 0x0406),
+    ("de-AT", 0x0C07),
+    ("de-CH", 0x0807),
+    ("de-DE", 0x0407),
+    ("en-AU", 0x0C09),
+    ("en-CA", 0x1009),
+    ("en-GB", 0x0809),
+    ("en-US", 0x0409),
+    ("es-ES", 0x0C0A),
+    ("es-MX", 0x080A),
+    ("fi-FI", 0x040B),
+    ("fr-BE", 0x080C),
+    ("fr-CA", 0x0C0C),
+    ("fr-CH", 0x100C),
+    ("fr-FR", 0x040C),
+    ("hu-HU", 0x040E),
+    ("it-IT", 0x0410),
+    ("ja-JP", 0x0411),
+    ("nb-NO", 0x0414),
+    ("nl-BE", 0x0813),
+    ("nl-NL", 0x0413),
+    ("pl-PL", 0x0415),
+    ("pt-BR", 0x0416),
+    ("pt-PT", 0x0816),
+    ("ru-RU", 0x0419),
+    ("sv-SE", 0x041D),
+    ("zh-CN", 0x0804),
+];
+
+/// Options of the `Text - txt - csv (StarCalc)` import filter, see [Office::document_load_csv]
+///
+/// By default, fields are separated by commas and delimited by double quotes, the file
+/// is read as UTF-8 from its first line, numbers and dates are recognized in the locale
+/// of LibreOffice, including special numbers (e.g. dates, times and scientific notation).
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::{CsvImportOptions, LanguageTag};
+/// use std::convert::TryFrom;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = CsvImportOptions::new()
+///     .separators(&[';'])?
+///     .language(&LanguageTag::try_from("de-DE")?)?
+///     .text_columns(&[1]);
+///
+/// assert_eq!(
+///     "59,34,76,1,1/2,1031,false,true,,,false,,true,,true",
+///     options.to_filter_string()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvImportOptions {
+    separators: Vec<char>,
+    text_delimiter: char,
+    first_line: u32,
+    /// The columns, from 1, imported as text
+    text_columns: Vec<u32>,
+    /// The LibreOffice language identifier of the locale, see [LANGUAGE_IDS]
+    language_id: Option<u32>,
+    quoted_as_text: bool,
+    detect_numbers: bool,
+}
+
+impl Default for CsvImportOptions {
+    fn default() -> CsvImportOptions {
+        CsvImportOptions {
+            separators: vec![','],
+            text_delimiter: '"',
+            first_line: 1,
+            text_columns: Vec::new(),
+            language_id: None,
+            quoted_as_text: false,
+            detect_numbers: true,
+        }
+    }
+}
+
+impl CsvImportOptions {
+    /// Create the default import options, see [CsvImportOptions]
+    pub fn new() -> CsvImportOptions {
+        CsvImportOptions::default()
+    }
+
+    /// Set the field separators, any of which separates fields, e.g. `&[';']` or `&['\t', ',']`
+    ///
+    /// # Arguments
+    ///  * `separators` - the separators, at least one
+    pub fn separators(mut self, separators: &[char]) -> Result<CsvImportOptions, Error> {
+        if separators.is_empty() {
+            return Err(Error::new("At least one CSV separator is required".into()));
+        }
+        for &separator in separators {
+            validate_csv_char("field separator", separator)?;
+        }
+        self.separators = separators.to_vec();
+        Ok(self)
+    }
+
+    /// Set the delimiter of text fields, e.g. `'` instead of `"`
+    ///
+    /// # Arguments
+    ///  * `delimiter` - the text delimiter
+    pub fn text_delimiter(mut self, delimiter: char) -> Result<CsvImportOptions, Error> {
+        validate_csv_char("text delimiter", delimiter)?;
+        self.text_delimiter = delimiter;
+        Ok(self)
+    }
+
+    /// Start the import at a line, e.g. 2 to skip a header line
+    ///
+    /// # Arguments
+    ///  * `line` - the first line to import, from 1
+    pub fn first_line(mut self, line: u32) -> Result<CsvImportOptions, Error> {
+        if line == 0 {
+            return Err(Error::new("CSV lines are numbered from 1".into()));
+        }
+        self.first_line = line;
+        Ok(self)
+    }
+
+    /// Import columns as text as they are, e.g. zip codes or identifiers with leading zeros,
+    /// which would otherwise be recognized as numbers
+    ///
+    /// # Arguments
+    ///  * `columns` - the columns, numbered from 1
+    pub fn text_columns(mut self, columns: &[u32]) -> CsvImportOptions {
+        self.text_columns = columns
+            .iter()
+            .copied()
+            .filter(|&column| column > 0)
+            .collect();
+        self.text_columns.sort_unstable();
+        self.text_columns.dedup();
+        self
+    }
+
+    /// Recognize numbers and dates in the conventions of a locale, e.g. `de-DE` for
+    /// `1.234,5` and `31.12.2023`, instead of the locale of LibreOffice.
+    ///
+    /// The filter takes a Windows language identifier, which is known for the common
+    /// locales of Europe and America, and a few others, other tags are an error.
+    ///
+    /// # Arguments
+    ///  * `language` - the locale of the file, e.g. `fr-FR`
+    pub fn language(mut self, language: &LanguageTag) -> Result<CsvImportOptions, Error> {
+        let id = LANGUAGE_IDS
+            .iter()
+            .find(|(tag, _)| tag.eq_ignore_ascii_case(language.as_str()))
+            .map(|(_, id)| *id)
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "Unsupported CSV import language {language}, expected one of {}",
+                    LANGUAGE_IDS
+                        .iter()
+                        .map(|(tag, _)| *tag)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+            })?;
+        self.language_id = Some(id);
+        Ok(self)
+    }
+
+    /// Import the quoted fields as text, without recognizing numbers, `false` by default
+    pub fn quoted_as_text(mut self, quoted_as_text: bool) -> CsvImportOptions {
+        self.quoted_as_text = quoted_as_text;
+        self
+    }
+
+    /// Recognize special numbers: dates, times and the scientific notation, `true` by
+    /// default. Without, only plain decimal numbers are recognized, the rest is text.
+    pub fn detect_numbers(mut self, detect_numbers: bool) -> CsvImportOptions {
+        self.detect_numbers = detect_numbers;
+        self
+    }
+
+    /// Builds the filter options token string of the CSV import filter, e.g.
+    /// `59,34,76,1,1/2,1031,false,true,,,false,,true,,true`: field separators, text
+    /// delimiter, encoding, first line, column formats, language, quoted fields as text,
+    /// detect special numbers, two export options, remove spaces, a third export option,
+    /// evaluate formulas, a fourth export option and detect scientific numbers
+    pub fn to_filter_string(&self) -> String {
+        let separators: Vec<_> = self
+            .separators
+            .iter()
+            .map(|&separator| (separator as u32).to_string())
+            .collect();
+        let columns: Vec<_> = self
+            .text_columns
+            .iter()
+            .map(|column| format!("{column}/{CSV_TEXT_COLUMN}"))
+            .collect();
+        let language = self
+            .language_id
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+
+        format!(
+            "{},{},{CSV_UTF8},{},{},{language},{},{},,,false,,true,,{}",
+            separators.join("/"),
+            self.text_delimiter as u32,
+            self.first_line,
+            columns.join("/"),
+            self.quoted_as_text,
+            self.detect_numbers,
+            self.detect_numbers
+        )
+    }
+}
+
+impl Office {
+    /// Loads a CSV file as a spreadsheet, with the options of the CSV import filter,
+    /// e.g. to recognize the dates and decimal commas of a European file.
+    ///
+    /// The filter options are passed along with the load options, like
+    /// [Office::document_load_with]. LibreOffice picks the CSV filter from the
+    /// extension and content of the file: files that aren't detected as CSV,
+    /// e.g. without `.csv` extension, may be loaded as text documents, which is an error.
+    ///
+    /// # Arguments
+    /// * `url` - The URL of the CSV file
+    /// * `options` - The import options, see [CsvImportOptions]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{CsvImportOptions, LanguageTag, Office, urls};
+    /// use std::convert::TryFrom;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/european.csv")?;
+    /// let options = CsvImportOptions::new()
+    ///     .separators(&[';'])?
+    ///     .language(&LanguageTag::try_from("de-DE")?)?;
+    ///
+    /// let _doc = office.document_load_csv(doc_url, &options)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn document_load_csv(
+        &mut self,
+        url: DocUrl,
+        options: &CsvImportOptions,
+    ) -> Result<Document, Error> {
+        let doc = self.document_load_with(url.clone(), &options.to_filter_string())?;
+        if doc.get_document_type() != DocumentType::Spreadsheet {
+            return Err(Error::new(format!(
+                "{url} wasn't imported as a CSV spreadsheet, but as a {:?} document",
+                doc.get_document_type()
+            )));
+        }
+        Ok(doc)
+    }
+}
+
+impl Document {
+    /// Exports a sheet of a spreadsheet to a CSV file, in UTF-8.
+    ///
+    /// Builds the filter options of the `Text - txt - csv (StarCalc)` export filter:
+    /// cells are exported as shown (i.e. formatted) and formulas as their results.
+    ///
+    /// # Arguments
+    /// * `url` - the location of the CSV file
+    /// * `field_sep` - the field separator, e.g. `,` or `;`
+    /// * `text_delim` - the delimiter of text fields, e.g. `"`
+    /// * `sheet` - the index of the sheet to export, from 0, or `None` for the current sheet.
+    ///             Selecting a sheet requires LibreOffice 7.2 or newer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/table.ods")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// let output_path = std::env::temp_dir().join("libreoffice_rs_export_csv.csv");
+    /// let output_url = urls::local_as_abs(output_path.display().to_string())?;
+    ///
+    /// doc.export_csv(&output_url, ';', '"', Some(1))?;
+    /// let _ = std::fs::remove_file(&output_path);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_csv(
+        &mut self,
+        url: &DocUrl,
+        field_sep: char,
+        text_delim: char,
+        sheet: Option<i32>,
+    ) -> Result<(), Error> {
+        if self.get_document_type() != DocumentType::Spreadsheet {
+            return Err(Error::new(
+                "Only spreadsheets can be exported to CSV".into(),
+            ));
+        }
+
+        let filter = csv_filter_options(field_sep, text_delim, sheet)?;
+        if self.save_as(&url.to_string(), "csv", Some(&filter)) {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "Failed to export the spreadsheet to {url} as CSV"
+            )))
+        }
+    }
+}
+
+/// Builds the filter options token string of the CSV export filter, e.g.
+/// `44,34,76,1,,0,false,true,true,false,false,2`: field separator, text delimiter,
+/// encoding, first line, column formats, language, quote all text, detect special numbers,
+/// save as shown, export formulas, remove spaces and the sheet number (0 for the current one)
+fn csv_filter_options(
+    field_sep: char,
+    text_delim: char,
+    sheet: Option<i32>,
+) -> Result<String, Error> {
+    validate_csv_char("field separator", field_sep)?;
+    validate_csv_char("text delimiter", text_delim)?;
+    let sheet = match sheet {
+        None => 0,
+        Some(sheet) if sheet >= 0 => sheet + 1,
+        Some(sheet) => return Err(Error::new(format!("Invalid sheet index {sheet}"))),
+    };
+
+    Ok(format!(
+        "{},{},{CSV_UTF8},1,,0,false,true,true,false,false,{sheet}",
+        field_sep as u32, text_delim as u32
+    ))
+}
+
+/// Checks that a separator or delimiter fits the 16 bit codes of the CSV filter options
+fn validate_csv_char(name: &str, c: char) -> Result<(), Error> {
+    if c == '\0' || c as u32 > 0xFFFF {
+        return Err(Error::new(format!("Unsupported CSV {name} {c:?}")));
+    }
+    Ok(())
+}
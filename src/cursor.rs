@@ -0,0 +1,158 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::error::Error;
+use crate::{Document, DocumentType, UnoArgs};
+
+impl Document {
+    /// Moves the text cursor of the current view of a text document to a logical position,
+    /// collapsing any selection.
+    ///
+    /// Positions are counted in the running text of the document, as its plain text shows it:
+    /// `paragraph` is the index of the paragraph, from 0, and `character` the number of
+    /// characters before the cursor in that paragraph, from 0 (at the start) to the length
+    /// of the paragraph (at its end). Characters are grapheme clusters, which the cursor
+    /// moves over at once, e.g. a letter with a combining accent is one character. The
+    /// paragraphs of tables, frames and other objects aren't addressed reliably.
+    ///
+    /// LibreOfficeKit has no command taking such a position: the whole text is selected
+    /// to check the position, then the cursor is moved with `.uno:GoToStartOfDoc` and
+    /// `.uno:GoRight` over the characters and paragraph ends before the position, sent once
+    /// per `i16::MAX` steps since its `Count` is a 16 bit integer.
+    ///
+    /// # Arguments
+    /// * `paragraph` - the index of the paragraph, from 0
+    /// * `character` - the index of the character in the paragraph, from 0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/headings.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// doc.set_cursor_position(1, 3)?;
+    /// assert_eq!((1, 3), doc.get_cursor_position()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_cursor_position(&mut self, paragraph: i32, character: i32) -> Result<(), Error> {
+        self.check_text_cursor()?;
+        let invalid = || {
+            Error::new(format!(
+                "Invalid cursor position ({paragraph}, {character}), expected indexes from 0 \
+                 within the paragraphs of the document"
+            ))
+        };
+        let (Ok(paragraph), Ok(character)) =
+            (usize::try_from(paragraph), usize::try_from(character))
+        else {
+            return Err(invalid());
+        };
+
+        self.dispatch_uno_command(
+            ".uno:GoToStartOfDoc",
+            UnoArgs::new().boolean("Select", false).as_ref(),
+            false,
+        )?;
+        self.dispatch_uno_command(
+            ".uno:GoToEndOfDoc",
+            UnoArgs::new().boolean("Select", true).as_ref(),
+            false,
+        )?;
+        let text = self
+            .selected_text()
+            .unwrap_or_default()
+            .replace("\r\n", "\n");
+        let paragraphs = text.split('\n').collect::<Vec<_>>();
+        let length = paragraphs
+            .get(paragraph)
+            .map(|text| text.graphemes(true).count())
+            .ok_or_else(invalid)?;
+        if character > length {
+            return Err(invalid());
+        }
+
+        // a paragraph end is one step of the cursor
+        let before = paragraphs[..paragraph]
+            .iter()
+            .map(|text| text.graphemes(true).count() + 1)
+            .sum::<usize>();
+        self.move_cursor(before + character)
+    }
+
+    /// Returns the logical position of the text cursor of the current view of a text document,
+    /// as `(paragraph, character)`, see [Document::set_cursor_position] for the coordinate model.
+    ///
+    /// LibreOfficeKit only reports the cursor in twips (see [Document::cursor_rectangle]),
+    /// so the text from the start of the document to the cursor is selected and counted,
+    /// then the cursor is moved back there. Any selection is collapsed to the cursor.
+    pub fn get_cursor_position(&self) -> Result<(i32, i32), Error> {
+        self.check_text_cursor()?;
+
+        self.dispatch_uno_command(
+            ".uno:GoToStartOfDoc",
+            UnoArgs::new().boolean("Select", true).as_ref(),
+            false,
+        )?;
+        let before = self
+            .selected_text()
+            .unwrap_or_default()
+            .replace("\r\n", "\n");
+        // a paragraph end is one step of the cursor, and one grapheme cluster
+        self.move_cursor(before.graphemes(true).count())?;
+
+        let paragraph = before.matches('\n').count();
+        let character = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .graphemes(true)
+            .count();
+        let paragraph = i32::try_from(paragraph).map_err(|_| {
+            Error::new(format!("The document has too many paragraphs: {paragraph}"))
+        })?;
+        let character = i32::try_from(character).map_err(|_| {
+            Error::new(format!("The paragraph is too long: {character} characters"))
+        })?;
+        Ok((paragraph, character))
+    }
+
+    /// Checks that the document has a text cursor addressed by paragraphs
+    fn check_text_cursor(&self) -> Result<(), Error> {
+        let ty = self.get_document_type();
+        if ty != DocumentType::Text {
+            return Err(Error::new(format!(
+                "The cursor position can only be set in text documents, not in {ty:?} documents"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Moves the cursor the given number of steps from the start of the document
+    fn move_cursor(&self, steps: usize) -> Result<(), Error> {
+        self.dispatch_uno_command(
+            ".uno:GoToStartOfDoc",
+            UnoArgs::new().boolean("Select", false).as_ref(),
+            false,
+        )?;
+
+        // `Count` is a 16 bit integer
+        let mut remaining = steps;
+        while remaining > 0 {
+            let count = remaining.min(i16::MAX as usize);
+            self.dispatch_uno_command(
+                ".uno:GoRight",
+                UnoArgs::new()
+                    .boolean("Select", false)
+                    .short("Count", count as i16)
+                    .as_ref(),
+                false,
+            )?;
+            remaining -= count;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use crate::error::Error;
+use crate::xml::{attribute, to_twips};
+use crate::{Document, Rect};
+
+/// The kind of an object embedded in a document, see [Document::embedded_objects]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EmbeddedObjectKind {
+    Chart,
+    /// A Math formula
+    Formula,
+    Spreadsheet,
+    Text,
+    Drawing,
+    Presentation,
+    /// An object of another application, e.g. a Windows OLE object
+    Ole,
+}
+
+/// An object embedded in a document, e.g. a chart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedObject {
+    pub kind: EmbeddedObjectKind,
+    /// The name of the object, e.g. `Object 1`, empty when it has none
+    pub name: String,
+    /// The position and size (in twips) of the object, as stored in the document:
+    /// relative to its anchor (e.g. a paragraph) in text documents
+    pub rect: Rect,
+}
+
+impl Document {
+    /// Returns the objects embedded in the document, e.g. charts, formulas and OLE
+    /// objects, in document order.
+    ///
+    /// LibreOfficeKit only reports the selected object, with `LOK_CALLBACK_GRAPHIC_SELECTION`,
+    /// while the list is only kept by `XEmbeddedObjectSupplier` of the UNO API. The objects
+    /// are the `draw:frame`s holding a `draw:object` or a `draw:object-ole` in a flat ODF
+    /// export of the document.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{EmbeddedObjectKind, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/chart.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let objects = doc.embedded_objects()?;
+    /// assert_eq!(EmbeddedObjectKind::Chart, objects[0].kind);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn embedded_objects(&mut self) -> Result<Vec<EmbeddedObject>, Error> {
+        let content = self.flat_odf_export()?;
+        Ok(parse_embedded_objects(&content))
+    }
+}
+
+/// Returns the embedded objects of flat ODF content
+fn parse_embedded_objects(content: &str) -> Vec<EmbeddedObject> {
+    let mut objects = Vec::new();
+
+    for frame in content.split("<draw:frame ").skip(1) {
+        let Some(tag_end) = frame.find('>') else {
+            continue;
+        };
+        let (start_tag, body) = frame.split_at(tag_end);
+        let body = body[1..].trim_start();
+
+        let kind = if body.starts_with("<draw:object-ole") {
+            EmbeddedObjectKind::Ole
+        } else if body.starts_with("<draw:object") {
+            object_kind(body)
+        } else {
+            continue;
+        };
+
+        let length = |name: &str| attribute(start_tag, name).and_then(|value| to_twips(&value));
+        objects.push(EmbeddedObject {
+            kind,
+            name: attribute(start_tag, "draw:name").unwrap_or_default(),
+            rect: Rect::new(
+                length("svg:x").unwrap_or(0),
+                length("svg:y").unwrap_or(0),
+                length("svg:width").unwrap_or(0),
+                length("svg:height").unwrap_or(0),
+            ),
+        });
+    }
+
+    objects
+}
+
+/// Returns the kind of the document inlined in a `draw:object`, from its body element,
+/// e.g. `<office:body><office:chart>`
+fn object_kind(object: &str) -> EmbeddedObjectKind {
+    let element = object
+        .split_once("<office:body>")
+        .and_then(|(_, body)| body.trim_start().strip_prefix("<office:"))
+        .map(|body| &body[..body.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(0)]);
+
+    match element {
+        Some("chart") => EmbeddedObjectKind::Chart,
+        Some("formula") => EmbeddedObjectKind::Formula,
+        Some("spreadsheet") => EmbeddedObjectKind::Spreadsheet,
+        Some("text") => EmbeddedObjectKind::Text,
+        Some("drawing") => EmbeddedObjectKind::Drawing,
+        Some("presentation") => EmbeddedObjectKind::Presentation,
+        _ => EmbeddedObjectKind::Ole,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_embedded_objects, EmbeddedObject, EmbeddedObjectKind};
+    use crate::Rect;
+
+    #[test]
+    fn test_parse_embedded_objects() {
+        let content = r#"<office:text><text:p>
+            <draw:frame draw:name="Chart &amp; Data" svg:x="1cm" svg:y="0cm" svg:width="2in" svg:height="1in">
+              <draw:object><office:document><office:body><office:chart/></office:body></office:document></draw:object>
+            </draw:frame>
+            <draw:frame draw:name="Object 2" svg:width="1in" svg:height="1in"><draw:object-ole xlink:href="./Object 2"/></draw:frame>
+            <draw:frame draw:name="Image 1"><draw:image xlink:href="Pictures/1.png"/></draw:frame>
+            <draw:frame draw:name="Object 3"><draw:object xlink:href="./Object 3"/></draw:frame>
+            </text:p></office:text>"#;
+
+        assert_eq!(
+            vec![
+                EmbeddedObject {
+                    kind: EmbeddedObjectKind::Chart,
+                    name: "Chart & Data".to_string(),
+                    rect: Rect::new(567, 0, 2880, 1440),
+                },
+                EmbeddedObject {
+                    kind: EmbeddedObjectKind::Ole,
+                    name: "Object 2".to_string(),
+                    rect: Rect::new(0, 0, 1440, 1440),
+                },
+                EmbeddedObject {
+                    kind: EmbeddedObjectKind::Ole,
+                    name: "Object 3".to_string(),
+                    rect: Rect::default(),
+                },
+            ],
+            parse_embedded_objects(content)
+        );
+    }
+}
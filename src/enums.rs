@@ -0,0 +1,335 @@
+use std::convert::TryFrom;
+
+use crate::error::Error;
+
+/// Implements `TryFrom<i32>` for an enum mirroring a LibreOfficeKit enum,
+/// to decode the raw values of callbacks and FFI calls
+macro_rules! impl_try_from_i32 {
+    ($name:ident { $($variant:ident),+ $(,)? }) => {
+        impl TryFrom<i32> for $name {
+            type Error = Error;
+
+            fn try_from(value: i32) -> Result<$name, Error> {
+                $(
+                    if value == $name::$variant as i32 {
+                        return Ok($name::$variant);
+                    }
+                )+
+                Err(Error::new(format!(
+                    "Unknown {} value {value}",
+                    stringify!($name)
+                )))
+            }
+        }
+    };
+}
+
+/// Types of the events LibreOfficeKit reports to registered callbacks,
+/// mirroring `LibreOfficeKitCallbackType`.
+///
+/// @see [crate::Office::register_callback]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CallbackType {
+    InvalidateTiles = 0,
+    InvalidateVisibleCursor = 1,
+    TextSelection = 2,
+    TextSelectionStart = 3,
+    TextSelectionEnd = 4,
+    CursorVisible = 5,
+    GraphicSelection = 6,
+    HyperlinkClicked = 7,
+    StateChanged = 8,
+    StatusIndicatorStart = 9,
+    StatusIndicatorSetValue = 10,
+    StatusIndicatorFinish = 11,
+    SearchNotFound = 12,
+    DocumentSizeChanged = 13,
+    SetPart = 14,
+    SearchResultSelection = 15,
+    UnoCommandResult = 16,
+    CellCursor = 17,
+    MousePointer = 18,
+    CellFormula = 19,
+    DocumentPassword = 20,
+    DocumentPasswordToModify = 21,
+    Error = 22,
+    ContextMenu = 23,
+    InvalidateViewCursor = 24,
+    TextViewSelection = 25,
+    CellViewCursor = 26,
+    GraphicViewSelection = 27,
+    ViewCursorVisible = 28,
+    ViewLock = 29,
+    RedlineTableSizeChanged = 30,
+    RedlineTableEntryModified = 31,
+    Comment = 32,
+    InvalidateHeader = 33,
+    CellAddress = 34,
+    RulerUpdate = 35,
+    Window = 36,
+    ValidityListButton = 37,
+    ClipboardChanged = 38,
+    ContextChanged = 39,
+    SignatureStatus = 40,
+    ProfileFrame = 41,
+    CellSelectionArea = 42,
+    CellAutoFillArea = 43,
+    TableSelection = 44,
+    ReferenceMarks = 45,
+    JsDialog = 46,
+    CalcFunctionList = 47,
+    TabStopList = 48,
+    FormFieldButton = 49,
+    InvalidateSheetGeometry = 50,
+    ValidityInputHelp = 51,
+    DocumentBackgroundColor = 52,
+}
+
+/// The type of a loaded document, mirroring `LibreOfficeKitDocumentType`.
+///
+/// Like the other enums of this crate that mirror LibreOfficeKit, it is `#[non_exhaustive]`:
+/// new LibreOffice versions add values, so matches need a wildcard arm.
+///
+/// ```compile_fail
+/// use libreoffice_rs::DocumentType;
+///
+/// fn name(ty: DocumentType) -> &'static str {
+///     match ty {
+///         DocumentType::Text => "text",
+///         DocumentType::Spreadsheet => "spreadsheet",
+///         DocumentType::Presentation => "presentation",
+///         DocumentType::Drawing => "drawing",
+///         DocumentType::Other => "other",
+///     }
+/// }
+/// ```
+///
+/// @see [crate::Document::get_document_type]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentType {
+    Text = 0,
+    Spreadsheet = 1,
+    Presentation = 2,
+    Drawing = 3,
+    Other = 4,
+}
+
+impl DocumentType {
+    /// The side, in pixels, of the square tiles to render documents of this type with,
+    /// when the client has no preference, see [crate::Document::set_zoom].
+    ///
+    /// Text documents use the 256 pixel tiles of LibreOffice Online, which
+    /// follow the flow of the pages. Spreadsheets, presentations and drawings
+    /// are laid out in two dimensions and are viewed larger, they use 512 pixel
+    /// tiles to need a quarter of the calls.
+    ///
+    /// ```
+    /// use libreoffice_rs::DocumentType;
+    ///
+    /// assert_eq!(256, DocumentType::Text.default_tile_size());
+    /// assert_eq!(512, DocumentType::Presentation.default_tile_size());
+    /// ```
+    pub fn default_tile_size(self) -> u32 {
+        match self {
+            DocumentType::Spreadsheet | DocumentType::Presentation | DocumentType::Drawing => 512,
+            DocumentType::Text | DocumentType::Other => 256,
+        }
+    }
+
+    /// The resolution, in pixels per inch, to render documents of this type at,
+    /// when the client has no preference.
+    ///
+    /// Text documents and spreadsheets are read at screen resolution, [crate::units::SCREEN_DPI].
+    /// Slides and drawings are usually shown full screen: at 144 DPI, a 28 cm wide
+    /// slide is about 1600 pixels wide.
+    ///
+    /// ```
+    /// use libreoffice_rs::DocumentType;
+    ///
+    /// assert_eq!(96, DocumentType::Text.default_render_dpi());
+    /// assert_eq!(144, DocumentType::Presentation.default_render_dpi());
+    /// ```
+    pub fn default_render_dpi(self) -> u32 {
+        match self {
+            DocumentType::Presentation | DocumentType::Drawing => 144,
+            DocumentType::Text | DocumentType::Spreadsheet | DocumentType::Other => {
+                crate::units::SCREEN_DPI
+            }
+        }
+    }
+}
+
+/// The pixel format of rendered tiles, mirroring `LibreOfficeKitTileMode`.
+///
+/// @see [crate::Document::get_tile_mode]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TileMode {
+    /// Premultiplied RGBA
+    Rgba = 0,
+    /// Premultiplied BGRA
+    Bgra = 1,
+}
+
+/// The type of the current selection, mirroring `LOK_SELTYPE_*`
+///
+/// @see [crate::Document::get_selection_type]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SelectionType {
+    None = 0,
+    Text = 1,
+    /// A text selection too large to be copied as text
+    LargeText = 2,
+    /// A selection of e.g. images or shapes
+    Complex = 3,
+}
+
+/// The state of the digital signatures of a document, mirroring `SignatureState`
+///
+/// @see [crate::Document::get_signature_state]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SignatureState {
+    NoSignatures = 0,
+    Ok = 1,
+    Broken = 2,
+    Invalid = 3,
+    NotValidated = 4,
+    PartialOk = 5,
+    Unknown = 6,
+}
+
+/// The edit mode of a presentation: editing slides or their master slides.
+/// Other documents are always in the normal mode.
+///
+/// @see [crate::Document::get_edit_mode]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EditMode {
+    Normal = 0,
+    MasterPage = 1,
+}
+
+/// The type of a mouse event, mirroring `LibreOfficeKitMouseEventType`
+///
+/// @see [crate::Document::post_mouse_event]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MouseEventType {
+    ButtonDown = 0,
+    ButtonUp = 1,
+    Move = 2,
+}
+
+/// Whether documents may run their macros, e.g. on load, see [crate::Office::set_macro_security]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum MacroSecurity {
+    /// No macro runs, the default of LibreOfficeKit
+    #[default]
+    Disabled,
+    /// The medium security level of LibreOffice: macros of trusted locations run,
+    /// the other macros need a confirmation, which LibreOfficeKit doesn't ask for
+    Prompt,
+    /// Every macro runs, only for trusted documents
+    Enabled,
+}
+
+impl MacroSecurity {
+    /// The `documentLoadWithOptions` options of the level, empty for the LibreOfficeKit default
+    pub(crate) fn load_options(self) -> &'static str {
+        match self {
+            MacroSecurity::Disabled => "",
+            MacroSecurity::Prompt => "EnableMacrosExecution=true,MacroSecurityLevel=1",
+            MacroSecurity::Enabled => "EnableMacrosExecution=true,MacroSecurityLevel=0",
+        }
+    }
+}
+
+impl_try_from_i32!(CallbackType {
+    InvalidateTiles,
+    InvalidateVisibleCursor,
+    TextSelection,
+    TextSelectionStart,
+    TextSelectionEnd,
+    CursorVisible,
+    GraphicSelection,
+    HyperlinkClicked,
+    StateChanged,
+    StatusIndicatorStart,
+    StatusIndicatorSetValue,
+    StatusIndicatorFinish,
+    SearchNotFound,
+    DocumentSizeChanged,
+    SetPart,
+    SearchResultSelection,
+    UnoCommandResult,
+    CellCursor,
+    MousePointer,
+    CellFormula,
+    DocumentPassword,
+    DocumentPasswordToModify,
+    Error,
+    ContextMenu,
+    InvalidateViewCursor,
+    TextViewSelection,
+    CellViewCursor,
+    GraphicViewSelection,
+    ViewCursorVisible,
+    ViewLock,
+    RedlineTableSizeChanged,
+    RedlineTableEntryModified,
+    Comment,
+    InvalidateHeader,
+    CellAddress,
+    RulerUpdate,
+    Window,
+    ValidityListButton,
+    ClipboardChanged,
+    ContextChanged,
+    SignatureStatus,
+    ProfileFrame,
+    CellSelectionArea,
+    CellAutoFillArea,
+    TableSelection,
+    ReferenceMarks,
+    JsDialog,
+    CalcFunctionList,
+    TabStopList,
+    FormFieldButton,
+    InvalidateSheetGeometry,
+    ValidityInputHelp,
+    DocumentBackgroundColor,
+});
+impl_try_from_i32!(DocumentType {
+    Text,
+    Spreadsheet,
+    Presentation,
+    Drawing,
+    Other
+});
+impl_try_from_i32!(TileMode { Rgba, Bgra });
+impl_try_from_i32!(SelectionType {
+    None,
+    Text,
+    LargeText,
+    Complex
+});
+impl_try_from_i32!(SignatureState {
+    NoSignatures,
+    Ok,
+    Broken,
+    Invalid,
+    NotValidated,
+    PartialOk,
+    Unknown
+});
+impl_try_from_i32!(EditMode { Normal, MasterPage });
+impl_try_from_i32!(MouseEventType {
+    ButtonDown,
+    ButtonUp,
+    Move
+});
@@ -0,0 +1,182 @@
+use serde_json::{json, Map, Value};
+
+use crate::error::Error;
+use crate::urls::DocUrl;
+use crate::{Document, DocumentType};
+
+/// The `SplitMethod` values of the EPUB export filter
+const EPUB_SPLIT_HEADING: i32 = 0;
+const EPUB_SPLIT_PAGE_BREAK: i32 = 1;
+
+/// The `LayoutMethod` values of the EPUB export filter
+const EPUB_LAYOUT_REFLOWABLE: i32 = 0;
+const EPUB_LAYOUT_FIXED: i32 = 1;
+
+/// Options of the `EPUB` export filter, see [Document::export_epub]
+///
+/// Options that aren't set keep the defaults of LibreOffice: EPUB 3, a reflowable
+/// layout split into a file per heading, and no cover, unless an image named `cover`
+/// (`.gif`, `.jpg`, `.png` or `.svg`) lies next to the document.
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::EpubOptions;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = EpubOptions::new().version(2)?.split_by_heading(false);
+///
+/// assert_eq!(
+///     r#"{"EPUBVersion":{"type":"long","value":20},"SplitMethod":{"type":"long","value":1}}"#,
+///     options.to_filter_string()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EpubOptions {
+    /// `20` or `30`
+    version: Option<i32>,
+    split_by_heading: Option<bool>,
+    fixed_layout: Option<bool>,
+    cover_image: Option<DocUrl>,
+}
+
+impl EpubOptions {
+    /// Create an empty set of EPUB options, see [EpubOptions]
+    pub fn new() -> EpubOptions {
+        EpubOptions::default()
+    }
+
+    /// Set the version of EPUB to write
+    ///
+    /// # Arguments
+    ///  * `version` - `2` or `3`
+    pub fn version(mut self, version: u8) -> Result<EpubOptions, Error> {
+        match version {
+            2 | 3 => self.version = Some(i32::from(version) * 10),
+            _ => {
+                return Err(Error::new(format!(
+                    "Unsupported EPUB version {version}, expected 2 or 3"
+                )))
+            }
+        }
+        Ok(self)
+    }
+
+    /// Split the book into a file per heading (`true`), or per page break (`false`).
+    /// Readers usually load one file at a time, so long chapters open slower.
+    pub fn split_by_heading(mut self, split_by_heading: bool) -> EpubOptions {
+        self.split_by_heading = Some(split_by_heading);
+        self
+    }
+
+    /// Lay out the pages as in the document (`true`), instead of letting readers reflow
+    /// the text to their screen (`false`)
+    pub fn fixed_layout(mut self, fixed_layout: bool) -> EpubOptions {
+        self.fixed_layout = Some(fixed_layout);
+        self
+    }
+
+    /// Set the cover of the book, written as the `RVNGCoverImage` the EPUB export filter reads
+    ///
+    /// # Arguments
+    ///  * `image` - the URL of a PNG, JPEG, GIF or SVG image, e.g. from [crate::urls::local_into_abs]
+    pub fn cover_image(mut self, image: &DocUrl) -> EpubOptions {
+        self.cover_image = Some(image.clone());
+        self
+    }
+
+    /// Serialize the options into the JSON `FilterData` of the EPUB export filter,
+    /// empty when no option is set
+    pub fn to_filter_string(&self) -> String {
+        let mut filter_data = Map::new();
+        if let Some(version) = self.version {
+            filter_data.insert(
+                "EPUBVersion".into(),
+                json!({ "type": "long", "value": version }),
+            );
+        }
+        if let Some(split_by_heading) = self.split_by_heading {
+            let method = if split_by_heading {
+                EPUB_SPLIT_HEADING
+            } else {
+                EPUB_SPLIT_PAGE_BREAK
+            };
+            filter_data.insert(
+                "SplitMethod".into(),
+                json!({ "type": "long", "value": method }),
+            );
+        }
+        if let Some(fixed_layout) = self.fixed_layout {
+            let method = if fixed_layout {
+                EPUB_LAYOUT_FIXED
+            } else {
+                EPUB_LAYOUT_REFLOWABLE
+            };
+            filter_data.insert(
+                "LayoutMethod".into(),
+                json!({ "type": "long", "value": method }),
+            );
+        }
+        if let Some(image) = &self.cover_image {
+            filter_data.insert(
+                "RVNGCoverImage".into(),
+                json!({ "type": "string", "value": image.to_string() }),
+            );
+        }
+
+        if filter_data.is_empty() {
+            return String::new();
+        }
+        Value::Object(filter_data).to_string()
+    }
+}
+
+impl Document {
+    /// Exports a text document to an EPUB e-book.
+    ///
+    /// Only text documents can be exported to EPUB: the `EPUB` filter of LibreOffice
+    /// is a Writer filter, spreadsheets, presentations and drawings are an error.
+    /// Headings make the table of contents of the book, and its metadata (e.g. title,
+    /// author and language) is taken from the properties of the document.
+    ///
+    /// # Arguments
+    /// * `url` - the location of the EPUB file
+    /// * `options` - the options of the export, or `None` for the defaults, see [EpubOptions]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{EpubOptions, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/headings.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// let output_path = std::env::temp_dir().join("libreoffice_rs_export_epub.epub");
+    /// let output_url = urls::local_as_abs(output_path.display().to_string())?;
+    ///
+    /// doc.export_epub(&output_url, Some(EpubOptions::new().split_by_heading(true)))?;
+    /// let _ = std::fs::remove_file(&output_path);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_epub(&mut self, url: &DocUrl, options: Option<EpubOptions>) -> Result<(), Error> {
+        let ty = self.get_document_type();
+        if ty != DocumentType::Text {
+            return Err(Error::new(format!(
+                "Only text documents can be exported to EPUB, not {ty:?} documents"
+            )));
+        }
+
+        let filter = options.unwrap_or_default().to_filter_string();
+        if self.save_as(&url.to_string(), "epub", Some(&filter)) {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "Failed to export the document to {url} as EPUB"
+            )))
+        }
+    }
+}
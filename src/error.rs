@@ -1,24 +1,67 @@
 use std::fmt;
+use std::path::PathBuf;
 
-#[derive(Debug)]
-pub struct Error {
-    details: String,
+/// The errors of this crate, comparable to assert on them in tests
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An error reported by LibreOfficeKit, or a failed check of this crate
+    Message(String),
+    /// The target of a save already exists, see [crate::Document::save_as_no_overwrite]
+    FileExists { path: PathBuf },
+    /// The directory given to [crate::Office::new] doesn't contain the
+    /// LibreOfficeKit libraries
+    InstallationNotFound { searched: PathBuf },
+    /// LibreOfficeKit is already initialized from another installation,
+    /// see [crate::Office::new]
+    AlreadyInitialized { install_path: PathBuf },
+    /// LibreOfficeKit was destroyed along with the last [crate::Office] handle,
+    /// and can't be initialized again in the same process, see [crate::Office::new]
+    ReinitializationUnsupported,
+    /// A part index outside of the `count` parts of the document,
+    /// see [crate::Document::set_part]
+    PartOutOfRange { index: i32, count: i32 },
+    /// The operation was cancelled with its [crate::CancellationToken],
+    /// see [crate::Office::document_load_cancellable]
+    Cancelled,
 }
 
 impl Error {
     pub fn new(msg: String) -> Error {
-        Error { details: msg }
+        Error::Message(msg)
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.details)
+        match self {
+            Error::Message(details) => write!(f, "{}", details),
+            Error::FileExists { path } => write!(f, "The file {} already exists", path.display()),
+            Error::InstallationNotFound { searched } => write!(
+                f,
+                "No LibreOffice installation found in {}, expected the `program` directory \
+                 containing {}",
+                searched.display(),
+                crate::LOK_LIBRARIES.join(" or ")
+            ),
+            Error::AlreadyInitialized { install_path } => write!(
+                f,
+                "LibreOfficeKit is already initialized from {}, \
+                 it can only be initialized once per process",
+                install_path.display()
+            ),
+            Error::ReinitializationUnsupported => write!(
+                f,
+                "LibreOfficeKit was destroyed along with the last Office, \
+                 it can't be initialized again in the same process"
+            ),
+            Error::PartOutOfRange { index, count } => write!(
+                f,
+                "The part {index} is out of range, the document has {count} parts"
+            ),
+            Error::Cancelled => write!(f, "The operation was cancelled"),
+        }
     }
 }
 
-impl std::error::Error for Error {
-    fn description(&self) -> &str {
-        &self.details
-    }
-}
+impl std::error::Error for Error {}
@@ -0,0 +1,288 @@
+use std::io::Write;
+#[cfg(feature = "unstable")]
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::Error;
+use crate::{Document, DocumentType};
+
+/// Distinguishes the temporary exports of concurrent calls
+static EXPORT_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns a unique path in the temporary directory for an export,
+/// e.g. `libreoffice_rs_print_ranges_<pid>_<n>.fods`
+pub(crate) fn temp_export_path(name: &str, extension: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "libreoffice_rs_{name}_{}_{}.{extension}",
+        std::process::id(),
+        EXPORT_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// How much of the document an export may have lost, see [ExportWarning]
+#[cfg(feature = "unstable")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum LossSeverity {
+    /// Nothing is known to be lost, e.g. an informational message of LibreOffice
+    Info,
+    /// Some features may be dropped or approximated, e.g. by a legacy format
+    Minor,
+    /// Content is dropped, e.g. all but the text, or all but the first page
+    Major,
+}
+
+/// A possible loss of an export, see [ExportReport]
+#[cfg(feature = "unstable")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportWarning {
+    pub severity: LossSeverity,
+    pub message: String,
+}
+
+/// The outcome of [Document::export_checked]
+#[cfg(feature = "unstable")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExportReport {
+    /// Whether the document was saved
+    pub success: bool,
+    /// The known losses of the target format first, then the messages of LibreOffice
+    pub warnings: Vec<ExportWarning>,
+}
+
+#[cfg(feature = "unstable")]
+impl ExportReport {
+    /// Returns the highest severity of the warnings, `None` without warnings
+    ///
+    /// ```
+    /// use libreoffice_rs::{ExportReport, ExportWarning, LossSeverity};
+    ///
+    /// let mut report = ExportReport { success: true, warnings: Vec::new() };
+    /// assert_eq!(None, report.max_severity());
+    ///
+    /// report.warnings.push(ExportWarning { severity: LossSeverity::Minor, message: "...".into() });
+    /// report.warnings.push(ExportWarning { severity: LossSeverity::Info, message: "...".into() });
+    /// assert_eq!(Some(LossSeverity::Minor), report.max_severity());
+    /// ```
+    pub fn max_severity(&self) -> Option<LossSeverity> {
+        self.warnings.iter().map(|warning| warning.severity).max()
+    }
+}
+
+impl Document {
+    /// Exports the document into a writer, e.g. an in-memory buffer or a network upload,
+    /// returning the number of bytes written.
+    ///
+    /// LibreOfficeKit can only save to URLs, and not to a `private:stream` backed by
+    /// a callback, so the document is saved to a temporary file first, then copied
+    /// into the writer. The temporary file is removed in any case.
+    ///
+    /// # Arguments
+    /// * `format` - the format to export to, e.g. `pdf`, also the extension of the temporary file
+    /// * `filter` - options for the export filter, see [Document::save_as],
+    ///              except `TakeOwnership`, which fails since it would tie the document to
+    ///              the temporary file
+    /// * `out` - the writer receiving the exported document
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let mut pdf = Vec::new();
+    /// doc.export("pdf", None, &mut pdf)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export<W: Write>(
+        &mut self,
+        format: &str,
+        filter: Option<&str>,
+        out: &mut W,
+    ) -> Result<u64, Error> {
+        if format.is_empty() || !format.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(Error::new(format!("Invalid export format {format:?}")));
+        }
+        if filter.map_or(false, |filter| filter.contains("TakeOwnership")) {
+            return Err(Error::new(
+                "TakeOwnership can't be used to export, it would tie the document to a temporary file"
+                    .into(),
+            ));
+        }
+
+        let path = temp_export_path("export", format);
+        let saved = self.save_as(&path.display().to_string(), format, filter);
+        let copied = std::fs::File::open(&path).and_then(|mut file| std::io::copy(&mut file, out));
+        let _ = std::fs::remove_file(&path);
+        if !saved {
+            return Err(Error::new(format!(
+                "Failed to export the document as {format}"
+            )));
+        }
+
+        copied.map_err(|ex| Error::new(format!("Failed to write the {format} export! {ex}")))
+    }
+
+    /// Returns the content of the document in its flat ODF format (`fodt`, `fods`, `fodp`
+    /// or `fodg`): a single XML file, for reading what LibreOfficeKit doesn't report.
+    ///
+    /// The document is saved to a temporary file, see [Document::export], so this takes
+    /// as long as saving the document.
+    pub(crate) fn flat_odf_export(&mut self) -> Result<String, Error> {
+        let ty = self.get_document_type();
+        let format = match ty {
+            DocumentType::Text => "fodt",
+            DocumentType::Spreadsheet => "fods",
+            DocumentType::Presentation => "fodp",
+            DocumentType::Drawing => "fodg",
+            DocumentType::Other => {
+                return Err(Error::new(format!(
+                    "{ty:?} documents have no flat ODF format"
+                )))
+            }
+        };
+
+        let mut export = Vec::new();
+        self.export(format, None, &mut export)?;
+        String::from_utf8(export)
+            .map_err(|ex| Error::new(format!("The {format} export isn't UTF-8! {ex}")))
+    }
+
+    /// Stores the document to a URL like [Document::save_as], reporting what the conversion
+    /// may have lost, e.g. for batch jobs to flag the documents to review.
+    ///
+    /// The report lists the known losses of the target format for the type of the document
+    /// (e.g. plain text drops the formatting, CSV keeps only the current sheet, legacy binary
+    /// formats approximate newer features), then the messages of the `LOK_CALLBACK_ERROR`
+    /// callbacks received during the save, see [Document::save_as_with_warnings]. Their severity
+    /// follows the classification of LibreOffice: `info` messages are [LossSeverity::Info],
+    /// `error` messages [LossSeverity::Major], and the others [LossSeverity::Minor].
+    ///
+    /// LibreOffice reports little about lossy conversions, so a report without warnings doesn't
+    /// guarantee a faithful export. A failed save is reported with `success` set to `false`,
+    /// an `Err` is only returned for invalid arguments.
+    ///
+    /// # Arguments
+    /// * `url` - the location where to store the document
+    /// * `format` - the format to use while exporting, deduced from the URL's extension when empty
+    /// * `filter` - options for the export filter, see [Document::save_as]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{LossSeverity, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/chart.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let report = doc.export_checked("/tmp/chart.doc", "doc", None)?;
+    /// assert!(report.success);
+    /// if report.max_severity() >= Some(LossSeverity::Minor) {
+    ///     for warning in &report.warnings {
+    ///         eprintln!("{:?}: {}", warning.severity, warning.message);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn export_checked(
+        &mut self,
+        url: &str,
+        format: &str,
+        filter: Option<&str>,
+    ) -> Result<ExportReport, Error> {
+        if url.contains('\0') || format.contains('\0') || filter.unwrap_or_default().contains('\0')
+        {
+            return Err(Error::new(
+                "The URL, format and filter options can't contain NUL characters".to_string(),
+            ));
+        }
+
+        let target_format = if format.is_empty() {
+            Path::new(url)
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+                .unwrap_or_default()
+        } else {
+            format.to_ascii_lowercase()
+        };
+        let mut warnings: Vec<_> = format_losses(self.get_document_type(), &target_format)
+            .into_iter()
+            .map(|(severity, message)| ExportWarning {
+                severity,
+                message: message.to_string(),
+            })
+            .collect();
+
+        self.register_callback_buffer();
+        self.callbacks.collect_warnings();
+        let success = self.save_as(url, format, filter);
+        warnings.extend(self.callbacks.take_warnings().into_iter().map(
+            |(classification, message)| ExportWarning {
+                severity: match classification.as_str() {
+                    "info" => LossSeverity::Info,
+                    "error" => LossSeverity::Major,
+                    _ => LossSeverity::Minor,
+                },
+                message,
+            },
+        ));
+
+        Ok(ExportReport { success, warnings })
+    }
+}
+
+/// Returns the known losses of exporting a document of the given type to a format
+#[cfg(feature = "unstable")]
+fn format_losses(ty: DocumentType, format: &str) -> Vec<(LossSeverity, &'static str)> {
+    let mut losses = Vec::new();
+    match (ty, format) {
+        (_, "png" | "jpg" | "jpeg" | "gif" | "bmp" | "tif" | "tiff" | "webp" | "svg") => {
+            losses.push((
+                LossSeverity::Major,
+                "Image formats keep only the first page, as a picture",
+            ));
+        }
+        (DocumentType::Text, "txt") => {
+            losses.push((
+                LossSeverity::Major,
+                "Plain text keeps only the text, the formatting, images and objects are dropped",
+            ));
+        }
+        (DocumentType::Spreadsheet, "csv") => {
+            losses.push((
+                LossSeverity::Major,
+                "CSV keeps only the values of the current sheet, the formulas, formatting and other sheets are dropped",
+            ));
+        }
+        (_, "doc" | "xls" | "ppt" | "rtf") => {
+            losses.push((
+                LossSeverity::Minor,
+                "Legacy formats can't represent every feature, e.g. newer chart types, shapes and text effects",
+            ));
+        }
+        (_, "html" | "htm" | "xhtml") => {
+            losses.push((
+                LossSeverity::Minor,
+                "HTML keeps the content, but not the page layout",
+            ));
+        }
+        (_, "docx" | "xlsx" | "pptx") => {
+            losses.push((
+                LossSeverity::Info,
+                "Office Open XML may approximate some OpenDocument features",
+            ));
+        }
+        _ => {}
+    }
+    losses
+}
@@ -0,0 +1,444 @@
+use serde_json::{json, Map, Value};
+
+use crate::error::Error;
+use crate::units::TWIPS_PER_INCH;
+
+/// Image formats that LibreOffice exports one page at a time
+const IMAGE_FORMATS: [&str; 8] = ["png", "jpg", "jpeg", "gif", "bmp", "svg", "tiff", "webp"];
+
+/// The largest width or height of exported images, in pixels
+const MAX_IMAGE_PIXELS: u32 = 16384;
+
+/// The highest resolution of exported images, in pixels per inch, see [FilterOptions::dpi]
+const MAX_IMAGE_DPI: u32 = 2400;
+
+/// The text encodings of text and CSV exports, see [FilterOptions::encoding]:
+/// their `rtl_TextEncoding` name, which the text filter takes, and value, which the CSV filter takes
+const ENCODINGS: [(&str, u32); 7] = [
+    ("UTF8", 76),
+    ("UCS2", 0xFFFF),
+    ("ASCII_US", 11),
+    ("MS_1252", 1),
+    ("ISO_8859_1", 12),
+    ("ISO_8859_2", 13),
+    ("ISO_8859_15", 22),
+];
+
+/// The family of export filter a format resolves to, which decides
+/// how the filter options are passed to `saveAs`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportKind {
+    Pdf,
+    Image,
+    /// Plain text, from text documents
+    Text,
+    /// Comma separated values, from spreadsheets
+    Csv,
+    Other,
+}
+
+impl ExportKind {
+    fn from_format(format: &str) -> ExportKind {
+        let format = format.to_ascii_lowercase();
+        if format == "pdf" {
+            ExportKind::Pdf
+        } else if IMAGE_FORMATS.contains(&format.as_str()) {
+            ExportKind::Image
+        } else if format == "txt" {
+            ExportKind::Text
+        } else if format == "csv" {
+            ExportKind::Csv
+        } else {
+            ExportKind::Other
+        }
+    }
+}
+
+/// The PDF/A conformance levels of archival PDF exports
+///
+/// @see [FilterOptions::pdf_a]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PdfAConformance {
+    /// PDF/A-1b, based on PDF 1.4
+    A1b,
+    /// PDF/A-2b, based on PDF 1.7
+    A2b,
+    /// PDF/A-3b, PDF/A-2b allowing embedded files
+    A3b,
+}
+
+impl PdfAConformance {
+    /// The `SelectPdfVersion` value of the PDF export filter for this level
+    pub fn pdf_version(self) -> i32 {
+        match self {
+            PdfAConformance::A1b => 1,
+            PdfAConformance::A2b => 2,
+            PdfAConformance::A3b => 3,
+        }
+    }
+}
+
+/// Options for the export filter used by [crate::Document::save_as_with]
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::FilterOptions;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let options = FilterOptions::new().page_range("2-4")?;
+///
+/// assert_eq!(
+///     r#"{"PageRange":{"type":"string","value":"2-4"}}"#,
+///     options.to_filter_string("pdf")?
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FilterOptions {
+    page_range: Option<String>,
+    pdf_a: Option<PdfAConformance>,
+    /// The index in [ENCODINGS]
+    encoding: Option<usize>,
+    jpeg_quality: Option<u8>,
+    pixel_width: Option<u32>,
+    pixel_height: Option<u32>,
+    /// The exported area in 1/100 mm, see [FilterOptions::dpi]
+    logical_size: Option<(i64, i64)>,
+}
+
+impl FilterOptions {
+    /// Create an empty set of filter options
+    pub fn new() -> FilterOptions {
+        FilterOptions::default()
+    }
+
+    /// Restrict the export to a range of pages.
+    ///
+    /// The range is a comma separated list of page numbers (`3`) or
+    /// inclusive page spans (`2-4`), pages are numbered from 1.
+    ///
+    /// The range is passed as `PageRange` in the JSON `FilterData`, which both
+    /// the PDF and the image export filters understand. Image filters render a
+    /// single page, so only the first page of the range is used there.
+    ///
+    /// # Arguments
+    ///  * `range` - The pages to export, e.g. `2-4` or `1,3,5-7`
+    pub fn page_range(mut self, range: &str) -> Result<FilterOptions, Error> {
+        validate_page_range(range)?;
+        self.page_range = Some(range.to_owned());
+        Ok(self)
+    }
+
+    /// Export a PDF/A document of the given conformance level, for archival.
+    ///
+    /// The level is passed as `SelectPdfVersion` in the JSON `FilterData`,
+    /// only PDF exports accept it.
+    ///
+    /// # Arguments
+    ///  * `conformance` - The PDF/A level, e.g. [PdfAConformance::A2b]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{FilterOptions, PdfAConformance};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let options = FilterOptions::new().pdf_a(PdfAConformance::A2b);
+    ///
+    /// assert_eq!(
+    ///     r#"{"SelectPdfVersion":{"type":"long","value":2}}"#,
+    ///     options.to_filter_string("pdf")?
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pdf_a(mut self, conformance: PdfAConformance) -> FilterOptions {
+        self.pdf_a = Some(conformance);
+        self
+    }
+
+    /// Export text in the given encoding, instead of the system encoding.
+    ///
+    /// Only plain text (`txt`) and CSV (`csv`) exports accept it, and then only
+    /// the encoding can be set. The supported encodings are `UTF8`, `UCS2`, `ASCII_US`,
+    /// `MS_1252`, `ISO_8859_1`, `ISO_8859_2` and `ISO_8859_15`, ignoring case.
+    ///
+    /// # Arguments
+    ///  * `encoding` - The name of the encoding, e.g. `UTF8`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::FilterOptions;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let options = FilterOptions::new().encoding("UTF8")?;
+    ///
+    /// assert_eq!("UTF8", options.to_filter_string("txt")?);
+    /// assert_eq!("44,34,76", options.to_filter_string("csv")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encoding(mut self, encoding: &str) -> Result<FilterOptions, Error> {
+        let index = ENCODINGS
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case(encoding))
+            .ok_or_else(|| {
+                let names: Vec<_> = ENCODINGS.iter().map(|(name, _)| *name).collect();
+                Error::new(format!(
+                    "Unsupported encoding {encoding}, expected one of {}",
+                    names.join(", ")
+                ))
+            })?;
+        self.encoding = Some(index);
+        Ok(self)
+    }
+
+    /// Compress JPEG images to the given quality, instead of the default of 90:
+    /// lower qualities make smaller files, with more compression artifacts.
+    ///
+    /// The quality is passed as `Quality` in the JSON `FilterData`,
+    /// only JPEG image exports (`jpg` or `jpeg`) accept it.
+    ///
+    /// # Arguments
+    ///  * `quality` - The quality, from 1 (smallest) to 100 (best)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::FilterOptions;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let options = FilterOptions::new().jpeg_quality(75)?;
+    ///
+    /// assert_eq!(
+    ///     r#"{"Quality":{"type":"long","value":75}}"#,
+    ///     options.to_filter_string("jpg")?
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn jpeg_quality(mut self, quality: u8) -> Result<FilterOptions, Error> {
+        if !(1..=100).contains(&quality) {
+            return Err(Error::new(format!(
+                "Invalid JPEG quality {quality}, expected 1 to 100"
+            )));
+        }
+        self.jpeg_quality = Some(quality);
+        Ok(self)
+    }
+
+    /// Export images of the given width, instead of the default size.
+    ///
+    /// The width is passed as `PixelWidth` in the JSON `FilterData`, only image
+    /// exports accept it. Set the height too, with [FilterOptions::pixel_height],
+    /// to control the size of the image exactly.
+    ///
+    /// # Arguments
+    ///  * `width` - The width of the image, from 1 to 16384 pixels
+    pub fn pixel_width(mut self, width: u32) -> Result<FilterOptions, Error> {
+        validate_pixels("width", width)?;
+        self.pixel_width = Some(width);
+        Ok(self)
+    }
+
+    /// Export images of the given height, instead of the default size,
+    /// see [FilterOptions::pixel_width].
+    ///
+    /// The height is passed as `PixelHeight` in the JSON `FilterData`.
+    ///
+    /// # Arguments
+    ///  * `height` - The height of the image, from 1 to 16384 pixels
+    pub fn pixel_height(mut self, height: u32) -> Result<FilterOptions, Error> {
+        validate_pixels("height", height)?;
+        self.pixel_height = Some(height);
+        Ok(self)
+    }
+
+    /// Export images of a page at the given resolution.
+    ///
+    /// The image filters have no resolution setting, they scale the exported area to
+    /// the pixel size: the size of the page is needed to compute it, e.g. from
+    /// [crate::Document::get_part_page_rectangles] or [crate::Document::get_document_size].
+    /// It's passed as `PixelWidth` and `PixelHeight`, along with the size of the page as
+    /// `LogicalWidth` and `LogicalHeight` (in 1/100 mm), in the JSON `FilterData`.
+    /// Replaces the sizes set with [FilterOptions::pixel_width] and [FilterOptions::pixel_height].
+    ///
+    /// # Arguments
+    ///  * `dpi` - The resolution, from 1 to 2400 pixels per inch
+    ///  * `page_size` - The size of the exported page in twips, as `(width, height)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::FilterOptions;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // a 1 x 2 inch page
+    /// let options = FilterOptions::new().dpi(300, (1440, 2880))?;
+    ///
+    /// assert_eq!(
+    ///     concat!(
+    ///         r#"{"PixelWidth":{"type":"long","value":300},"PixelHeight":{"type":"long","value":600},"#,
+    ///         r#""LogicalWidth":{"type":"long","value":2540},"LogicalHeight":{"type":"long","value":5080}}"#
+    ///     ),
+    ///     options.to_filter_string("png")?
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dpi(mut self, dpi: u32, page_size: (i64, i64)) -> Result<FilterOptions, Error> {
+        if !(1..=MAX_IMAGE_DPI).contains(&dpi) {
+            return Err(Error::new(format!(
+                "Invalid resolution of {dpi} DPI, expected 1 to {MAX_IMAGE_DPI}"
+            )));
+        }
+        let (width, height) = page_size;
+        if width <= 0 || height <= 0 {
+            return Err(Error::new(format!(
+                "Invalid page size {width}x{height} twips"
+            )));
+        }
+        let to_pixels = |twips: i64| (twips * dpi as i64 + TWIPS_PER_INCH / 2) / TWIPS_PER_INCH;
+        let (pixel_width, pixel_height) = (to_pixels(width).max(1), to_pixels(height).max(1));
+        for (side, pixels) in [("width", pixel_width), ("height", pixel_height)] {
+            if pixels > MAX_IMAGE_PIXELS as i64 {
+                return Err(Error::new(format!(
+                    "An image {side} of {pixels} pixels is too large, expected at most {MAX_IMAGE_PIXELS}"
+                )));
+            }
+        }
+
+        // 1440 twips and 2540 1/100 mm per inch
+        let to_mm100 = |twips: i64| (twips * 127 + 36) / 72;
+        self.pixel_width = Some(pixel_width as u32);
+        self.pixel_height = Some(pixel_height as u32);
+        self.logical_size = Some((to_mm100(width), to_mm100(height)));
+        Ok(self)
+    }
+
+    /// Serialize the options into the filter options string LibreOfficeKit
+    /// expects for the given export format.
+    ///
+    /// # Arguments
+    ///  * `format` - The export format, e.g. `pdf` or `png`
+    pub fn to_filter_string(&self, format: &str) -> Result<String, Error> {
+        let kind = ExportKind::from_format(format);
+        let mut filter_data = Map::new();
+
+        if let Some(range) = &self.page_range {
+            if !matches!(kind, ExportKind::Pdf | ExportKind::Image) {
+                return Err(Error::new(format!(
+                    "Page ranges are only supported for PDF and image exports, not {format}"
+                )));
+            }
+            filter_data.insert(
+                "PageRange".into(),
+                json!({ "type": "string", "value": range }),
+            );
+        }
+
+        if let Some(conformance) = self.pdf_a {
+            if kind != ExportKind::Pdf {
+                return Err(Error::new(format!(
+                    "PDF/A is only supported for PDF exports, not {format}"
+                )));
+            }
+            filter_data.insert(
+                "SelectPdfVersion".into(),
+                json!({ "type": "long", "value": conformance.pdf_version() }),
+            );
+        }
+
+        if let Some(quality) = self.jpeg_quality {
+            if !matches!(format.to_ascii_lowercase().as_str(), "jpg" | "jpeg") {
+                return Err(Error::new(format!(
+                    "JPEG quality is only supported for JPEG exports, not {format}"
+                )));
+            }
+            filter_data.insert(
+                "Quality".into(),
+                json!({ "type": "long", "value": quality }),
+            );
+        }
+
+        let sizes = [
+            ("PixelWidth", self.pixel_width.map(i64::from)),
+            ("PixelHeight", self.pixel_height.map(i64::from)),
+            ("LogicalWidth", self.logical_size.map(|(width, _)| width)),
+            ("LogicalHeight", self.logical_size.map(|(_, height)| height)),
+        ];
+        for (name, size) in sizes {
+            let Some(size) = size else {
+                continue;
+            };
+            if kind != ExportKind::Image {
+                return Err(Error::new(format!(
+                    "Image sizes are only supported for image exports, not {format}"
+                )));
+            }
+            filter_data.insert(name.into(), json!({ "type": "long", "value": size }));
+        }
+
+        if let Some(index) = self.encoding {
+            let (name, value) = ENCODINGS[index];
+            // the text filters take a plain token string rather than JSON filter data:
+            // the ASCII options of Writer and the field separator, text delimiter
+            // and encoding of the CSV filter
+            return match kind {
+                ExportKind::Text if filter_data.is_empty() => Ok(name.to_owned()),
+                ExportKind::Csv if filter_data.is_empty() => Ok(format!("44,34,{value}")),
+                _ => Err(Error::new(format!(
+                    "Encodings are only supported alone, for txt and csv exports, not {format}"
+                ))),
+            };
+        }
+
+        if filter_data.is_empty() {
+            return Ok(String::new());
+        }
+
+        Ok(Value::Object(filter_data).to_string())
+    }
+}
+
+/// Checks the width or height of an exported image
+fn validate_pixels(side: &str, pixels: u32) -> Result<(), Error> {
+    if !(1..=MAX_IMAGE_PIXELS).contains(&pixels) {
+        return Err(Error::new(format!(
+            "Invalid image {side} of {pixels} pixels, expected 1 to {MAX_IMAGE_PIXELS}"
+        )));
+    }
+    Ok(())
+}
+
+/// Check a page range is a comma separated list of pages or ascending spans
+fn validate_page_range(range: &str) -> Result<(), Error> {
+    let invalid = || Error::new(format!("Invalid page range {range}!"));
+    let page = |value: &str| match value.trim().parse::<u32>() {
+        Ok(page) if page > 0 => Ok(page),
+        _ => Err(invalid()),
+    };
+
+    if range.trim().is_empty() {
+        return Err(invalid());
+    }
+
+    for part in range.split(',') {
+        match part.split_once('-') {
+            Some((start, end)) => {
+                if page(start)? > page(end)? {
+                    return Err(invalid());
+                }
+            }
+            None => {
+                page(part)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use crate::error::Error;
+use crate::xml::{attribute, to_twips, unescape};
+use crate::{Document, Rect};
+
+/// The kind of a fillable form field, see [Document::form_fields]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FormFieldKind {
+    /// A text box, also for passwords, formatted text and file names
+    Text,
+    CheckBox,
+    RadioButton,
+    ListBox,
+    ComboBox,
+    Date,
+    Number,
+    /// Another control taking input, e.g. a time field or a scroll bar
+    Other,
+}
+
+/// A fillable field of a form, see [Document::form_fields]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormField {
+    /// The name of the field, shared by the radio buttons of a group
+    pub name: String,
+    pub kind: FormFieldKind,
+    /// The current value of the field: the text of text boxes, `checked`, `unchecked`
+    /// or `unknown` for check boxes, `true` or `false` for radio buttons, and the
+    /// selected entries of list boxes, one per line
+    pub value: String,
+    /// The position and size (in twips) of the field, as stored in the document:
+    /// relative to its anchor (e.g. a paragraph) in text documents. Empty for the
+    /// legacy form fields of text documents, which flow with the text
+    pub rect: Rect,
+}
+
+impl Document {
+    /// Returns the fillable fields of the forms of the document: the form controls,
+    /// in the order of their forms, then the legacy form fields of text documents
+    /// (e.g. from Word documents), in document order. Buttons, labels, hidden
+    /// fields and other controls without input aren't listed.
+    ///
+    /// LibreOfficeKit reports neither form controls, which `XFormsSupplier::getForms` of
+    /// the draw pages lists, nor the fieldmarks of the text. They are the `form:form` and
+    /// `field:fieldmark` elements of a flat ODF export of the document.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/form.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// for field in doc.form_fields()? {
+    ///     println!("{} ({:?}): {}", field.name, field.kind, field.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn form_fields(&mut self) -> Result<Vec<FormField>, Error> {
+        let content = self.flat_odf_export()?;
+
+        let mut fields = parse_form_controls(&content);
+        fields.extend(parse_fieldmarks(&content));
+        Ok(fields)
+    }
+}
+
+/// Returns the form controls of flat ODF content, positioned by their `draw:control` shapes
+fn parse_form_controls(content: &str) -> Vec<FormField> {
+    let mut shapes = HashMap::new();
+    for shape in content.split("<draw:control ").skip(1) {
+        let start_tag = &shape[..shape.find('>').unwrap_or(shape.len())];
+        let length = |name: &str| attribute(start_tag, name).and_then(|value| to_twips(&value));
+        if let Some(control) = attribute(start_tag, "draw:control") {
+            let rect = Rect::new(
+                length("svg:x").unwrap_or(0),
+                length("svg:y").unwrap_or(0),
+                length("svg:width").unwrap_or(0),
+                length("svg:height").unwrap_or(0),
+            );
+            shapes.insert(control, rect);
+        }
+    }
+
+    let mut fields = Vec::new();
+    for (i, _) in content.match_indices("<form:") {
+        let element = &content[i + "<form:".len()..];
+        let Some(tag_end) = element.find('>') else {
+            continue;
+        };
+        let start_tag = &element[..tag_end];
+        let name_end = start_tag
+            .find(|c: char| c.is_whitespace() || c == '/')
+            .unwrap_or(start_tag.len());
+
+        let kind = match &start_tag[..name_end] {
+            "text" | "textarea" | "password" | "formatted-text" | "file" => FormFieldKind::Text,
+            "checkbox" => FormFieldKind::CheckBox,
+            "radio" => FormFieldKind::RadioButton,
+            "listbox" => FormFieldKind::ListBox,
+            "combobox" => FormFieldKind::ComboBox,
+            "date" => FormFieldKind::Date,
+            "number" => FormFieldKind::Number,
+            "time" | "value-range" | "generic-control" => FormFieldKind::Other,
+            _ => continue,
+        };
+
+        let current = |name: &str, default: &str| {
+            attribute(start_tag, name).or_else(|| attribute(start_tag, default))
+        };
+        let value = match kind {
+            FormFieldKind::CheckBox => current("form:current-state", "form:state")
+                .unwrap_or_else(|| "unchecked".to_string()),
+            FormFieldKind::RadioButton => current("form:current-selected", "form:selected")
+                .unwrap_or_else(|| "false".to_string()),
+            FormFieldKind::ListBox => {
+                let end = element.find("</form:listbox>").unwrap_or(tag_end);
+                selected_options(&element[tag_end..end]).join("\n")
+            }
+            _ => current("form:current-value", "form:value").unwrap_or_default(),
+        };
+
+        let id = attribute(start_tag, "form:id").or_else(|| attribute(start_tag, "xml:id"));
+        fields.push(FormField {
+            name: attribute(start_tag, "form:name").unwrap_or_default(),
+            kind,
+            value,
+            rect: id
+                .and_then(|id| shapes.get(&id).copied())
+                .unwrap_or_default(),
+        });
+    }
+
+    fields
+}
+
+/// Returns the labels of the selected `form:option`s of a list box
+fn selected_options(options: &str) -> Vec<String> {
+    let options: Vec<_> = options
+        .split("<form:option")
+        .skip(1)
+        .filter_map(|option| option.find('>').map(|end| &option[..end]))
+        .collect();
+    // the current selection, if the document has one, else the default selection
+    let selected = |name: &str| -> Vec<String> {
+        options
+            .iter()
+            .filter(|option| attribute(option, name).as_deref() == Some("true"))
+            .filter_map(|option| attribute(option, "form:label"))
+            .collect()
+    };
+
+    let has_current = options
+        .iter()
+        .any(|option| attribute(option, "form:current-selected").is_some());
+    if has_current {
+        selected("form:current-selected")
+    } else {
+        selected("form:selected")
+    }
+}
+
+/// Returns the legacy form fields of flat ODF text content: the `field:fieldmark`s
+/// (check boxes) and `field:fieldmark-start`s (text, drop-down and date fields)
+fn parse_fieldmarks(content: &str) -> Vec<FormField> {
+    let mut fields = Vec::new();
+    for fieldmark in content.split("<field:fieldmark").skip(1) {
+        let Some(tag_end) = fieldmark.find('>') else {
+            continue;
+        };
+        let start_tag = &fieldmark[..tag_end];
+        if start_tag.starts_with("-end") {
+            continue;
+        }
+        let Some(ty) = attribute(start_tag, "field:type") else {
+            continue;
+        };
+
+        let params: Vec<_> = fieldmark
+            .split("<field:param ")
+            .skip(1)
+            .filter_map(|param| {
+                let param = &param[..param.find('>')?];
+                Some((
+                    attribute(param, "field:name")?,
+                    attribute(param, "field:value").unwrap_or_default(),
+                ))
+            })
+            .collect();
+        let param = |name: &str| {
+            params
+                .iter()
+                .find(|(param, _)| param == name)
+                .map(|(_, value)| value.clone())
+        };
+
+        let (kind, value) = match ty.trim_start_matches("vnd.oasis.opendocument.field.") {
+            "FORMCHECKBOX" => {
+                let checked = param("Checkbox_Checked").as_deref() == Some("true");
+                let value = if checked { "checked" } else { "unchecked" };
+                (FormFieldKind::CheckBox, value.to_string())
+            }
+            "FORMDROPDOWN" => {
+                let entries: Vec<_> = params
+                    .iter()
+                    .filter(|(param, _)| param == "Dropdown_ListEntry")
+                    .map(|(_, entry)| entry.clone())
+                    .collect();
+                let selected = param("Dropdown_Selected")
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .and_then(|index| entries.get(index).cloned());
+                (FormFieldKind::ListBox, selected.unwrap_or_default())
+            }
+            "FORMTEXT" => (FormFieldKind::Text, fieldmark_text(&fieldmark[tag_end..])),
+            "FORMDATE" => (FormFieldKind::Date, fieldmark_text(&fieldmark[tag_end..])),
+            _ => continue,
+        };
+
+        fields.push(FormField {
+            name: attribute(start_tag, "text:name").unwrap_or_default(),
+            kind,
+            value,
+            rect: Rect::default(),
+        });
+    }
+
+    fields
+}
+
+/// Returns the text of a fieldmark, from the end of its start tag to its `field:fieldmark-end`,
+/// which ends the split content
+fn fieldmark_text(fieldmark: &str) -> String {
+    const START_END: &str = "</field:fieldmark-start>";
+    let content = match fieldmark.find(START_END) {
+        Some(end) => &fieldmark[end + START_END.len()..],
+        None => &fieldmark[1..],
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in content.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    unescape(&text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_fieldmarks, parse_form_controls, FormField, FormFieldKind};
+    use crate::Rect;
+
+    fn field(name: &str, kind: FormFieldKind, value: &str, rect: Rect) -> FormField {
+        FormField {
+            name: name.to_string(),
+            kind,
+            value: value.to_string(),
+            rect,
+        }
+    }
+
+    #[test]
+    fn test_parse_form_controls() {
+        let content = r#"<office:forms><form:form form:name="Standard">
+            <form:text form:name="Name" xml:id="control1" form:id="control1" form:current-value="Tom &amp; Jerry"/>
+            <form:checkbox form:name="Agree" form:id="control2" form:current-state="checked"/>
+            <form:radio/>
+            <form:listbox form:name="Colors" form:id="control3">
+              <form:properties><form:property form:property-name="Tag"/></form:properties>
+              <form:option form:label="Red" form:selected="true"/>
+              <form:option form:label="Blue" form:current-selected="true"/>
+            </form:listbox>
+            </form:form></office:forms>
+            <draw:control draw:control="control1" svg:x="1in" svg:y="2in" svg:width="1in" svg:height="0.25in"/>"#;
+
+        assert_eq!(
+            vec![
+                field(
+                    "Name",
+                    FormFieldKind::Text,
+                    "Tom & Jerry",
+                    Rect::new(1440, 2880, 1440, 360)
+                ),
+                field("Agree", FormFieldKind::CheckBox, "checked", Rect::default()),
+                field("", FormFieldKind::RadioButton, "false", Rect::default()),
+                field("Colors", FormFieldKind::ListBox, "Blue", Rect::default()),
+            ],
+            parse_form_controls(content)
+        );
+    }
+
+    #[test]
+    fn test_parse_fieldmarks() {
+        let content = r#"<office:text><text:p>
+            <field:fieldmark text:name="Check1" field:type="vnd.oasis.opendocument.field.FORMCHECKBOX">
+              <field:param field:name="Checkbox_Checked" field:value="true"/>
+            </field:fieldmark>
+            <field:fieldmark text:name="Drop1" field:type="vnd.oasis.opendocument.field.FORMDROPDOWN">
+              <field:param field:name="Dropdown_ListEntry" field:value="One"/>
+              <field:param field:name="Dropdown_ListEntry" field:value="Two"/>
+              <field:param field:name="Dropdown_Selected" field:value="1"/>
+            </field:fieldmark>
+            <field:fieldmark-start text:name="Text1" field:type="vnd.oasis.opendocument.field.FORMTEXT"/>Tom &amp; <text:span>Jerry</text:span><field:fieldmark-end/>
+            <field:fieldmark-start text:name="Date1" field:type="vnd.oasis.opendocument.field.FORMDATE"><field:param field:name="DateField_DateFormat" field:value="MM/DD/YY"/></field:fieldmark-start>10/16/26<field:fieldmark-end/>
+            </text:p></office:text>"#;
+
+        assert_eq!(
+            vec![
+                field(
+                    "Check1",
+                    FormFieldKind::CheckBox,
+                    "checked",
+                    Rect::default()
+                ),
+                field("Drop1", FormFieldKind::ListBox, "Two", Rect::default()),
+                field("Text1", FormFieldKind::Text, "Tom & Jerry", Rect::default()),
+                field("Date1", FormFieldKind::Date, "10/16/26", Rect::default()),
+            ],
+            parse_fieldmarks(content)
+        );
+    }
+}
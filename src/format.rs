@@ -0,0 +1,130 @@
+use std::fmt;
+
+/// Formats documents can be exported to, see [crate::Document::save_as]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OutputFormat {
+    Pdf,
+    Png,
+    Jpeg,
+    Gif,
+    Bmp,
+    Svg,
+    Odt,
+    Ods,
+    Odp,
+    Odg,
+    Docx,
+    Doc,
+    Xlsx,
+    Xls,
+    Pptx,
+    Ppt,
+    Rtf,
+    Txt,
+    Csv,
+    Html,
+}
+
+impl OutputFormat {
+    /// The format name passed to `saveAs`, which is also the file extension
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Pdf => "pdf",
+            OutputFormat::Png => "png",
+            OutputFormat::Jpeg => "jpg",
+            OutputFormat::Gif => "gif",
+            OutputFormat::Bmp => "bmp",
+            OutputFormat::Svg => "svg",
+            OutputFormat::Odt => "odt",
+            OutputFormat::Ods => "ods",
+            OutputFormat::Odp => "odp",
+            OutputFormat::Odg => "odg",
+            OutputFormat::Docx => "docx",
+            OutputFormat::Doc => "doc",
+            OutputFormat::Xlsx => "xlsx",
+            OutputFormat::Xls => "xls",
+            OutputFormat::Pptx => "pptx",
+            OutputFormat::Ppt => "ppt",
+            OutputFormat::Rtf => "rtf",
+            OutputFormat::Txt => "txt",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Html => "html",
+        }
+    }
+
+    /// Whether the format is an image, which holds a single page
+    pub fn is_image(&self) -> bool {
+        matches!(
+            self,
+            OutputFormat::Png
+                | OutputFormat::Jpeg
+                | OutputFormat::Gif
+                | OutputFormat::Bmp
+                | OutputFormat::Svg
+        )
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+/// The file types [crate::Office::document_load_as] can force,
+/// and [crate::Office::document_load_reader] can be hinted:
+/// their extension, MIME type and import filter name
+const IMPORT_TYPES: [(&str, &str, &str); 14] = [
+    ("odt", "application/vnd.oasis.opendocument.text", "writer8"),
+    (
+        "ods",
+        "application/vnd.oasis.opendocument.spreadsheet",
+        "calc8",
+    ),
+    (
+        "odp",
+        "application/vnd.oasis.opendocument.presentation",
+        "impress8",
+    ),
+    (
+        "odg",
+        "application/vnd.oasis.opendocument.graphics",
+        "draw8",
+    ),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "MS Word 2007 XML",
+    ),
+    (
+        "xlsx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "Calc MS Excel 2007 XML",
+    ),
+    (
+        "pptx",
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "Impress MS PowerPoint 2007 XML",
+    ),
+    ("doc", "application/msword", "MS Word 97"),
+    ("xls", "application/vnd.ms-excel", "MS Excel 97"),
+    ("ppt", "application/vnd.ms-powerpoint", "MS PowerPoint 97"),
+    ("rtf", "application/rtf", "Rich Text Format"),
+    ("txt", "text/plain", "Text"),
+    ("csv", "text/csv", "Text - txt - csv (StarCalc)"),
+    ("html", "text/html", "HTML (StarWriter)"),
+];
+
+/// Returns the file extension of a MIME type, an import filter name or an extension,
+/// ignoring case, e.g. `odt` for `application/vnd.oasis.opendocument.text` or `writer8`
+pub(crate) fn import_extension(mime_or_filter: &str) -> Option<&'static str> {
+    IMPORT_TYPES
+        .iter()
+        .find(|(extension, mime, filter)| {
+            mime.eq_ignore_ascii_case(mime_or_filter)
+                || filter.eq_ignore_ascii_case(mime_or_filter)
+                || extension.eq_ignore_ascii_case(mime_or_filter)
+        })
+        .map(|(extension, _, _)| *extension)
+}
@@ -0,0 +1,96 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::error::Error;
+
+/// A BCP 47 language tag as LibreOffice understands it, e.g. `en-US`,
+/// `pt-BR` or `sr-Latn-RS`.
+///
+/// The tag is validated on construction, so a typo like `en_US`
+/// fails early instead of silently leaving the language unchanged:
+///  * the language is 2 or 3 lowercase letters
+///  * a script is 4 letters, capitalized, e.g. `Latn`
+///  * a region is 2 uppercase letters or 3 digits
+///  * other subtags (variants, extensions) are up to 8 lowercase letters or digits
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::LanguageTag;
+/// use std::convert::TryFrom;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let tag = LanguageTag::try_from("en-US")?;
+///
+/// assert_eq!("en-US", tag.as_str());
+/// assert!(LanguageTag::try_from("en_US").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageTag(String);
+
+impl LanguageTag {
+    /// Validate a language tag, see [LanguageTag]
+    ///
+    /// # Arguments
+    /// * `tag` - the language tag, e.g. `de-CH`
+    pub fn new(tag: &str) -> Result<LanguageTag, Error> {
+        validate_language_tag(tag)?;
+        Ok(LanguageTag(tag.to_owned()))
+    }
+
+    /// Returns the tag as a string, e.g. `en-US`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for LanguageTag {
+    type Error = Error;
+
+    fn try_from(tag: &str) -> Result<LanguageTag, Error> {
+        LanguageTag::new(tag)
+    }
+}
+
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn validate_language_tag(tag: &str) -> Result<(), Error> {
+    let invalid = |reason: &str| Err(Error::new(format!("Invalid language tag {tag}! {reason}")));
+    let mut subtags = tag.split('-');
+
+    let language = subtags.next().unwrap_or_default();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_lowercase()) {
+        return invalid("The language must be 2 or 3 lowercase letters");
+    }
+
+    for subtag in subtags {
+        let letters = subtag.chars().all(|c| c.is_ascii_alphabetic());
+        let valid = match subtag.len() {
+            // region, e.g. US
+            2 if letters => subtag.chars().all(|c| c.is_ascii_uppercase()),
+            // region, e.g. 419
+            3 if subtag.chars().all(|c| c.is_ascii_digit()) => true,
+            // script, e.g. Latn
+            4 if letters => {
+                subtag.starts_with(|c: char| c.is_ascii_uppercase())
+                    && subtag[1..].chars().all(|c| c.is_ascii_lowercase())
+            }
+            // variants and extensions, e.g. valencia
+            1..=8 => subtag
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+            _ => false,
+        };
+        if !valid {
+            return invalid(&format!("Unexpected subtag {subtag:?}"));
+        }
+    }
+
+    Ok(())
+}
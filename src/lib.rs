@@ -7,24 +7,397 @@
 #![allow(clippy::all)]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+#[cfg(feature = "unstable")]
+mod annotation;
+mod builder;
+#[cfg(feature = "unstable")]
+mod callback;
+mod cancel;
+mod capabilities;
+#[cfg(feature = "unstable")]
+mod cell_range;
+mod coalesce;
+mod color;
+mod convert;
+mod csv;
+#[cfg(feature = "unstable")]
+mod cursor;
+mod embedded;
+mod enums;
+mod epub;
 mod error;
+mod export;
+mod filter;
+mod form;
+mod format;
+mod language;
+mod load_options;
+mod memory;
+mod metrics;
+#[cfg(feature = "unstable")]
+mod outline;
+/// The types most programs use, to import them all at once
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut office = Office::builder()
+///     .feature(LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD)
+///     .build("/usr/lib/libreoffice/program")?;
+/// let doc_url: DocUrl = urls::local_into_abs("./test_data/test.odt")?;
+/// let mut doc: Document = office.document_load(doc_url)?;
+///
+/// if doc.get_document_type() == DocumentType::Text {
+///     let options = FilterOptions::new().page_range("1")?;
+///     doc.save_as_with("/tmp/test.pdf", OutputFormat::Pdf.extension(), &options)?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub mod prelude;
+mod rect;
+#[cfg(feature = "image")]
+mod render;
+#[cfg(feature = "unstable")]
+mod spreadsheet;
+#[cfg(feature = "unstable")]
+mod style;
+#[cfg(feature = "unstable")]
+mod tracked_changes;
+pub mod units;
+mod uno;
 pub mod urls;
+mod version;
+#[cfg(feature = "unstable")]
+mod view;
+mod xml;
 
-use error::Error;
+#[cfg(feature = "unstable")]
+pub use annotation::Annotation;
+pub use builder::OfficeBuilder;
+#[cfg(feature = "unstable")]
+use callback::CallbackBuffer;
+pub use cancel::CancellationToken;
+pub use capabilities::Capabilities;
+#[cfg(feature = "unstable")]
+pub use cell_range::CellRange;
+pub use coalesce::{InvalidationCoalescer, WHOLE_DOCUMENT};
+pub use color::Color;
+pub use csv::CsvImportOptions;
+pub use embedded::{EmbeddedObject, EmbeddedObjectKind};
+pub use enums::{
+    CallbackType, DocumentType, EditMode, MacroSecurity, MouseEventType, SelectionType,
+    SignatureState, TileMode,
+};
+pub use epub::EpubOptions;
+pub use error::Error;
+#[cfg(feature = "unstable")]
+pub use export::{ExportReport, ExportWarning, LossSeverity};
+pub use filter::{FilterOptions, PdfAConformance};
+pub use form::{FormField, FormFieldKind};
+pub use format::OutputFormat;
+pub use language::LanguageTag;
+use load_options::validate_load_options;
+pub use memory::MemoryStats;
+use metrics::Metrics;
+#[cfg(feature = "unstable")]
+pub use outline::OutlineEntry;
+pub use rect::Rect;
+#[cfg(feature = "image")]
+pub use render::RenderedTile;
+#[cfg(feature = "unstable")]
+pub use style::StyleFamily;
+#[cfg(feature = "unstable")]
+pub use tracked_changes::{TrackedChange, TrackedChangeKind};
+pub use uno::UnoArgs;
 use urls::DocUrl;
+pub use version::VersionInfo;
+#[cfg(feature = "unstable")]
+pub use view::ViewInfo;
 
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+#[cfg(feature = "unstable")]
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
 use std::ffi::{CStr, CString};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+
+/// Environment variable overriding the LibreOffice `program` directory used by [Office::new_auto]
+pub const LIBREOFFICE_PROGRAM_ENV: &str = "LIBREOFFICE_PROGRAM";
+
+/// The libraries `lok_init` loads from the `program` directory, one of them must exist
+#[cfg(target_os = "macos")]
+const LOK_LIBRARIES: [&str; 2] = ["libsofficeapp.dylib", "libmergedlo.dylib"];
+#[cfg(windows)]
+const LOK_LIBRARIES: [&str; 2] = ["sofficeapp.dll", "mergedlo.dll"];
+#[cfg(not(any(target_os = "macos", windows)))]
+const LOK_LIBRARIES: [&str; 2] = ["libsofficeapp.so", "libmergedlo.so"];
+
+/// The environment variables LibreOffice reads its temporary directory from
+#[cfg(windows)]
+const TEMP_DIR_ENVS: [&str; 2] = ["TMP", "TEMP"];
+#[cfg(not(windows))]
+const TEMP_DIR_ENVS: [&str; 1] = ["TMPDIR"];
 
 /// A Wrapper for the `LibreOfficeKit` C API.
+///
+/// LibreOfficeKit can only be initialized once per process: every [Office]
+/// is a handle to the same instance, which is destroyed along with the last handle.
+/// It can't be initialized again afterwards, so keep an [Office] alive for the
+/// lifetime of the process, and hand out clones of it.
 #[derive(Clone)]
 pub struct Office {
+    instance: Arc<Instance>,
+    /// The `install_path` given to [Office::new]
+    install_path: String,
     lok: *mut LibreOfficeKit,
     lok_clz: *mut LibreOfficeKitClass,
+    password_prompts: Arc<PasswordPrompts>,
+    /// The data of the registered callback, freed once it's replaced or unregistered
+    callback_data: Arc<AtomicPtr<OfficeCallbackData>>,
+    /// The hash of the last error [Office::get_error] reported or [Office::reset] cleared,
+    /// which it doesn't report again, 0 for none
+    seen_error: Arc<AtomicU64>,
+}
+
+/// The LibreOfficeKit instance of the process, shared by every [Office] handle
+/// and destroyed along with the last one
+struct Instance {
+    lok: *mut LibreOfficeKit,
+    /// Whether the instance is destroyed along with the last handle,
+    /// see [Office::from_raw_borrowed]
+    owned: bool,
+    /// The `program` directory the instance was initialized from,
+    /// empty for an instance adopted with [Office::from_raw]
+    install_path: PathBuf,
+    password_prompts: Arc<PasswordPrompts>,
+    callback_data: Arc<AtomicPtr<OfficeCallbackData>>,
+    seen_error: Arc<AtomicU64>,
+    /// The language documents are loaded with, see [Office::set_ui_language]
+    ui_language: Mutex<Option<LanguageTag>>,
+    /// Whether documents run their macros, see [Office::set_macro_security]
+    macro_security: Mutex<MacroSecurity>,
+    metrics: Metrics,
+    /// The errors reported through callbacks, see [Office::take_error]
+    async_errors: Arc<AsyncErrors>,
+    /// The flags of the optional features set, see [Office::get_optional_features]
+    optional_features: AtomicU64,
+}
+
+// The instance is only shared to hand out new [Office] handles,
+// LibreOfficeKit serializes its calls with its own global lock
+unsafe impl Send for Instance {}
+unsafe impl Sync for Instance {}
+
+/// The LibreOfficeKit instance of the process, while an [Office] handle is alive
+static INSTANCE: Mutex<Option<Weak<Instance>>> = Mutex::new(None);
+
+/// Whether an instance initialized by this crate was destroyed, which LibreOfficeKit
+/// can't initialize again, see [Error::ReinitializationUnsupported]
+static DESTROYED: AtomicBool = AtomicBool::new(false);
+
+impl Instance {
+    fn new(lok: *mut LibreOfficeKit, install_path: PathBuf, owned: bool) -> Arc<Instance> {
+        Arc::new(Instance {
+            lok,
+            owned,
+            install_path,
+            password_prompts: Arc::default(),
+            callback_data: Arc::default(),
+            seen_error: Arc::default(),
+            ui_language: Mutex::default(),
+            macro_security: Mutex::default(),
+            metrics: Metrics::default(),
+            async_errors: Arc::default(),
+            optional_features: AtomicU64::new(0),
+        })
+    }
+
+    /// Creates a new handle to the instance
+    ///
+    /// # Arguments
+    /// * `install_path` - the path the handle was requested for
+    fn office(self: &Arc<Instance>, install_path: &str) -> Office {
+        Office {
+            instance: Arc::clone(self),
+            install_path: install_path.to_string(),
+            lok: self.lok,
+            lok_clz: if self.lok.is_null() {
+                std::ptr::null_mut()
+            } else {
+                unsafe { (*self.lok).pClass }
+            },
+            password_prompts: Arc::clone(&self.password_prompts),
+            callback_data: Arc::clone(&self.callback_data),
+            seen_error: Arc::clone(&self.seen_error),
+        }
+    }
+}
+
+impl Drop for Instance {
+    fn drop(&mut self) {
+        if self.lok.is_null() {
+            return;
+        }
+        unsafe {
+            let callback_data = self
+                .callback_data
+                .swap(std::ptr::null_mut(), Ordering::AcqRel);
+            if !callback_data.is_null() {
+                // a borrowed instance outlives this crate's handles, and must not call
+                // the callback once its data is freed
+                (*(*self.lok).pClass).registerCallback.unwrap()(
+                    self.lok,
+                    None,
+                    std::ptr::null_mut(),
+                );
+                drop_callback_data(callback_data);
+            }
+            if !self.owned {
+                return;
+            }
+            (*(*self.lok).pClass).destroy.unwrap()(self.lok);
+        }
+        DESTROYED.store(true, Ordering::Release);
+    }
+}
+
+/// Decides which password prompts of the document being loaded the [Office] callback
+/// answers with no password, aborting the load: the ones past the retry limit, and all
+/// of them once the load is cancelled. Shared by an [Office], its clones and its callback.
+///
+/// @see [Office::set_password_retry_limit], [Office::document_load_cancellable]
+#[derive(Default)]
+struct PasswordPrompts {
+    /// The maximum number of password requests per load, 0 means unlimited
+    limit: AtomicU32,
+    attempts: AtomicU32,
+    cancellation: Mutex<Option<CancellationToken>>,
+}
+
+impl PasswordPrompts {
+    /// Counts a password request, returns whether it exceeds the limit
+    fn exceeded(&self) -> bool {
+        let limit = self.limit.load(Ordering::Acquire);
+        let attempts = self.attempts.fetch_add(1, Ordering::AcqRel) + 1;
+        limit > 0 && attempts > limit
+    }
+
+    fn reset(&self) {
+        self.attempts.store(0, Ordering::Release);
+    }
+
+    /// Sets the token cancelling the document being loaded, `None` once loaded
+    fn set_cancellation(&self, token: Option<CancellationToken>) {
+        *self.cancellation.lock().unwrap_or_else(|e| e.into_inner()) = token;
+    }
+
+    /// Whether loading the document was cancelled
+    fn cancelled(&self) -> bool {
+        match &*self.cancellation.lock().unwrap_or_else(|e| e.into_inner()) {
+            Some(token) => token.is_cancelled(),
+            None => false,
+        }
+    }
+}
+
+/// The most errors [AsyncErrors] keeps, older errors are dropped
+const MAX_ASYNC_ERRORS: usize = 32;
+
+/// The payloads of the `LOK_CALLBACK_ERROR` events, which LibreOfficeKit reports
+/// to the [Office] callback instead of returning them, e.g. while loading a document.
+///
+/// @see [Office::take_error], [Office::set_fail_load_on_error]
+#[derive(Default)]
+struct AsyncErrors {
+    /// The unread errors, oldest first
+    errors: Mutex<VecDeque<String>>,
+    /// The number of errors received so far, including the dropped ones
+    received: AtomicU64,
+    /// Whether loading a document fails when it reports an error
+    fail_load: AtomicBool,
 }
 
+impl AsyncErrors {
+    fn push(&self, error: String) {
+        let mut errors = self.errors.lock().unwrap_or_else(|e| e.into_inner());
+        if errors.len() == MAX_ASYNC_ERRORS {
+            errors.pop_front();
+        }
+        errors.push_back(error);
+        self.received.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Removes and returns the errors received since `received` was the count
+    fn take_since(&self, received: u64) -> Vec<String> {
+        let mut errors = self.errors.lock().unwrap_or_else(|e| e.into_inner());
+        let count = self.received.load(Ordering::Acquire) - received;
+        let start = errors.len().saturating_sub(count as usize);
+        errors.drain(start..).collect()
+    }
+
+    fn clear(&self) {
+        self.errors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// The data handed to LibreOfficeKit along with the [Office] callback shim
+struct OfficeCallbackData {
+    callback: Box<dyn FnMut(&mut OfficeRef<'_>, std::os::raw::c_int, *const std::os::raw::c_char)>,
+    lok: *mut LibreOfficeKit,
+    password_prompts: Arc<PasswordPrompts>,
+    async_errors: Arc<AsyncErrors>,
+    /// Whether a panic of the callback aborts the process, see [Office::register_callback_abort_on_panic]
+    abort_on_panic: bool,
+}
+
+/// A non-owning handle to an [Office], handed to the callbacks registered with
+/// [Office::register_callback_with_office].
+///
+/// LibreOfficeKit invokes callbacks while one of its functions is running, e.g.
+/// [Office::document_load], on the same thread, so the [Office] is already borrowed.
+/// The handle only exposes what LibreOfficeKit supports being called from within
+/// a callback and can't outlive the callback invocation.
+pub struct OfficeRef<'a> {
+    lok: *mut LibreOfficeKit,
+    _office: std::marker::PhantomData<&'a mut Office>,
+}
+
+/// How long [Document::run_macro] waits for the macro to finish
+#[cfg(feature = "unstable")]
+const MACRO_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
 /// A Wrapper for the `LibreOfficeKitDocument` C API.
 pub struct Document {
     doc: *mut LibreOfficeKitDocument,
+    #[cfg(feature = "unstable")]
+    callbacks: Box<CallbackBuffer>,
+    #[cfg(feature = "unstable")]
+    callbacks_registered: std::cell::Cell<bool>,
+    #[cfg(feature = "unstable")]
+    rendering_initialized: bool,
+    /// The authors of the views created with [Document::create_view_with_author]
+    #[cfg(feature = "unstable")]
+    view_authors: HashMap<i32, String>,
+    /// The views made read-only with [Document::set_view_read_only]
+    #[cfg(feature = "unstable")]
+    read_only_views: HashSet<i32>,
+    /// The URL the document was loaded from
+    url: DocUrl,
+    /// The temporary copy the document was loaded from, removed along with the document,
+    /// see [Office::document_load_as]
+    temp_file: Option<PathBuf>,
+    metrics: Metrics,
 }
 
 /// Optional features of LibreOfficeKit, in particular callbacks that block
@@ -32,7 +405,8 @@ pub struct Document {
 ///  deadlock if the client does not support the feature.
 ///
 ///  @see [Office::set_optional_features]
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum LibreOfficeKitOptionalFeatures {
     /// Handle `LOK_CALLBACK_DOCUMENT_PASSWORD` by prompting the user for a password.
     ///
@@ -60,6 +434,20 @@ pub enum LibreOfficeKitOptionalFeatures {
 impl Office {
     /// Create a new LibreOfficeKit instance.
     ///
+    /// Fails with [Error::InstallationNotFound] when `install_path` doesn't
+    /// contain the LibreOfficeKit libraries.
+    ///
+    /// LibreOfficeKit can only be initialized once per process: while an [Office]
+    /// is alive, creating another one from the same `install_path` returns a handle
+    /// to the same instance, and fails with [Error::AlreadyInitialized] for any
+    /// other `install_path`. An instance adopted with [Office::from_raw] is shared
+    /// whatever the `install_path`.
+    ///
+    /// Once the last handle is dropped, LibreOfficeKit is destroyed and LibreOffice
+    /// doesn't support initializing it again in the same process: trying to would hang,
+    /// so this fails with [Error::ReinitializationUnsupported] instead. Keep an [Office]
+    /// alive for the lifetime of the process.
+    ///
     /// # Arguments
     ///
     ///  * `install_path` - The path to the LibreOffice installation.
@@ -77,15 +465,88 @@ impl Office {
     /// # }
     /// ```
     pub fn new(install_path: &str) -> Result<Office, Error> {
+        Office::init(install_path, None, None)
+    }
+
+    /// Initializes LibreOfficeKit like [Office::new], with the user profile at the
+    /// given URL, or the default one for `None`, and the given temporary directory.
+    /// Fails when LibreOfficeKit is already initialized and a user profile or a
+    /// temporary directory is given, which it can't switch to.
+    ///
+    /// # Arguments
+    ///
+    ///  * `install_path` - The path to the LibreOffice installation.
+    ///  * `user_profile_url` - The `file://` URL of the user profile directory
+    ///  * `temp_dir` - The directory LibreOffice writes its temporary files to
+    fn init(
+        install_path: &str,
+        user_profile_url: Option<&str>,
+        temp_dir: Option<&Path>,
+    ) -> Result<Office, Error> {
+        // lok_init can't report a missing library, check for it up front
+        let program_dir = Path::new(install_path);
+        if !LOK_LIBRARIES
+            .iter()
+            .any(|lib| program_dir.join(lib).is_file())
+        {
+            return Err(Error::InstallationNotFound {
+                searched: program_dir.to_path_buf(),
+            });
+        }
+
+        let mut current = INSTANCE.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(instance) = current.as_ref().and_then(Weak::upgrade) {
+            // an adopted instance has no known path, see [Office::from_raw]
+            if let Some(user_profile_url) = user_profile_url {
+                return Err(Error::new(format!(
+                    "LibreOfficeKit is already initialized, it can't switch to the user profile {user_profile_url}"
+                )));
+            }
+            if let Some(temp_dir) = temp_dir {
+                return Err(Error::new(format!(
+                    "LibreOfficeKit is already initialized, it can't switch to the temporary directory {}",
+                    temp_dir.display()
+                )));
+            }
+            if instance.install_path.as_os_str().is_empty()
+                || same_install_path(&instance.install_path, program_dir)
+            {
+                return Ok(instance.office(install_path));
+            }
+            return Err(Error::AlreadyInitialized {
+                install_path: instance.install_path.clone(),
+            });
+        }
+
+        if DESTROYED.load(Ordering::Acquire) {
+            return Err(Error::ReinitializationUnsupported);
+        }
+        // no thread of LibreOffice reads the environment yet
+        if let Some(temp_dir) = temp_dir {
+            set_temp_dir_env(temp_dir)?;
+        }
+
         let c_install_path = CString::new(install_path).unwrap();
         unsafe {
-            let lok = lok_init_wrapper(c_install_path.as_ptr());
+            let lok = match user_profile_url {
+                Some(user_profile_url) => {
+                    let c_user_profile_url = CString::new(user_profile_url).unwrap();
+                    lok_init_2_wrapper(c_install_path.as_ptr(), c_user_profile_url.as_ptr())
+                }
+                None => lok_init_wrapper(c_install_path.as_ptr()),
+            };
+            if lok.is_null() {
+                return Err(Error::new(format!(
+                    "Failed to initialize LibreOfficeKit from {install_path}"
+                )));
+            }
             let raw_error = (*(*lok).pClass).getError.unwrap()(lok);
             match *raw_error {
-                0 => Ok(Office {
-                    lok,
-                    lok_clz: (*lok).pClass,
-                }),
+                0 => {
+                    let instance = Instance::new(lok, program_dir.to_path_buf(), true);
+                    *current = Some(Arc::downgrade(&instance));
+                    Ok(instance.office(install_path))
+                }
                 _ => Err(Error::new(
                     CStr::from_ptr(raw_error).to_string_lossy().into_owned(),
                 )),
@@ -93,443 +554,3903 @@ impl Office {
         }
     }
 
-    fn destroy(&mut self) {
-        unsafe {
-            (*self.lok_clz).destroy.unwrap()(self.lok);
-        }
-    }
-
-    /// Returns the last error as a string
-    pub fn get_error(&mut self) -> String {
-        unsafe {
-            let raw_error = (*self.lok_clz).getError.unwrap()(self.lok);
-            CStr::from_ptr(raw_error).to_string_lossy().into_owned()
-        }
+    /// Returns the path to the LibreOffice installation, as given to [Office::new]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let office = Office::new("/usr/lib/libreoffice/program")?;
+    ///
+    /// assert_eq!("/usr/lib/libreoffice/program", office.install_path());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn install_path(&self) -> &str {
+        &self.install_path
     }
 
-    /// Registers a callback. LOK will invoke this function when it wants to
-    /// inform the client about events.
+    /// Wraps a LibreOfficeKit instance initialized elsewhere, e.g. by a C++ host
+    /// embedding this crate, rather than initializing it with [Office::new].
     ///
-    /// # Arguments
+    /// The [Office] takes ownership of the instance: it is destroyed along with the
+    /// last handle, like one created by [Office::new]. Use [Office::from_raw_borrowed]
+    /// when the host destroys it. While an [Office] wrapping the same pointer is alive,
+    /// this returns another handle to it, which keeps its ownership.
+    /// [Office::new] returns handles to the adopted instance whatever its `install_path`,
+    /// and [Office::install_path] is empty.
     ///
-    ///  * `cb` - the callback to invoke (type, payload)
+    /// # Safety
+    /// `lok` must be a valid instance returned by `lok_init`, not yet destroyed, and
+    /// it must be the only LibreOfficeKit instance of the process wrapped by an [Office].
+    /// The host must not destroy it while an [Office] owns it.
+    ///
+    /// # Arguments
+    ///  * `lok` - The LibreOfficeKit instance
     ///
     /// # Example
     ///
     /// ```
-    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures};
+    /// use libreoffice_rs::{LibreOfficeKit, Office};
     ///
+    /// # fn host_lok() -> *mut LibreOfficeKit {
+    /// #     let office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    /// #     let lok = office.as_raw();
+    /// #     std::mem::forget(office);
+    /// #     lok
+    /// # }
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
-    /// office.set_optional_features(
-    ///    [LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD]
-    /// )?;
-    ///
-    /// office.register_callback(Box::new({
-    ///     move |_type, _payload| {
-    ///         println!("Call set_document_password and/or do something here!");
-    ///     }
-    /// }))?;
+    /// // e.g. initialized by the C++ code of the process
+    /// let lok: *mut LibreOfficeKit = host_lok();
+    /// let office = unsafe { Office::from_raw(lok) };
     ///
+    /// assert_eq!(lok, office.as_raw());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn register_callback<
-        F: FnMut(std::os::raw::c_int, *const std::os::raw::c_char) + 'static,
-    >(
-        &mut self,
-        cb: F,
-    ) -> Result<(), Error> {
-        unsafe {
-            /// Callback that Libreoffice will invoke. The actual user callback
-            /// is provided as the data value to this callback
-            ///
-            /// LibreOfficeKitCallback typedef (int nType, const char* pPayload, void* pData);
-            unsafe extern "C" fn callback_shim(
-                ty: std::os::raw::c_int,
-                payload: *const std::os::raw::c_char,
-                data: *mut std::os::raw::c_void,
-            ) {
-                // Get the callback function from the data argument
-                let callback: *mut Box<
-                    dyn FnMut(std::os::raw::c_int, *const std::os::raw::c_char),
-                > = data.cast();
+    pub unsafe fn from_raw(lok: *mut LibreOfficeKit) -> Office {
+        Office::adopt(lok, true)
+    }
 
-                // Catch panics from calling the callback
-                _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
-                    // Invoke the callback
-                    (**callback)(ty, payload);
-                }));
+    /// Wraps a LibreOfficeKit instance initialized elsewhere like [Office::from_raw],
+    /// without taking ownership: the instance isn't destroyed along with the last handle,
+    /// the host remains responsible for it.
+    ///
+    /// # Safety
+    /// Like [Office::from_raw], and the host must not destroy the instance
+    /// while any [Office] or [Document] created from it is alive.
+    ///
+    /// # Arguments
+    ///  * `lok` - The LibreOfficeKit instance
+    pub unsafe fn from_raw_borrowed(lok: *mut LibreOfficeKit) -> Office {
+        Office::adopt(lok, false)
+    }
+
+    unsafe fn adopt(lok: *mut LibreOfficeKit, owned: bool) -> Office {
+        let mut current = INSTANCE.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(instance) = current.as_ref().and_then(Weak::upgrade) {
+            if instance.lok == lok {
+                return instance.office("");
             }
+        }
 
-            // Wrap the user provided callback and convert it into a pointer
-            let user_callback: *mut Box<
-                dyn FnMut(std::os::raw::c_int, *const std::os::raw::c_char),
-            > = Box::into_raw(Box::new(Box::new(cb)));
+        let instance = Instance::new(lok, PathBuf::new(), owned);
+        *current = Some(Arc::downgrade(&instance));
+        instance.office("")
+    }
 
-            let callback: LibreOfficeKitCallback = Some(callback_shim);
+    /// Returns the underlying LibreOfficeKit instance, e.g. to hand it to C code.
+    /// The [Office] keeps ownership of it, see [Office::from_raw_borrowed].
+    pub fn as_raw(&self) -> *mut LibreOfficeKit {
+        self.lok
+    }
 
-            // Get and invoke the register callback
-            let register_callback = (*self.lok_clz)
-                .registerCallback
-                .expect("missing registerCallback function");
+    /// Creates a handle without any LibreOfficeKit instance, only meant to test that
+    /// dropping it is harmless.
+    ///
+    /// # Safety
+    /// Every method but `drop` dereferences the null instance.
+    #[cfg(test)]
+    unsafe fn null_for_tests() -> Office {
+        Instance::new(std::ptr::null_mut(), PathBuf::new(), true).office("")
+    }
 
-            register_callback(self.lok, callback, user_callback.cast());
+    /// Create a new LibreOfficeKit instance from the first LibreOffice
+    /// installation that initializes successfully.
+    ///
+    /// When the `LIBREOFFICE_PROGRAM` environment variable is set, only that
+    /// directory is tried. Otherwise the well-known locations are searched in order:
+    ///
+    ///  * Linux: `/usr/lib/libreoffice/program`, `/usr/lib64/libreoffice/program`,
+    ///    `/usr/local/lib/libreoffice/program`, `/opt/libreoffice*/program`
+    ///    (newest version first) and `/snap/libreoffice/current/lib/libreoffice/program`
+    ///  * macOS: `/Applications/LibreOffice.app/Contents/Frameworks`
+    ///  * Windows: `C:\Program Files\LibreOffice\program` and
+    ///    `C:\Program Files (x86)\LibreOffice\program`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new_auto()?;
+    ///
+    /// assert_eq!("", office.get_error());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_auto() -> Result<Office, Error> {
+        if let Some(program_dir) = std::env::var_os(LIBREOFFICE_PROGRAM_ENV) {
+            let program_dir = PathBuf::from(program_dir);
+            if !program_dir.is_dir() {
+                return Err(Error::new(format!(
+                    "{LIBREOFFICE_PROGRAM_ENV} is set to {}, which is not a directory",
+                    program_dir.display()
+                )));
+            }
+            return Office::new(&program_dir.display().to_string());
+        }
 
-            let error = self.get_error();
-            if error != "" {
-                return Err(Error::new(error));
+        let candidates = install_path_candidates();
+        let mut failures = Vec::new();
+        for candidate in candidates.iter().filter(|c| c.is_dir()) {
+            match Office::new(&candidate.display().to_string()) {
+                Ok(office) => return Ok(office),
+                Err(err) => failures.push(format!("{}: {}", candidate.display(), err)),
             }
         }
 
-        Ok(())
+        let searched = candidates
+            .iter()
+            .map(|c| c.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(Error::new(format!(
+            "No usable LibreOffice installation found, set {LIBREOFFICE_PROGRAM_ENV} \
+             to its program directory. Searched: {searched}. Failures: [{}]",
+            failures.join("; ")
+        )))
     }
 
-    /// Loads a document from a URL.
+    /// Returns the last error as a string, or an empty string when there's none.
     ///
-    /// # Arguments
-    ///  * `url` - The URL to load.
+    /// LibreOfficeKit keeps reporting an error until the next one, so every error is
+    /// only returned once: reading it again returns an empty string until a different
+    /// error occurs. Errors returned as [Error] by the methods of [Office], or cleared
+    /// by [Office::reset], count as read. The same error occurring again is not
+    /// reported by this method, but still by the failing method.
     ///
     /// # Example
     ///
     /// ```
-    /// use libreoffice_rs::Office;
-    /// use libreoffice_rs::urls;
+    /// use libreoffice_rs::{Office, urls};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
-    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
-    /// office.document_load(doc_url)?;
+    /// let doc_url = urls::remote("file:///does/not/exist.odt")?;
+    /// assert!(office.document_load(doc_url).is_err());
     ///
+    /// // already returned by document_load
+    /// assert_eq!("", office.get_error());
     /// # Ok(())
     /// # }
     /// ```
-    pub fn document_load(&mut self, url: DocUrl) -> Result<Document, Error> {
-        let c_url = CString::new(url.to_string()).unwrap();
-        unsafe {
-            let doc = (*self.lok_clz).documentLoad.unwrap()(self.lok, c_url.as_ptr());
-            let error = self.get_error();
-            if error != "" {
-                return Err(Error::new(error));
-            }
-            Ok(Document { doc })
+    pub fn get_error(&mut self) -> String {
+        let error = self.get_lok_error();
+        if error.is_empty() {
+            return error;
+        }
+        let hash = error_hash(&error);
+        if self.seen_error.swap(hash, Ordering::AcqRel) == hash {
+            return String::new();
         }
+        error
     }
 
-    /// Set bitmask of optional features supported by the client and return the flags set.
-    ///
-    /// # Arguments
-    ///  * `feature_flags` - The feature flags to set.
-    ///
-    /// @see [LibreOfficeKitOptionalFeatures]
+    /// Removes and returns the oldest error LibreOfficeKit reported through the callback,
+    /// or `None` when there's none left.
     ///
-    /// @since LibreOffice 6.0
+    /// Some errors aren't returned by the failing call but reported as
+    /// [CallbackType::Error] events, e.g. the I/O errors of a document being loaded.
+    /// The callback registered with [Office::register_callback] collects them before
+    /// it is invoked, so one must be registered for errors to be reported; the most
+    /// recent 32 errors are kept. The payload is usually a JSON object describing the
+    /// error, e.g. `{"classification":"error","cmd":"load","kind":"io","code":"..."}`.
     ///
     /// # Example
     ///
     /// ```
-    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures};
+    /// use libreoffice_rs::{Office, urls};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
-    /// let feature_flags = [
-    ///     LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD,
-    ///     LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD_TO_MODIFY,
-    /// ];
-    /// let flags_set = office.set_optional_features(feature_flags)?;
+    /// office.register_callback(|_, _| {})?;
     ///
-    /// // Integration tests assertions
-    /// for feature_flag in feature_flags {
-    ///   assert!(flags_set & feature_flag as u64 > 0,
-    ///     "Failed to set the flag with value: {}", feature_flag as u64
-    ///   );
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let _doc = office.document_load(doc_url)?;
+    /// while let Some(error) = office.take_error() {
+    ///     println!("LibreOffice reported {error}");
     /// }
-    /// assert!(flags_set &
-    /// LibreOfficeKitOptionalFeatures::LOK_FEATURE_PART_IN_INVALIDATION_CALLBACK as u64 == 0,
-    ///   "LOK_FEATURE_PART_IN_INVALIDATION_CALLBACK feature was wrongly set!"
-    /// );
-    ///
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_optional_features<T>(&mut self, optional_features: T) -> Result<u64, Error>
-    where
-        T: IntoIterator<Item = LibreOfficeKitOptionalFeatures>,
-    {
-        let feature_flags: u64 = optional_features
-            .into_iter()
-            .map(|i| i as u64)
-            .fold(0, |acc, item| acc | item);
-
-        unsafe {
-            (*self.lok_clz).setOptionalFeatures.unwrap()(self.lok, feature_flags);
-            let error = self.get_error();
-            if error != "" {
-                return Err(Error::new(error));
-            }
-        }
+    pub fn take_error(&mut self) -> Option<String> {
+        self.instance
+            .async_errors
+            .errors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop_front()
+    }
 
-        Ok(feature_flags)
+    /// Returns the most recent error LibreOfficeKit reported through the callback
+    /// and wasn't taken yet, without removing it, see [Office::take_error]
+    pub fn last_error_snapshot(&self) -> Option<String> {
+        self.instance
+            .async_errors
+            .errors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .back()
+            .cloned()
     }
 
+    /// Sets whether loading a document fails when LibreOfficeKit reports errors
+    /// through the callback while loading it, see [Office::take_error].
     ///
-    /// Set password required for loading or editing a document.
-    ///
-    /// Loading the document is blocked until the password is provided.
-    /// This MUST be used in combination of features and within a callback
+    /// LibreOfficeKit may still load a document it reported errors for, e.g. a damaged
+    /// document it repaired. When enabled, such documents are dropped and the loads
+    /// fail with the reported errors, which are no longer returned by [Office::take_error].
+    /// Disabled by default. Applies to this [Office] and its clones, and needs a callback
+    /// registered with [Office::register_callback].
     ///
     /// # Arguments
-    ///  * `url` - the URL of the document, as sent to the callback
-    ///  * `password` - the password, nullptr indicates no password
+    /// * `fail` - whether loading fails on errors
     ///
-    /// In response to `LOK_CALLBACK_DOCUMENT_PASSWORD`, a valid password
-    /// will continue loading the document, an invalid password will
-    /// result in another `LOK_CALLBACK_DOCUMENT_PASSWORD` request,
-    /// and a NULL password will abort loading the document.
+    /// # Example
     ///
-    /// In response to `LOK_CALLBACK_DOCUMENT_PASSWORD_TO_MODIFY`, a valid
-    /// password will continue loading the document, an invalid password will
-    /// result in another `LOK_CALLBACK_DOCUMENT_PASSWORD_TO_MODIFY` request,
-    /// and a NULL password will continue loading the document in read-only
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.register_callback(|_, _| {})?;
+    /// office.set_fail_load_on_error(true);
+    ///
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let _doc = office.document_load(doc_url)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_fail_load_on_error(&mut self, fail: bool) {
+        self.instance
+            .async_errors
+            .fail_load
+            .store(fail, Ordering::Release);
+    }
+
+    /// Returns the error of a call that failed, even when [Office::get_error] already
+    /// reported the same error for a previous call, and marks it as read
+    ///
+    /// # Arguments
+    /// * `failure` - describes the call, used when LibreOfficeKit reports no error
+    fn failure(&mut self, failure: String) -> Error {
+        let error = self.get_lok_error();
+        if error.is_empty() {
+            return Error::new(failure);
+        }
+        self.seen_error.store(error_hash(&error), Ordering::Release);
+        Error::new(error)
+    }
+
+    /// Returns the last error LibreOfficeKit reported, which it never clears
+    fn get_lok_error(&self) -> String {
+        unsafe {
+            let raw_error = (*self.lok_clz).getError.unwrap()(self.lok);
+            CStr::from_ptr(raw_error).to_string_lossy().into_owned()
+        }
+    }
+
+    /// Returns the instance to a state fit for reusing it with other documents,
+    /// without the cost of initializing LibreOfficeKit again:
+    ///
+    ///  * the callback registered with [Office::register_callback] is unregistered and dropped
+    ///  * the optional features set with [Office::set_optional_features] are all turned off
+    ///  * the password retry limit of [Office::set_password_retry_limit] is removed
+    ///  * the last error is cleared, [Office::get_error] reports an empty string
+    ///    until a different error occurs
+    ///  * the errors reported through the callback are cleared, see [Office::take_error]
+    ///
+    /// Documents that are still loaded, options set with [Office::set_option] and
+    /// the state of LibreOffice itself (e.g. its user profile or caches) are not reset.
+    /// Must not be called from within a callback, nor while a clone of this
+    /// [Office] is loading a document on another thread.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.register_callback(|_, _| println!("callback"))?;
+    ///
+    /// office.reset();
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let _doc = office.document_load(doc_url)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reset(&mut self) {
+        unsafe {
+            (*self.lok_clz).registerCallback.unwrap()(self.lok, None, std::ptr::null_mut());
+            drop_callback_data(
+                self.callback_data
+                    .swap(std::ptr::null_mut(), Ordering::AcqRel),
+            );
+            (*self.lok_clz).setOptionalFeatures.unwrap()(self.lok, 0);
+        }
+        self.instance.optional_features.store(0, Ordering::Release);
+        self.password_prompts.limit.store(0, Ordering::Release);
+        self.password_prompts.reset();
+        self.instance.async_errors.clear();
+
+        let error = self.get_lok_error();
+        let seen = if error.is_empty() {
+            0
+        } else {
+            error_hash(&error)
+        };
+        self.seen_error.store(seen, Ordering::Release);
+    }
+
+    /// Returns the version information of the LibreOffice installation
+    pub fn get_version_info(&self) -> Result<VersionInfo, Error> {
+        let info = unsafe { take_lok_string((*self.lok_clz).getVersionInfo.unwrap()(self.lok)) };
+        match info {
+            Some(info) => VersionInfo::from_json(&info),
+            None => Err(Error::new("LibreOfficeKit returned no version info".into())),
+        }
+    }
+
+    /// Fails unless the LibreOffice installation is at least version `major.minor`,
+    /// e.g. before using an API added in that version.
+    ///
+    /// The version is parsed from the `ProductVersion` (and `ProductExtension`)
+    /// of [Office::get_version_info], see [VersionInfo::major_minor].
+    ///
+    /// # Arguments
+    ///  * `major` - the minimum major version, e.g. 7
+    ///  * `minor` - the minimum minor version, e.g. 4
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let office = Office::new("/usr/lib/libreoffice/program")?;
+    ///
+    /// office.require_version(6, 0)?;
+    /// assert!(office.require_version(999, 0).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn require_version(&self, major: u32, minor: u32) -> Result<(), Error> {
+        let info = self.get_version_info()?;
+        if info.is_at_least(major, minor)? {
+            return Ok(());
+        }
+        Err(Error::new(format!(
+            "LibreOffice {major}.{minor} or newer is required, found {}",
+            info.full_version()
+        )))
+    }
+
+    /// Registers a callback. LOK will invoke this function when it wants to
+    /// inform the client about events.
+    ///
+    /// Replaces the previously registered callback, which is dropped,
+    /// so this must not be called from within a callback.
+    ///
+    /// A panic of the callback is caught, so that it doesn't unwind into LibreOffice,
+    /// and LibreOffice carries on as if the callback returned, although it didn't
+    /// finish, e.g. didn't answer a password request. Use
+    /// [Office::register_callback_abort_on_panic] to abort the process instead.
+    ///
+    /// The callback is kept until it's replaced, [Office::reset] is called or the last
+    /// handle is dropped: a callback holding a clone of the [Office] keeps it alive, so
+    /// LibreOfficeKit is never destroyed. Use [Office::register_callback_with_office]
+    /// to call back into LibreOfficeKit instead.
+    ///
+    /// # Arguments
+    ///
+    ///  * `cb` - the callback to invoke (type, payload)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.set_optional_features(
+    ///    [LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD]
+    /// )?;
+    ///
+    /// office.register_callback(Box::new({
+    ///     move |_type, _payload| {
+    ///         println!("Call set_document_password and/or do something here!");
+    ///     }
+    /// }))?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_callback<
+        F: FnMut(std::os::raw::c_int, *const std::os::raw::c_char) + 'static,
+    >(
+        &mut self,
+        mut cb: F,
+    ) -> Result<(), Error> {
+        self.register_callback_with_office(move |_, ty, payload| cb(ty, payload))
+    }
+
+    /// Registers a callback like [Office::register_callback], but aborts the process
+    /// when the callback panics.
+    ///
+    /// Catching the panic hides the bug and leaves the callback half-run, while
+    /// LibreOffice goes on relying on it; aborting stops everything right away,
+    /// including the other documents being edited, but can't leave an inconsistent
+    /// state behind. The panic message is printed before aborting.
+    ///
+    /// # Arguments
+    ///
+    ///  * `cb` - the callback to invoke (type, payload)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.register_callback_abort_on_panic(|ty, _payload| {
+    ///     println!("Received the event {ty}");
+    /// })?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_callback_abort_on_panic<
+        F: FnMut(std::os::raw::c_int, *const std::os::raw::c_char) + 'static,
+    >(
+        &mut self,
+        mut cb: F,
+    ) -> Result<(), Error> {
+        self.register_shim(move |_, ty, payload| cb(ty, payload), true)
+    }
+
+    /// Registers a callback like [Office::register_callback], which is also handed
+    /// an [OfficeRef] to answer the events without cloning the [Office].
+    ///
+    /// The callback runs on the thread that called into LibreOfficeKit, while that
+    /// call (e.g. [Office::document_load]) is in progress, so don't block waiting for
+    /// another thread that uses the [Office]. A clone of the [Office] captured by the
+    /// callback can call back into LibreOfficeKit too, but keeps it alive, see
+    /// [Office::register_callback]; the given [OfficeRef] doesn't.
+    ///
+    /// # Arguments
+    ///
+    ///  * `cb` - the callback to invoke (office, type, payload)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{CallbackType, LibreOfficeKitOptionalFeatures, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let doc_url = urls::local_into_abs("./test_data/test_password.odt")?;
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])?;
+    /// office.set_password_retry_limit(1);
+    ///
+    /// office.register_callback_with_office({
+    ///     let doc_url = doc_url.clone();
+    ///     move |office, ty, _| {
+    ///         if ty == CallbackType::DocumentPassword as i32 {
+    ///             let _ = office.set_document_password(doc_url.clone(), "test");
+    ///         }
+    ///     }
+    /// })?;
+    ///
+    /// let _doc = office.document_load(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_callback_with_office<
+        F: FnMut(&mut OfficeRef<'_>, std::os::raw::c_int, *const std::os::raw::c_char) + 'static,
+    >(
+        &mut self,
+        cb: F,
+    ) -> Result<(), Error> {
+        self.register_shim(cb, false)
+    }
+
+    /// Registers the callback shim, invoking the given callback
+    ///
+    /// # Arguments
+    ///
+    ///  * `cb` - the callback to invoke (office, type, payload)
+    ///  * `abort_on_panic` - whether a panic of the callback aborts the process, or is caught
+    fn register_shim<
+        F: FnMut(&mut OfficeRef<'_>, std::os::raw::c_int, *const std::os::raw::c_char) + 'static,
+    >(
+        &mut self,
+        cb: F,
+        abort_on_panic: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            /// Callback that Libreoffice will invoke. The actual user callback
+            /// is provided, wrapped in [OfficeCallbackData], as the data value to this callback
+            ///
+            /// LibreOfficeKitCallback typedef (int nType, const char* pPayload, void* pData);
+            unsafe extern "C" fn callback_shim(
+                ty: std::os::raw::c_int,
+                payload: *const std::os::raw::c_char,
+                data: *mut std::os::raw::c_void,
+            ) {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::trace_span!("libreoffice_callback", ty).entered();
+
+                // Get the callback data from the data argument
+                let data: *mut OfficeCallbackData = data.cast();
+
+                // Unset the password once the password retry limit is exceeded or loading
+                // is cancelled, which aborts loading, or loads the document read-only for
+                // a password to modify. The payload of a password request is the document URL
+                if (ty == CallbackType::DocumentPassword as std::os::raw::c_int
+                    || ty == CallbackType::DocumentPasswordToModify as std::os::raw::c_int)
+                    && ((*data).password_prompts.cancelled() || (*data).password_prompts.exceeded())
+                {
+                    let lok = (*data).lok;
+                    (*(*lok).pClass).setDocumentPassword.unwrap()(lok, payload, std::ptr::null());
+                    return;
+                }
+
+                if ty == CallbackType::Error as std::os::raw::c_int && !payload.is_null() {
+                    let error = CStr::from_ptr(payload).to_string_lossy().into_owned();
+                    (*data).async_errors.push(error);
+                }
+
+                let mut office = OfficeRef {
+                    lok: (*data).lok,
+                    _office: std::marker::PhantomData,
+                };
+
+                // Catch panics from calling the callback, they must not unwind into LibreOffice
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+                    // Invoke the callback
+                    ((*data).callback)(&mut office, ty, payload);
+                }));
+                if result.is_err() && (*data).abort_on_panic {
+                    std::process::abort();
+                }
+            }
+
+            // Wrap the user provided callback and convert it into a pointer
+            let user_callback: *mut OfficeCallbackData =
+                Box::into_raw(Box::new(OfficeCallbackData {
+                    callback: Box::new(cb),
+                    lok: self.lok,
+                    password_prompts: self.password_prompts.clone(),
+                    async_errors: self.instance.async_errors.clone(),
+                    abort_on_panic,
+                }));
+
+            let callback: LibreOfficeKitCallback = Some(callback_shim);
+
+            // Get and invoke the register callback
+            let register_callback = (*self.lok_clz)
+                .registerCallback
+                .expect("missing registerCallback function");
+
+            register_callback(self.lok, callback, user_callback.cast());
+            // LibreOfficeKit no longer calls the replaced callback
+            drop_callback_data(self.callback_data.swap(user_callback, Ordering::AcqRel));
+
+            let error = self.get_error();
+            if error != "" {
+                return Err(Error::new(error));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Loads a document from a URL.
+    ///
+    /// # Arguments
+    ///  * `url` - The URL to load.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    /// use libreoffice_rs::urls;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// office.document_load(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn document_load(&mut self, url: DocUrl) -> Result<Document, Error> {
+        if !self.with_default_options("").is_empty() {
+            return self.document_load_with(url, "");
+        }
+        let c_url = CString::new(url.to_string()).unwrap();
+        self.password_prompts.reset();
+        let received = self.instance.async_errors.received.load(Ordering::Acquire);
+        unsafe {
+            let doc = self.instance.metrics.time("document_load", &url, || {
+                (*self.lok_clz).documentLoad.unwrap()(self.lok, c_url.as_ptr())
+            });
+            if doc.is_null() {
+                return Err(self.failure(format!("Failed to load the document {url}")));
+            }
+            self.loaded_document(doc, url, received)
+        }
+    }
+
+    /// Loads a document from a URL, unless the token is cancelled.
+    ///
+    /// LibreOfficeKit can't interrupt a load: the only point where a load in progress can be
+    /// aborted is a password prompt, which the callback registered with
+    /// [Office::register_callback] answers with no password once the token is cancelled.
+    /// A document without password, or loaded without a callback registered, loads to the
+    /// end whatever the token: the token is only checked before loading and once loaded,
+    /// when the document is dropped. Fails with [Error::Cancelled] when the token was cancelled.
+    ///
+    /// # Arguments
+    ///  * `url` - The URL of the document to load
+    ///  * `token` - The token cancelling the load, e.g. from another thread
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{CancellationToken, Error, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let token = CancellationToken::new();
+    /// token.cancel();
+    ///
+    /// let loaded = office.document_load_cancellable(doc_url, &token);
+    /// assert_eq!(Some(Error::Cancelled), loaded.err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn document_load_cancellable(
+        &mut self,
+        url: DocUrl,
+        token: &CancellationToken,
+    ) -> Result<Document, Error> {
+        if token.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+
+        self.password_prompts.set_cancellation(Some(token.clone()));
+        let loaded = self.document_load(url);
+        self.password_prompts.set_cancellation(None);
+
+        if token.is_cancelled() {
+            // drops the document if it was loaded anyway
+            return Err(Error::Cancelled);
+        }
+        loaded
+    }
+
+    /// Set bitmask of optional features supported by the client and return the flags set.
+    ///
+    /// # Arguments
+    ///  * `feature_flags` - The feature flags to set.
+    ///
+    /// @see [LibreOfficeKitOptionalFeatures]
+    ///
+    /// @since LibreOffice 6.0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let feature_flags = [
+    ///     LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD,
+    ///     LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD_TO_MODIFY,
+    /// ];
+    /// let flags_set = office.set_optional_features(feature_flags)?;
+    ///
+    /// // Integration tests assertions
+    /// for feature_flag in feature_flags {
+    ///   assert!(flags_set & feature_flag as u64 > 0,
+    ///     "Failed to set the flag with value: {}", feature_flag as u64
+    ///   );
+    /// }
+    /// assert!(flags_set &
+    /// LibreOfficeKitOptionalFeatures::LOK_FEATURE_PART_IN_INVALIDATION_CALLBACK as u64 == 0,
+    ///   "LOK_FEATURE_PART_IN_INVALIDATION_CALLBACK feature was wrongly set!"
+    /// );
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_optional_features<T>(&mut self, optional_features: T) -> Result<u64, Error>
+    where
+        T: IntoIterator<Item = LibreOfficeKitOptionalFeatures>,
+    {
+        let feature_flags: u64 = optional_features
+            .into_iter()
+            .map(|i| i as u64)
+            .fold(0, |acc, item| acc | item);
+
+        unsafe {
+            (*self.lok_clz).setOptionalFeatures.unwrap()(self.lok, feature_flags);
+            let error = self.get_error();
+            if error != "" {
+                return Err(Error::new(error));
+            }
+        }
+        self.instance
+            .optional_features
+            .store(feature_flags, Ordering::Release);
+
+        Ok(feature_flags)
+    }
+
+    /// Returns the flags of the optional features last set with [Office::set_optional_features],
+    /// by this [Office] or its clones, 0 when none or after [Office::reset].
+    ///
+    /// LibreOfficeKit doesn't report which features are set, so only the features
+    /// set through this crate are known, not the ones set on a raw handle.
+    pub fn get_optional_features(&self) -> u64 {
+        self.instance.optional_features.load(Ordering::Acquire)
+    }
+
+    /// Sets a global LibreOfficeKit option.
+    ///
+    /// # Arguments
+    ///  * `option` - the name of the option, e.g. `sallogoverride` (see [Office::set_log_selector])
+    ///  * `value` - the value of the option
+    pub fn set_option(&mut self, option: &str, value: &str) -> Result<(), Error> {
+        let c_option = CString::new(option).unwrap();
+        let c_value = CString::new(value).unwrap();
+        unsafe {
+            (*self.lok_clz).setOption.unwrap()(self.lok, c_option.as_ptr(), c_value.as_ptr());
+            let error = self.get_error();
+            if error != "" {
+                return Err(Error::new(error));
+            }
+        }
+        Ok(())
+    }
+
+    /// Changes which internal log messages LibreOffice writes, overriding the `SAL_LOG`
+    /// environment variable LibreOffice read at startup.
+    ///
+    /// LibreOfficeKit has no way to hand log lines to a callback: LibreOffice writes them
+    /// to the standard error of the process (or to the file named by `SAL_LOG_FILE`,
+    /// set before [Office::new]). What can be controlled at runtime is the selector,
+    /// via the `sallogoverride` option. To capture startup messages too, set `SAL_LOG`
+    /// in the environment before [Office::new] instead.
+    ///
+    /// Release builds of LibreOffice are usually configured without `--enable-sal-log`,
+    /// which leaves out the info and warning messages entirely, whatever the selector.
+    ///
+    /// # Arguments
+    ///  * `selector` - a `SAL_LOG` selector, e.g. `+WARN` for all warnings or
+    ///                 `+INFO.lok+WARN` for LibreOfficeKit info messages and all warnings
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.set_log_selector("+WARN")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_log_selector(&mut self, selector: &str) -> Result<(), Error> {
+        self.set_option("sallogoverride", selector)
+    }
+
+    ///
+    /// Set password required for loading or editing a document.
+    ///
+    /// Loading the document is blocked until the password is provided.
+    /// This MUST be used in combination of features and within a callback
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    ///  * `password` - the password, nullptr indicates no password
+    ///
+    /// In response to `LOK_CALLBACK_DOCUMENT_PASSWORD`, a valid password
+    /// will continue loading the document, an invalid password will
+    /// result in another `LOK_CALLBACK_DOCUMENT_PASSWORD` request,
+    /// and a NULL password will abort loading the document.
+    ///
+    /// In response to `LOK_CALLBACK_DOCUMENT_PASSWORD_TO_MODIFY`, a valid
+    /// password will continue loading the document, an invalid password will
+    /// result in another `LOK_CALLBACK_DOCUMENT_PASSWORD_TO_MODIFY` request,
+    /// and a NULL password will continue loading the document in read-only
     /// mode.
     ///
-    /// @since LibreOffice 6.0
+    /// @since LibreOffice 6.0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures, urls};
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let doc_url = urls::local_into_abs("./test_data/test_password.odt")?;
+    /// let password = "test";
+    /// let password_was_set = AtomicBool::new(false);
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    ///
+    /// office.set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])?;
+    /// office.register_callback_with_office({
+    ///     let doc_url = doc_url.clone();
+    ///     move |office, _, _| {
+    ///         if !password_was_set.load(Ordering::Acquire) {
+    ///             let ret = office.set_document_password(doc_url.clone(), &password);
+    ///             password_was_set.store(true, Ordering::Release);
+    ///         }
+    ///     }
+    /// })?;
+    ///
+    /// let mut _doc = office.document_load(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_document_password(&mut self, url: DocUrl, password: &str) -> Result<(), Error> {
+        self.as_office_ref().set_document_password(url, password)
+    }
+
+    /// Set the password required for loading or editing a document, given as raw bytes,
+    /// like [Office::set_document_password] for passwords that shouldn't outlive their
+    /// use in memory.
+    ///
+    /// The bytes must still be UTF-8: LibreOffice decodes the password as UTF-8 (in
+    /// `LOKInteractionHandler::SetPassword`, `desktop/source/lib/lokinteractionhandler.cxx`),
+    /// so other bytes could never match the password of the document, and are an error.
+    ///
+    /// LibreOfficeKit takes passwords as NUL-terminated C strings: the bytes are copied
+    /// into a buffer with a terminating NUL, which is zeroized (with the `zeroize` crate,
+    /// so the writes aren't optimized away) as soon as LibreOfficeKit returns, also on errors and panics.
+    /// The buffer is allocated once, at its final size, so no partial copy is left behind
+    /// by a reallocation.
+    ///
+    /// This only covers the copy made by this method:
+    /// - `password` itself isn't modified, it's up to the caller to wipe it, e.g. by
+    ///   keeping it in a `zeroize::Zeroizing<Vec<u8>>`
+    /// - LibreOffice converts the password to its own strings while loading the document,
+    ///   those copies are out of reach of this crate
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    ///  * `password` - the UTF-8 password, without NUL bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let doc_url = urls::local_into_abs("./test_data/test_password.odt")?;
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    ///
+    /// office.set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])?;
+    /// office.set_password_retry_limit(1);
+    /// office.register_callback_with_office({
+    ///     let doc_url = doc_url.clone();
+    ///     move |office, _, _| {
+    ///         let _ = office.set_document_password_bytes(doc_url.clone(), b"test");
+    ///     }
+    /// })?;
+    ///
+    /// let mut _doc = office.document_load(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub fn set_document_password_bytes(
+        &mut self,
+        url: DocUrl,
+        password: &[u8],
+    ) -> Result<(), Error> {
+        self.as_office_ref()
+            .set_document_password_bytes(url, password)
+    }
+
+    /// This method provides a defense mechanism against infinite loops, upon password entry failures:
+    /// * Loading the document is blocked until a valid password is set within callbacks
+    /// * A wrong password will result into infinite repeated callback loops
+    /// * This method advises `LibreOfficeKit` to stop requesting a password *"as soon as possible"*
+    ///
+    /// It is safe for this method to be invoked even if the originally provided password was correct:
+    /// - `LibreOfficeKit` appears to maintain thread-local values of the password. It will stick to the first password entry value.
+    /// That will translate into a a successfully loaded document.
+    /// - `LibreOfficeKit` seems to send an "excessive" number of callbacks (potential internal issues with locks/monitors)
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures, urls};
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let doc_url = urls::local_into_abs("./test_data/test_password.odt")?;
+    /// let password = "forgotten_invalid_password_which_is_just_test";
+    /// let password_was_set = AtomicBool::new(false);
+    /// let failed_password_attempt = AtomicBool::new(false);
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    ///
+    /// office.set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])?;
+    /// office.register_callback_with_office({
+    ///     let doc_url = doc_url.clone();
+    ///     move |office, _, _| {
+    ///         if !password_was_set.load(Ordering::Acquire) {
+    ///             let ret = office.set_document_password(doc_url.clone(), &password);
+    ///             password_was_set.store(true, Ordering::Release);
+    ///         } else {
+    ///             if !failed_password_attempt.load(Ordering::Acquire) {
+    ///                 let ret = office.unset_document_password(doc_url.clone());
+    ///                 failed_password_attempt.store(true, Ordering::Release);
+    ///             }
+    ///         }
+    ///     }
+    /// })?;
+    ///
+    /// assert!(office.document_load(doc_url).is_err(),
+    ///         "Document loaded successfully with a wrong password!");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unset_document_password(&mut self, url: DocUrl) -> Result<(), Error> {
+        self.as_office_ref().unset_document_password(url)
+    }
+
+    /// Answers a `LOK_CALLBACK_DOCUMENT_PASSWORD_TO_MODIFY` request, for documents
+    /// protected by a password to edit them (but not to open them).
+    ///
+    /// Unlike a password to open the document, a missing password doesn't abort loading:
+    /// - `Some(password)`: a valid password loads the document for editing, an invalid one
+    ///   results in another `LOK_CALLBACK_DOCUMENT_PASSWORD_TO_MODIFY` request
+    /// - `None`: the document is loaded read-only
+    ///
+    /// LibreOfficeKit only sends the request once the
+    /// [LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD_TO_MODIFY] feature is set.
+    /// Once the limit of [Office::set_password_retry_limit] is exceeded, the document
+    /// is loaded read-only without invoking the callback.
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    ///  * `password` - the password to modify the document, `None` to load it read-only
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{CallbackType, Office, LibreOfficeKitOptionalFeatures, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let doc_url = urls::local_into_abs("./test_data/test_modify_password.odt")?;
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    ///
+    /// office.set_optional_features([
+    ///     LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD_TO_MODIFY,
+    /// ])?;
+    /// office.register_callback_with_office({
+    ///     let doc_url = doc_url.clone();
+    ///     move |office, ty, _| {
+    ///         if ty == CallbackType::DocumentPasswordToModify as std::os::raw::c_int {
+    ///             let _ = office.set_modify_password(doc_url.clone(), None);
+    ///         }
+    ///     }
+    /// })?;
+    ///
+    /// // loaded read-only
+    /// let _doc = office.document_load(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_modify_password(
+        &mut self,
+        url: DocUrl,
+        password: Option<&str>,
+    ) -> Result<(), Error> {
+        self.as_office_ref().set_modify_password(url, password)
+    }
+
+    fn as_office_ref(&mut self) -> OfficeRef<'_> {
+        OfficeRef {
+            lok: self.lok,
+            _office: std::marker::PhantomData,
+        }
+    }
+
+    /// Limit the number of `LOK_CALLBACK_DOCUMENT_PASSWORD` requests per document load.
+    ///
+    /// Once a load requests a password more than `max` times, the callback registered with
+    /// [Office::register_callback] is no longer invoked for it: the password is unset instead,
+    /// as with [Office::unset_document_password], which aborts loading the document.
+    /// This replaces tracking the attempts in the callback yourself.
+    ///
+    /// Requests of the password to modify a document count as well, exceeding the limit
+    /// loads the document read-only instead, see [Office::set_modify_password].
+    ///
+    /// # Arguments
+    ///  * `max` - the number of password requests allowed per load, 0 removes the limit
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let doc_url = urls::local_into_abs("./test_data/test_password.odt")?;
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    ///
+    /// office.set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])?;
+    /// office.set_password_retry_limit(1);
+    /// office.register_callback_with_office({
+    ///     let doc_url = doc_url.clone();
+    ///     move |office, _, _| {
+    ///         let _ = office.set_document_password(doc_url.clone(), "wrong_password");
+    ///     }
+    /// })?;
+    ///
+    /// assert!(office.document_load(doc_url).is_err(),
+    ///         "Document loaded successfully with a wrong password!");
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_password_retry_limit(&mut self, max: u32) {
+        self.password_prompts.limit.store(max, Ordering::Release);
+    }
+
+    /// Loads a document from a URL with additional options.
+    ///
+    /// # Arguments
+    /// * `url` - The URL to load.
+    /// * `options` - options for the import filter, e.g. SkipImages.
+    ///               Another useful FilterOption is "Language=...".  It is consumed
+    ///               by the documentLoad() itself, and when provided, LibreOfficeKit
+    ///               switches the language accordingly first.
+    ///
+    /// The comma separated `Key=value` options are checked against the keys
+    /// LibreOfficeKit consumes: `Language`, `Batch`, `DeviceFormFactor`, `Timezone`,
+    /// `Theme`, `MacroSecurityLevel` and `EnableMacrosExecution`, and `Password`,
+    /// `FilterOptions`, `ReadOnly` and `Hidden`, which it passes on to the import filter
+    /// along with the other options. An unknown key, e.g. a misspelled `Langauge`, is an
+    /// error rather than being silently ignored. Options without `=` are passed to the
+    /// import filter unchecked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// office.document_load_with(doc_url, "Language=en-US")?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn document_load_with(&mut self, url: DocUrl, options: &str) -> Result<Document, Error> {
+        validate_load_options(options)?;
+        let options = self.with_default_options(options);
+        let c_url = CString::new(url.to_string()).unwrap();
+        let c_options = CString::new(options).unwrap();
+        self.password_prompts.reset();
+        let received = self.instance.async_errors.received.load(Ordering::Acquire);
+        unsafe {
+            let doc = self.instance.metrics.time("document_load", &url, || {
+                (*self.lok_clz).documentLoadWithOptions.unwrap()(
+                    self.lok,
+                    c_url.as_ptr(),
+                    c_options.as_ptr(),
+                )
+            });
+            if doc.is_null() {
+                return Err(self.failure(format!("Failed to load the document {url}")));
+            }
+            self.loaded_document(doc, url, received)
+        }
+    }
+
+    /// Sets the language of LibreOfficeKit, for the strings it generates (e.g. the names
+    /// of new sheets or the `Page 1 of N` of headers), number formats and dialogs.
+    ///
+    /// LibreOfficeKit only switches languages when loading a document, with the `Language`
+    /// load option (see [Office::document_load_with]), so the language is passed along
+    /// to every document loaded afterwards by this [Office] or its clones, unless the options
+    /// already have a `Language`. Call it before loading the first document: LibreOffice
+    /// sets up some of its localized strings once, from the language of the first load.
+    ///
+    /// # Arguments
+    /// * `language` - The language, e.g. `de-DE`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{LanguageTag, Office, urls};
+    /// use std::convert::TryFrom;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.set_ui_language(&LanguageTag::try_from("de-DE")?)?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// office.document_load(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_ui_language(&mut self, language: &LanguageTag) -> Result<(), Error> {
+        *self
+            .instance
+            .ui_language
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(language.clone());
+        Ok(())
+    }
+
+    /// Adds the language of [Office::set_ui_language] and the macro options of
+    /// [Office::set_macro_security] to load options without them
+    fn with_default_options(&self, options: &str) -> String {
+        let has_option = |name: &str| {
+            options.split(',').any(
+                |option| matches!(option.split_once('='), Some((key, _)) if key.trim() == name),
+            )
+        };
+
+        let mut defaults = Vec::new();
+        if let Some(language) = &*self
+            .instance
+            .ui_language
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+        {
+            if !has_option("Language") {
+                defaults.push(format!("Language={language}"));
+            }
+        }
+        let macro_options = self
+            .instance
+            .macro_security
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .load_options();
+        if !macro_options.is_empty()
+            && !has_option("EnableMacrosExecution")
+            && !has_option("MacroSecurityLevel")
+        {
+            defaults.push(macro_options.to_owned());
+        }
+
+        if !options.is_empty() {
+            defaults.insert(0, options.to_owned());
+        }
+        defaults.join(",")
+    }
+
+    /// Sets whether the documents loaded afterwards by this [Office] or its clones
+    /// may run their macros, e.g. the ones bound to the opening of the document.
+    ///
+    /// LibreOfficeKit doesn't run the macros of documents by default, which is
+    /// [MacroSecurity::Disabled]: only enable them for trusted documents. The level is
+    /// passed along as the `EnableMacrosExecution` and `MacroSecurityLevel` load options
+    /// (see [Office::document_load_with]), unless the options already have them.
+    /// Macros run with [Office::run_macro] aren't affected.
+    ///
+    /// # Arguments
+    /// * `level` - whether macros may run
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{MacroSecurity, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.set_macro_security(MacroSecurity::Disabled);
+    /// let doc_url = urls::local_into_abs("./test_data/macro.odt")?;
+    /// office.document_load(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_macro_security(&mut self, level: MacroSecurity) {
+        *self
+            .instance
+            .macro_security
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = level;
+    }
+
+    /// Loads a document from a URL, switching LibreOfficeKit to a language first,
+    /// see [Office::document_load_with]
+    ///
+    /// # Arguments
+    /// * `url` - The URL to load.
+    /// * `language` - The language used for e.g. number formats and localized names
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{LanguageTag, Office, urls};
+    /// use std::convert::TryFrom;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// office.document_load_with_language(doc_url, &LanguageTag::try_from("de-DE")?)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn document_load_with_language(
+        &mut self,
+        url: DocUrl,
+        language: &LanguageTag,
+    ) -> Result<Document, Error> {
+        self.document_load_with(url, &format!("Language={language}"))
+    }
+
+    /// Loads a local file as the given type, whatever its extension, e.g. a download
+    /// saved under a random name without extension, which LibreOffice may fail to detect.
+    ///
+    /// LibreOfficeKit doesn't take the `FilterName` of the media descriptor: `documentLoadWithOptions`
+    /// (`lo_documentLoadWithOptions` in LibreOffice's `desktop/source/lib/init.cxx`) builds
+    /// the descriptor itself, from a fixed set of properties, and hands the whole options
+    /// string to the import filter as its `FilterOptions`. The type is detected from the URL
+    /// and the content instead.
+    ///
+    /// So a file whose extension doesn't match the type is loaded through a temporary
+    /// link with the extension of the type: a hard link, or a symbolic link when the
+    /// temporary directory is on another file system, and only as a last resort a copy.
+    /// The link is removed along with the [Document], which still refers to `url`,
+    /// e.g. for [Document::is_read_only]; save it explicitly with [Document::save_as].
+    ///
+    /// The type is a MIME type, an import filter name or an extension, ignoring case:
+    ///
+    /// | Extension | MIME type | Filter |
+    /// |-----------|-----------|--------|
+    /// | odt | `application/vnd.oasis.opendocument.text` | `writer8` |
+    /// | ods | `application/vnd.oasis.opendocument.spreadsheet` | `calc8` |
+    /// | odp | `application/vnd.oasis.opendocument.presentation` | `impress8` |
+    /// | odg | `application/vnd.oasis.opendocument.graphics` | `draw8` |
+    /// | docx | `application/vnd.openxmlformats-officedocument.wordprocessingml.document` | `MS Word 2007 XML` |
+    /// | xlsx | `application/vnd.openxmlformats-officedocument.spreadsheetml.sheet` | `Calc MS Excel 2007 XML` |
+    /// | pptx | `application/vnd.openxmlformats-officedocument.presentationml.presentation` | `Impress MS PowerPoint 2007 XML` |
+    /// | doc | `application/msword` | `MS Word 97` |
+    /// | xls | `application/vnd.ms-excel` | `MS Excel 97` |
+    /// | ppt | `application/vnd.ms-powerpoint` | `MS PowerPoint 97` |
+    /// | rtf | `application/rtf` | `Rich Text Format` |
+    /// | txt | `text/plain` | `Text` |
+    /// | csv | `text/csv` | `Text - txt - csv (StarCalc)` |
+    /// | html | `text/html` | `HTML (StarWriter)` |
+    ///
+    /// # Arguments
+    /// * `url` - The URL of a local file.
+    /// * `mime_or_filter` - The type of the file, e.g. `application/vnd.oasis.opendocument.text`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let download = std::env::temp_dir().join("libreoffice_rs_download_8f3a");
+    /// std::fs::copy("./test_data/test.odt", &download)?;
+    ///
+    /// let doc_url = urls::local_as_abs(download.display().to_string())?;
+    /// let doc = office.document_load_as(doc_url, "writer8")?;
+    /// # drop(doc);
+    /// # std::fs::remove_file(&download)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn document_load_as(
+        &mut self,
+        url: DocUrl,
+        mime_or_filter: &str,
+    ) -> Result<Document, Error> {
+        let extension = format::import_extension(mime_or_filter).ok_or_else(|| {
+            Error::new(format!(
+                "Unsupported document type {mime_or_filter}, expected a known MIME type or filter name"
+            ))
+        })?;
+        let path = url.to_file_path().ok_or_else(|| {
+            Error::new(format!(
+                "Only local files can be loaded as a given type, not {url}"
+            ))
+        })?;
+
+        let matches = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(extension));
+        if matches {
+            return self.document_load(url);
+        }
+
+        let link = export::temp_export_path("load_as", extension);
+        link_or_copy(&path, &link).map_err(|ex| {
+            Error::new(format!(
+                "Failed to link {} to {}! {ex}",
+                path.display(),
+                link.display()
+            ))
+        })?;
+        let loaded = urls::local_as_abs(link.display().to_string())
+            .and_then(|link_url| self.document_load(link_url));
+        match loaded {
+            Ok(mut doc) => {
+                doc.url = url;
+                doc.temp_file = Some(link);
+                Ok(doc)
+            }
+            Err(err) => {
+                let _ = std::fs::remove_file(&link);
+                Err(err)
+            }
+        }
+    }
+
+    /// Loads a document from a reader, e.g. the body of an HTTP response or a decompressor.
+    ///
+    /// LibreOfficeKit can only load URLs, and not a `private:stream` backed by a reader,
+    /// so the whole content is first written to a temporary file, which costs a copy on
+    /// disk and delays the load until the reader is exhausted. The file is loaded,
+    /// then removed along with the [Document], or right away if the load fails.
+    /// The [Document] refers to the temporary file: save it explicitly with [Document::save_as].
+    ///
+    /// # Arguments
+    /// * `reader` - The content of the document.
+    /// * `hint` - The type of the document, see [Office::document_load_as], e.g. `docx`.
+    ///            Without it, LibreOffice detects the type from the content alone,
+    ///            which fails for e.g. CSV files.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let file = std::fs::File::open("./test_data/test.odt")?;
+    /// let mut doc = office.document_load_reader(file, Some("odt"))?;
+    /// doc.save_as("/tmp/test.pdf", "pdf", None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn document_load_reader<R: std::io::Read>(
+        &mut self,
+        mut reader: R,
+        hint: Option<&str>,
+    ) -> Result<Document, Error> {
+        let extension = match hint {
+            Some(hint) => format::import_extension(hint).ok_or_else(|| {
+                Error::new(format!(
+                    "Unsupported document type {hint}, expected a known extension, MIME type or filter name"
+                ))
+            })?,
+            None => "tmp",
+        };
+
+        let path = export::temp_export_path("load_reader", extension);
+        let written =
+            std::fs::File::create(&path).and_then(|mut file| std::io::copy(&mut reader, &mut file));
+        if let Err(ex) = written {
+            let _ = std::fs::remove_file(&path);
+            return Err(Error::new(format!(
+                "Failed to buffer the document into {}! {ex}",
+                path.display()
+            )));
+        }
+
+        let loaded =
+            urls::local_as_abs(path.display().to_string()).and_then(|url| self.document_load(url));
+        match loaded {
+            Ok(mut doc) => {
+                doc.temp_file = Some(path);
+                Ok(doc)
+            }
+            Err(err) => {
+                let _ = std::fs::remove_file(&path);
+                Err(err)
+            }
+        }
+    }
+
+    /// Loads a document from a URL for viewing only: its view is read-only,
+    /// so key presses and UNO commands can't modify it.
+    ///
+    /// The document is loaded with the `ReadOnly=true` load option. LibreOfficeKit hands
+    /// load options to the import filter rather than to the media descriptor, see
+    /// [Office::document_load_as], so the view is then also made read-only, see
+    /// [Document::set_view_read_only], which [Document::is_read_only] reports.
+    /// Views created afterwards aren't read-only, and [Document::save_as] still works.
+    /// Note that [Document::get_edit_mode] is about presentation master pages, not read-only views.
+    ///
+    /// # Arguments
+    /// * `url` - The URL to load.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load_readonly(doc_url)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn document_load_readonly(&mut self, url: DocUrl) -> Result<Document, Error> {
+        let mut doc = self.document_load_with(url, "ReadOnly=true")?;
+        let view = doc.get_view();
+        doc.set_view_read_only(view, true);
+        Ok(doc)
+    }
+
+    /// Runs a macro stored at a specific path (within a document).
+    ///
+    /// # Arguments
+    /// * `path` - The macro path (macro:///Standard.Module1.MyMacro).
+    pub fn run_macro(&mut self, path: &str) -> Result<(), Error> {
+        let c_path = CString::new(path).unwrap();
+        unsafe {
+            let x = self.instance.metrics.time("run_macro", &path, || {
+                (*self.lok_clz).runMacro.unwrap()(self.lok, c_path.as_ptr())
+            });
+            if x == 0 {
+                return Err(self.failure(format!("Failed to run the macro {path}")));
+            }
+            Ok(())
+        }
+    }
+
+    /// Sets a callback invoked after every document load, save and macro run, e.g. to
+    /// record their timings. It's handed the name of the operation and how long it took:
+    /// * `document_load` - any load of [Office], e.g. [Office::document_load_with]
+    /// * `save_as` - any save of [Document], e.g. [Document::save_as_with]
+    /// * `run_macro` - [Office::run_macro]
+    ///
+    /// The callback replaces the previous one, for this [Office], its clones and all their
+    /// documents, including the ones already loaded. It's invoked whether the operation
+    /// succeeded or not, and must not load, save or run macros itself.
+    ///
+    /// With the `tracing` feature, these operations are also recorded as `libreoffice`
+    /// spans of the `tracing` crate, with `operation`, `url` and `elapsed_ms` fields and a
+    /// `DEBUG` event when they finish, and callbacks as `TRACE` level `libreoffice_callback`
+    /// (of the [Office]) and `libreoffice_document_callback` spans with a `ty` field.
+    /// Without the feature, none of this is compiled in.
+    ///
+    /// # Arguments
+    /// * `cb` - the callback to invoke (operation, elapsed time)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.set_metrics_callback(|operation, elapsed| {
+    ///     println!("{operation} took {} ms", elapsed.as_millis());
+    /// });
+    ///
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// office.document_load(doc_url)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_metrics_callback<F: FnMut(&str, std::time::Duration) + Send + 'static>(
+        &mut self,
+        cb: F,
+    ) {
+        self.instance.metrics.set(Box::new(cb));
+    }
+
+    /// Wraps a loaded document, unless [Office::set_fail_load_on_error] is enabled
+    /// and the callback received errors while loading it, which fails with them
+    ///
+    /// # Arguments
+    /// * `received` - the number of errors received before loading
+    fn loaded_document(
+        &self,
+        doc: *mut LibreOfficeKitDocument,
+        url: DocUrl,
+        received: u64,
+    ) -> Result<Document, Error> {
+        let document = self.wrap_document(doc, url);
+        if !self.instance.async_errors.fail_load.load(Ordering::Acquire) {
+            return Ok(document);
+        }
+        let errors = self.instance.async_errors.take_since(received);
+        if errors.is_empty() {
+            Ok(document)
+        } else {
+            Err(Error::new(errors.join("; ")))
+        }
+    }
+
+    /// Wraps a loaded document, which reports its saves to the metrics callback
+    fn wrap_document(&self, doc: *mut LibreOfficeKitDocument, url: DocUrl) -> Document {
+        let mut document = Document::wrap(doc, url);
+        document.metrics = self.instance.metrics.clone();
+        document
+    }
+}
+
+impl OfficeRef<'_> {
+    /// Returns the last error as a string, see [Office::get_error]
+    pub fn get_error(&mut self) -> String {
+        unsafe {
+            let raw_error = (*(*self.lok).pClass).getError.unwrap()(self.lok);
+            CStr::from_ptr(raw_error).to_string_lossy().into_owned()
+        }
+    }
+
+    /// Set the password required for loading or editing a document,
+    /// see [Office::set_document_password]
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    ///  * `password` - the password
+    pub fn set_document_password(&mut self, url: DocUrl, password: &str) -> Result<(), Error> {
+        let c_password = CString::new(password).unwrap();
+        self.set_password(url, c_password.as_ptr())
+    }
+
+    /// Set the password required for loading or editing a document, given as raw bytes,
+    /// see [Office::set_document_password_bytes]
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    ///  * `password` - the UTF-8 password, without NUL bytes
+    #[cfg(feature = "zeroize")]
+    pub fn set_document_password_bytes(
+        &mut self,
+        url: DocUrl,
+        password: &[u8],
+    ) -> Result<(), Error> {
+        if password.contains(&0) {
+            return Err(Error::new(
+                "The password contains a NUL byte, which can't be passed to LibreOfficeKit"
+                    .to_string(),
+            ));
+        }
+        // LibreOffice decodes the password as UTF-8, see Office::set_document_password_bytes
+        if std::str::from_utf8(password).is_err() {
+            return Err(Error::new(
+                "The password isn't UTF-8, which LibreOffice decodes it as".to_string(),
+            ));
+        }
+
+        // zeroized when dropped, also when unwinding
+        let mut c_password = zeroize::Zeroizing::new(Vec::with_capacity(password.len() + 1));
+        c_password.extend_from_slice(password);
+        c_password.push(0);
+        self.set_password(url, c_password.as_ptr() as *const std::os::raw::c_char)
+    }
+
+    /// Stop LibreOfficeKit from requesting a password for a document,
+    /// see [Office::unset_document_password]
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    pub fn unset_document_password(&mut self, url: DocUrl) -> Result<(), Error> {
+        self.set_password(url, std::ptr::null())
+    }
+
+    /// Answer a request for the password to modify a document,
+    /// see [Office::set_modify_password]
+    ///
+    /// # Arguments
+    ///  * `url` - the URL of the document, as sent to the callback
+    ///  * `password` - the password to modify the document, `None` to load it read-only
+    pub fn set_modify_password(
+        &mut self,
+        url: DocUrl,
+        password: Option<&str>,
+    ) -> Result<(), Error> {
+        match password {
+            Some(password) => self.set_document_password(url, password),
+            None => self.set_password(url, std::ptr::null()),
+        }
+    }
+
+    fn set_password(
+        &mut self,
+        url: DocUrl,
+        password: *const std::os::raw::c_char,
+    ) -> Result<(), Error> {
+        let c_url = CString::new(url.to_string()).unwrap();
+        unsafe {
+            (*(*self.lok).pClass).setDocumentPassword.unwrap()(self.lok, c_url.as_ptr(), password);
+            let error = self.get_error();
+            if error != "" {
+                return Err(Error::new(error));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Well-known LibreOffice `program` directories for the current OS, in search order
+fn install_path_candidates() -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+
+    if cfg!(target_os = "macos") {
+        candidates.push("/Applications/LibreOffice.app/Contents/Frameworks".into());
+    } else if cfg!(target_os = "windows") {
+        candidates.push(r"C:\Program Files\LibreOffice\program".into());
+        candidates.push(r"C:\Program Files (x86)\LibreOffice\program".into());
+    } else {
+        candidates.push("/usr/lib/libreoffice/program".into());
+        candidates.push("/usr/lib64/libreoffice/program".into());
+        candidates.push("/usr/local/lib/libreoffice/program".into());
+
+        // e.g. /opt/libreoffice7.5/program from the upstream packages, newest first
+        let mut opt_dirs: Vec<(Vec<u32>, PathBuf)> = std::fs::read_dir("/opt")
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let version = opt_dir_version(&entry.file_name().to_string_lossy())?;
+                Some((version, entry.path().join("program")))
+            })
+            .collect();
+        opt_dirs.sort_by(|a, b| b.cmp(a));
+        candidates.extend(opt_dirs.into_iter().map(|(_, dir)| dir));
+
+        candidates.push("/snap/libreoffice/current/lib/libreoffice/program".into());
+    }
+
+    candidates
+}
+
+/// Returns the version of a `/opt` directory of the upstream packages, e.g. `[24, 2]` for
+/// `libreoffice24.2`, empty when it has none, `None` for other directories
+fn opt_dir_version(name: &str) -> Option<Vec<u32>> {
+    let version = name.strip_prefix("libreoffice")?;
+    Some(
+        version
+            .split('.')
+            .map_while(|part| part.parse().ok())
+            .collect(),
+    )
+}
+
+/// Returns the rectangle of a page from the page rectangles LibreOfficeKit reports,
+/// e.g. `284, 284, 11906, 16838; 284, 17406, 11906, 16838`
+#[cfg(feature = "unstable")]
+fn nth_page_rectangle(rectangles: &str, page: usize) -> Option<Rect> {
+    Rect::parse_list(rectangles).ok()?.get(page).copied()
+}
+
+/// Returns the bounding rectangle of every match of a search result,
+/// e.g. `{"searchResultSelection": [{"part": "0", "rectangles": "1418, 1418, 3968, 276"}]}`
+#[cfg(feature = "unstable")]
+fn search_result_rects(result: &serde_json::Value) -> Result<Vec<Rect>, Error> {
+    let selections = match result["searchResultSelection"].as_array() {
+        Some(selections) => selections,
+        None => return Ok(Vec::new()),
+    };
+
+    selections
+        .iter()
+        .map(|selection| {
+            let rects = Rect::parse_list(selection["rectangles"].as_str().unwrap_or_default())?;
+            let left = rects.iter().map(|r| r.x).min().unwrap_or_default();
+            let top = rects.iter().map(|r| r.y).min().unwrap_or_default();
+            let right = rects
+                .iter()
+                .map(|r| r.x + r.width)
+                .max()
+                .unwrap_or_default();
+            let bottom = rects
+                .iter()
+                .map(|r| r.y + r.height)
+                .max()
+                .unwrap_or_default();
+            Ok(Rect::new(left, top, right - left, bottom - top))
+        })
+        .collect()
+}
+
+/// Returns the target and the text of the `<a href="...">` links of an HTML fragment
+#[cfg(feature = "unstable")]
+fn html_links(html: &str) -> Vec<(String, String)> {
+    // tags and attributes are case insensitive, and lowercasing keeps the byte offsets
+    let lowercase = html.to_ascii_lowercase();
+    let mut links = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = lowercase[offset..].find("<a ") {
+        let tag_start = offset + start;
+        let tag_end = match lowercase[tag_start..].find('>') {
+            Some(tag_end) => tag_start + tag_end,
+            None => break,
+        };
+        let content_end = lowercase[tag_end..]
+            .find("</a>")
+            .map_or(html.len(), |end| tag_end + end);
+
+        let tag = &lowercase[tag_start..tag_end];
+        let href = tag.find("href=\"").and_then(|href| {
+            let value_start = tag_start + href + 6;
+            lowercase[value_start..tag_end]
+                .find('"')
+                .map(|end| &html[value_start..value_start + end])
+        });
+        if let Some(href) = href {
+            links.push((html_text(href), html_text(&html[tag_end + 1..content_end])));
+        }
+        offset = content_end;
+    }
+    links
+}
+
+/// Strips the tags of an HTML fragment, decodes its entities and collapses its whitespace
+#[cfg(feature = "unstable")]
+fn html_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    let mut decoded = String::new();
+    let mut rest = text.as_str();
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let c = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => match entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+            {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+            }
+            .and_then(char::from_u32),
+        });
+        match (entity, c) {
+            (Some(entity), Some(c)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Checks the temporary directory, and sets the environment variables LibreOffice
+/// reads it from, see [OfficeBuilder::temp_dir]
+fn set_temp_dir_env(dir: &Path) -> Result<(), Error> {
+    let dir = std::fs::canonicalize(dir).map_err(|ex| {
+        Error::new(format!(
+            "Does the temporary directory {} exist? {ex}",
+            dir.display()
+        ))
+    })?;
+
+    // LibreOffice fails later, and cryptically, if it can't write there
+    let probe = dir.join(format!(".libreoffice_rs_probe_{}", std::process::id()));
+    std::fs::write(&probe, b"").map_err(|ex| {
+        Error::new(format!(
+            "The temporary directory {} isn't writable! {ex}",
+            dir.display()
+        ))
+    })?;
+    let _ = std::fs::remove_file(&probe);
+
+    for name in TEMP_DIR_ENVS {
+        std::env::set_var(name, &dir);
+    }
+    Ok(())
+}
+
+/// Makes `path` available as `link`: a hard link, else a symbolic link on unix, else a copy
+fn link_or_copy(path: &Path, link: &Path) -> std::io::Result<()> {
+    std::fs::hard_link(path, link)
+        .or_else(|_| {
+            #[cfg(unix)]
+            return std::os::unix::fs::symlink(std::fs::canonicalize(path)?, link);
+            #[cfg(not(unix))]
+            return Err(std::io::Error::from(std::io::ErrorKind::Unsupported));
+        })
+        .or_else(|_| std::fs::copy(path, link).map(|_| ()))
+}
+
+/// Frees the data of a callback that is no longer registered, if any
+unsafe fn drop_callback_data(data: *mut OfficeCallbackData) {
+    if !data.is_null() {
+        drop(Box::from_raw(data));
+    }
+}
+
+/// Hashes an error message, to recognize it without keeping it
+fn error_hash(error: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    error.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns whether two `program` directories are the same, following symbolic links
+fn same_install_path(a: &Path, b: &Path) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Copies a string allocated by LibreOfficeKit into a `String` and frees it,
+/// returns `None` for a null pointer
+unsafe fn take_lok_string(ptr: *mut std::os::raw::c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    let value = CStr::from_ptr(ptr).to_string_lossy().into_owned();
+    lok_free_wrapper(ptr.cast());
+    Some(value)
+}
+
+impl Document {
+    /// Creates a document without any LibreOfficeKit document, only meant to test that
+    /// dropping it is harmless.
+    ///
+    /// # Safety
+    /// Every method but `drop` dereferences the null document.
+    #[cfg(test)]
+    unsafe fn null_for_tests() -> Document {
+        Document::wrap(
+            std::ptr::null_mut(),
+            urls::internal("private:null").expect("private: URLs are internal"),
+        )
+    }
+
+    fn wrap(doc: *mut LibreOfficeKitDocument, url: DocUrl) -> Document {
+        Document {
+            doc,
+            #[cfg(feature = "unstable")]
+            callbacks: Box::default(),
+            #[cfg(feature = "unstable")]
+            callbacks_registered: std::cell::Cell::new(false),
+            #[cfg(feature = "unstable")]
+            rendering_initialized: false,
+            #[cfg(feature = "unstable")]
+            view_authors: HashMap::new(),
+            #[cfg(feature = "unstable")]
+            read_only_views: HashSet::new(),
+            url,
+            temp_file: None,
+            metrics: Metrics::default(),
+        }
+    }
+
+    /// Returns the underlying LibreOfficeKit document, e.g. to hand it to C code.
+    ///
+    /// The [Document] keeps ownership of it: the pointer is only valid while the
+    /// [Document] is alive, and must not be destroyed.
+    pub fn as_raw(&self) -> *mut LibreOfficeKitDocument {
+        self.doc
+    }
+
+    /// Gives up the underlying LibreOfficeKit document, e.g. to hand it to C code.
+    ///
+    /// The caller takes ownership of it: it is no longer destroyed when dropped,
+    /// the caller must call its `destroy` function, before the [Office] is destroyed.
+    /// The callbacks some methods capture keep being received, into memory that is leaked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let doc = office.document_load(doc_url)?.into_raw();
+    ///
+    /// // e.g. in the C code the document was handed to
+    /// unsafe { (*(*doc).pClass).destroy.unwrap()(doc) };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_raw(mut self) -> *mut LibreOfficeKitDocument {
+        #[cfg(feature = "unstable")]
+        if self.callbacks_registered.get() {
+            // LibreOfficeKit may still hand callbacks to the buffer
+            Box::leak(std::mem::take(&mut self.callbacks));
+        }
+        // the caller may still need the temporary copy it was loaded from
+        self.temp_file = None;
+        // dropping a null document doesn't destroy anything
+        std::mem::replace(&mut self.doc, std::ptr::null_mut())
+    }
+
+    /// Stores the document's persistent data to a URL and
+    /// continues to be a representation of the old URL.
+    ///
+    /// If the result is not true, then there's an error (possibly unsupported format or other errors)
+    ///
+    /// # Arguments
+    /// * `url` - the location where to store the document
+    /// * `format` - the format to use while exporting, when omitted, then deducted from pURL's extension
+    /// * `filter` -  options for the export filter, e.g. SkipImages.Another useful FilterOption is "TakeOwnership".  It is consumed
+    ///               by the saveAs() itself, and when provided, the document identity
+    ///               changes to the provided pUrl - meaning that '.uno:ModifiedStatus'
+    ///               is triggered as with the "Save As..." in the UI.
+    ///              "TakeOwnership" mode must not be used when saving to PNG or PDF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    /// use libreoffice_rs::urls;
+    ///
+    /// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// let output_path = std::env::temp_dir().join("libreoffice_rs_save_as.png");
+    /// let output_location = output_path.display().to_string();
+    /// let previously_saved = doc.save_as(&output_location, "png", None);
+    /// let _ = std::fs::remove_file(&output_path);
+    ///
+    /// assert!(previously_saved, "{}", office.get_error());
+    ///
+    /// #  Ok(())
+    /// # }
+    /// ```
+    pub fn save_as(&mut self, url: &str, format: &str, filter: Option<&str>) -> bool {
+        let c_url = CString::new(url).unwrap();
+        let c_format: CString = CString::new(format).unwrap();
+        let c_filter: CString = CString::new(filter.unwrap_or_default()).unwrap();
+        let ret = self.metrics.time("save_as", &url, || unsafe {
+            (*(*self.doc).pClass).saveAs.unwrap()(
+                self.doc,
+                c_url.as_ptr(),
+                c_format.as_ptr(),
+                c_filter.as_ptr(),
+            )
+        });
+
+        ret != 0
+    }
+
+    /// Stores the document to a URL like [Document::save_as], returning the warnings
+    /// LibreOffice reported meanwhile, e.g. about features the format can't represent.
+    ///
+    /// The warnings are the messages of the `LOK_CALLBACK_ERROR` callbacks of the document
+    /// that aren't classified as errors, collected only for the duration of the save.
+    /// LibreOffice may also report nothing about a lossy conversion.
+    ///
+    /// # Arguments
+    /// * `url` - the location where to store the document
+    /// * `format` - the format to use while exporting, deduced from the URL when empty
+    /// * `filter` - options for the export filter, see [Document::save_as]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// for warning in doc.save_as_with_warnings("/tmp/test.docx", "docx", None)? {
+    ///     eprintln!("{warning}");
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn save_as_with_warnings(
+        &mut self,
+        url: &str,
+        format: &str,
+        filter: Option<&str>,
+    ) -> Result<Vec<String>, Error> {
+        self.register_callback_buffer();
+        self.callbacks.collect_warnings();
+        let saved = self.save_as(url, format, filter);
+        let warnings = self
+            .callbacks
+            .take_warnings()
+            .into_iter()
+            .filter(|(classification, _)| classification != "error")
+            .map(|(_, message)| message)
+            .collect();
+
+        if !saved {
+            return Err(Error::new(format!(
+                "Failed to save the document to {url} as {format}"
+            )));
+        }
+        Ok(warnings)
+    }
+
+    /// Stores the document to a URL like [Document::save_as], returning the
+    /// normalized URL of the saved document on success, e.g. to load it in a next step.
+    ///
+    /// Local files are returned with the URL of their canonical path, see [urls::local_into_abs].
+    ///
+    /// # Arguments
+    /// * `url` - the location where to store the document
+    /// * `format` - the format to use while exporting, see [Document::save_as]
+    /// * `filter` - options for the export filter, see [Document::save_as]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// let output_path = std::env::temp_dir().join("libreoffice_rs_save_as_returning_url.pdf");
+    /// let output_url = urls::local_as_abs(output_path.display().to_string())?;
+    ///
+    /// let saved_url = doc.save_as_returning_url(&output_url, "pdf", None)?;
+    /// let _ = std::fs::remove_file(&output_path);
+    ///
+    /// #  Ok(())
+    /// # }
+    /// ```
+    pub fn save_as_returning_url(
+        &mut self,
+        url: &DocUrl,
+        format: &str,
+        filter: Option<&str>,
+    ) -> Result<DocUrl, Error> {
+        if !self.save_as(&url.to_string(), format, filter) {
+            return Err(Error::new(format!(
+                "Failed to save the document to {url} as {format}"
+            )));
+        }
+        urls::normalize(url)
+    }
+
+    /// Stores the document to a URL like [Document::save_as], but fails with
+    /// [Error::FileExists] instead of overwriting an existing file.
+    ///
+    /// The check only applies to local targets, i.e. `file://` URLs and absolute paths,
+    /// other URLs are saved to unchecked. It happens right before saving,
+    /// so it can't guard against another process creating the file in between.
+    ///
+    /// # Arguments
+    /// * `url` - the location where to store the document
+    /// * `format` - the format to use while exporting, see [Document::save_as]
+    /// * `filter` - options for the export filter, see [Document::save_as]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Error, Office, urls};
+    ///
+    /// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url.clone())?;
+    ///
+    /// let overwritten = doc.save_as_no_overwrite(&doc_url.to_string(), "odt", None);
+    /// assert!(matches!(overwritten, Err(Error::FileExists { .. })));
+    ///
+    /// #  Ok(())
+    /// # }
+    /// ```
+    pub fn save_as_no_overwrite(
+        &mut self,
+        url: &str,
+        format: &str,
+        filter: Option<&str>,
+    ) -> Result<(), Error> {
+        if let Some(path) = urls::local_target_path(url) {
+            if path.exists() {
+                return Err(Error::FileExists { path });
+            }
+        }
+        if self.save_as(url, format, filter) {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "Failed to save the document to {url} as {format}"
+            )))
+        }
+    }
+
+    /// Stores the document to a URL like [Document::save_as], keeping a backup
+    /// of the file it overwrites, e.g. when saving an edited document over its source.
+    ///
+    /// For local targets (`file://` URLs and absolute paths) that already exist, the
+    /// existing file is renamed first, by appending `.bak` to its name (`report.odt`
+    /// becomes `report.odt.bak`, replacing any previous backup). When the save fails,
+    /// whatever it wrote is removed and the backup is renamed back, so the original is
+    /// left intact. When it succeeds, the backup is kept. Other URLs are saved to directly.
+    ///
+    /// # Arguments
+    /// * `url` - the location where to store the document
+    /// * `format` - the format to use while exporting, see [Document::save_as]
+    /// * `filter` - options for the export filter, see [Document::save_as]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let path = std::env::temp_dir().join("libreoffice_rs_report.odt");
+    /// std::fs::copy("./test_data/test.odt", &path)?;
+    /// let mut doc = office.document_load(urls::local_as_abs(path.display().to_string())?)?;
+    ///
+    /// doc.save_as_with_backup(&path.display().to_string(), "odt", None)?;
+    /// let backup = std::env::temp_dir().join("libreoffice_rs_report.odt.bak");
+    /// assert!(backup.is_file());
+    /// # std::fs::remove_file(&backup)?;
+    /// # drop(doc);
+    /// # std::fs::remove_file(&path)?;
+    /// #  Ok(())
+    /// # }
+    /// ```
+    pub fn save_as_with_backup(
+        &mut self,
+        url: &str,
+        format: &str,
+        filter: Option<&str>,
+    ) -> Result<(), Error> {
+        let failed = || Error::new(format!("Failed to save the document to {url} as {format}"));
+        let target = match urls::local_target_path(url) {
+            Some(path) if path.exists() => path,
+            _ => {
+                return if self.save_as(url, format, filter) {
+                    Ok(())
+                } else {
+                    Err(failed())
+                }
+            }
+        };
+
+        let mut backup = target.clone().into_os_string();
+        backup.push(".bak");
+        let backup = PathBuf::from(backup);
+        std::fs::rename(&target, &backup).map_err(|ex| {
+            Error::new(format!(
+                "Failed to back {} up to {}! {ex}",
+                target.display(),
+                backup.display()
+            ))
+        })?;
+
+        if self.save_as(url, format, filter) {
+            return Ok(());
+        }
+        let _ = std::fs::remove_file(&target);
+        std::fs::rename(&backup, &target).map_err(|ex| {
+            Error::new(format!(
+                "Failed to save the document to {url} as {format}, and to restore it from {}! {ex}",
+                backup.display()
+            ))
+        })?;
+        Err(failed())
+    }
+
+    /// Stores the document to a URL, like [Document::save_as], with the
+    /// export filter configured through [FilterOptions].
+    ///
+    /// # Arguments
+    /// * `url` - the location where to store the document
+    /// * `format` - the format to use while exporting, e.g. `pdf` or `png`
+    /// * `options` - the export filter options
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{FilterOptions, Office, urls};
+    ///
+    /// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/multipage.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// let output_path = std::env::temp_dir().join("libreoffice_rs_save_as_with.pdf");
+    /// let options = FilterOptions::new().page_range("2-4")?;
+    /// let saved = doc.save_as_with(&output_path.display().to_string(), "pdf", &options);
+    /// let _ = std::fs::remove_file(&output_path);
+    ///
+    /// assert!(saved.is_ok(), "{:?}", saved.err());
+    ///
+    /// #  Ok(())
+    /// # }
+    /// ```
+    pub fn save_as_with(
+        &mut self,
+        url: &str,
+        format: &str,
+        options: &FilterOptions,
+    ) -> Result<(), Error> {
+        let filter = options.to_filter_string(format)?;
+        if self.save_as(url, format, Some(&filter)) {
+            Ok(())
+        } else {
+            Err(Error::new(format!(
+                "Failed to save the document to {url} as {format}"
+            )))
+        }
+    }
+
+    /// Returns the type of the document
+    pub fn get_document_type(&self) -> DocumentType {
+        let ty = unsafe { (*(*self.doc).pClass).getDocumentType.unwrap()(self.doc) };
+        DocumentType::try_from(ty).unwrap_or(DocumentType::Other)
+    }
+
+    /// Returns whether the document can't be modified, to check before saving it back.
+    ///
+    /// A document is read-only when:
+    /// - the current view was made read-only, e.g. by [Office::document_load_readonly]
+    /// - LibreOffice reported the `.uno:EditDoc` command (the "Edit Mode" toggle) as off,
+    ///   which is only known once [Document::initialize_for_rendering] was called
+    /// - it was loaded from a local file without write permission
+    ///
+    /// This is unrelated to [Document::get_edit_mode], which tells whether the
+    /// slides or the master slides of a presentation are edited: a read-only
+    /// presentation is still in [EditMode::Normal], and a document can be
+    /// editable in LibreOfficeKit while its file is read-only.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let doc = office.document_load(doc_url)?;
+    /// if !doc.is_read_only() {
+    ///     // save the changes
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_read_only(&self) -> bool {
+        #[cfg(feature = "unstable")]
+        if self.is_view_read_only() {
+            return true;
+        }
+
+        match self.url.to_file_path() {
+            Some(path) => std::fs::metadata(path)
+                .map(|metadata| metadata.permissions().readonly())
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+
+    /// Returns whether the current view can't modify the document: the first two cases
+    /// of [Document::is_read_only], leaving out the permissions of the file, since
+    /// a document is edited in memory whether or not it can be saved back
+    #[cfg(feature = "unstable")]
+    pub(crate) fn is_view_read_only(&self) -> bool {
+        self.read_only_views.contains(&self.get_view())
+            || self.callbacks.command_state(".uno:EditDoc").as_deref() == Some("false")
+    }
+
+    /// Returns the number of parts of the document:
+    /// sheets, slides or pages, while text documents have a single part
+    #[cfg(feature = "unstable")]
+    pub fn get_parts(&self) -> i32 {
+        unsafe { (*(*self.doc).pClass).getParts.unwrap()(self.doc) }
+    }
+
+    /// Returns the number of pages of the document.
+    ///
+    /// - Spreadsheets, presentations and drawings: the number of sheets, slides or pages,
+    ///   see [Document::get_parts]. This is a plain synchronous call.
+    /// - Text documents: the page count of the `.uno:StatePageNumber` status LibreOffice
+    ///   broadcasts once the document is initialized for rendering, e.g. `Page 1 of 3`.
+    ///   When it wasn't broadcast (yet), the pages are counted from
+    ///   [Document::get_part_page_rectangles] instead, so this never waits for events.
+    #[cfg(feature = "unstable")]
+    pub fn page_count(&mut self) -> Result<i32, Error> {
+        if self.get_document_type() != DocumentType::Text {
+            return Ok(self.get_parts());
+        }
+
+        if !self.rendering_initialized {
+            self.initialize_for_rendering("");
+        }
+        let page_count = self
+            .callbacks
+            .command_state(".uno:StatePageNumber")
+            .and_then(|state| {
+                // the status is localized, the page count is its second number
+                state
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter(|number| !number.is_empty())
+                    .nth(1)
+                    .and_then(|number| number.parse().ok())
+            });
+        match page_count {
+            Some(page_count) => Ok(page_count),
+            None => Ok(self.get_part_page_rectangles()?.len() as i32),
+        }
+    }
+
+    /// Returns the index of the current part
+    #[cfg(feature = "unstable")]
+    pub fn get_part(&self) -> i32 {
+        unsafe { (*(*self.doc).pClass).getPart.unwrap()(self.doc) }
+    }
+
+    /// Selects the current part of the document.
+    /// Fails with [Error::PartOutOfRange] for an index outside of the parts.
+    ///
+    /// # Arguments
+    /// * `part` - the index of the part, from 0 to [Document::get_parts] excluded
+    #[cfg(feature = "unstable")]
+    pub fn set_part(&mut self, part: i32) -> Result<(), Error> {
+        self.check_part(part)?;
+        unsafe { (*(*self.doc).pClass).setPart.unwrap()(self.doc, part) }
+        Ok(())
+    }
+
+    /// Returns the name of a part, e.g. the name of a sheet or a slide.
+    /// Fails with [Error::PartOutOfRange] for an index outside of the parts.
+    ///
+    /// # Arguments
+    /// * `part` - the index of the part, from 0 to [Document::get_parts] excluded
+    #[cfg(feature = "unstable")]
+    pub fn get_part_name(&self, part: i32) -> Result<Option<String>, Error> {
+        self.check_part(part)?;
+        Ok(unsafe { take_lok_string((*(*self.doc).pClass).getPartName.unwrap()(self.doc, part)) })
+    }
+
+    /// Checks a part index is within the parts of the document, LibreOfficeKit doesn't
+    #[cfg(feature = "unstable")]
+    fn check_part(&self, part: i32) -> Result<(), Error> {
+        let count = self.get_parts();
+        if part < 0 || part >= count {
+            return Err(Error::PartOutOfRange { index: part, count });
+        }
+        Ok(())
+    }
+
+    /// Returns the names of all the parts, in order, see [Document::get_part_name].
+    /// Parts without a name yield an empty string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/three_sheets.ods")?;
+    /// let doc = office.document_load(doc_url)?;
+    ///
+    /// assert_eq!(vec!["Summary", "Data", "Notes"], doc.part_names());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn part_names(&self) -> Vec<String> {
+        (0..self.get_parts())
+            .map(|part| self.get_part_name(part).ok().flatten().unwrap_or_default())
+            .collect()
+    }
+
+    /// Renames a part: a sheet of a spreadsheet, via `.uno:Name`, or a slide or page
+    /// of a presentation or drawing, via `.uno:RenamePage`. The pages of text
+    /// documents have no names.
+    ///
+    /// The part is selected for the rename, then the current part is restored.
+    /// Fails with [Error::PartOutOfRange] for an index outside of the parts, and when
+    /// LibreOffice rejects the name, e.g. the name of another sheet.
+    ///
+    /// # Arguments
+    /// * `part` - the index of the part, from 0 to [Document::get_parts] excluded
+    /// * `name` - the new name of the part
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/three_sheets.ods")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// doc.set_part_name(0, "Totals")?;
+    /// assert_eq!(Some("Totals".to_string()), doc.get_part_name(0)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn set_part_name(&mut self, part: i32, name: &str) -> Result<(), Error> {
+        self.check_part(part)?;
+        if name.is_empty() || name.contains('\0') {
+            return Err(Error::new(format!("{name:?} isn't a valid part name")));
+        }
+
+        let (command, args) = match self.get_document_type() {
+            // the index of the sheet is 1-based
+            DocumentType::Spreadsheet => (
+                ".uno:Name",
+                UnoArgs::new().long("Index", part + 1).string("Name", name),
+            ),
+            DocumentType::Presentation | DocumentType::Drawing => {
+                (".uno:RenamePage", UnoArgs::new().string("Name", name))
+            }
+            ty => {
+                return Err(Error::new(format!(
+                    "The parts of {ty:?} documents can't be renamed"
+                )))
+            }
+        };
+
+        let current_part = self.get_part();
+        self.set_part(part)?;
+        let posted = self.post_uno_command(command, args, false);
+        self.set_part(current_part)?;
+        posted?;
+
+        if self.get_part_name(part)?.as_deref() != Some(name) {
+            return Err(Error::new(format!(
+                "Failed to rename the part {part} to {name}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Returns the id of the current view
+    #[cfg(feature = "unstable")]
+    pub fn get_view(&self) -> i32 {
+        unsafe { (*(*self.doc).pClass).getView.unwrap()(self.doc) }
+    }
+
+    /// Makes a view the current view
+    ///
+    /// # Arguments
+    /// * `view_id` - the id of the view, e.g. from [Document::create_view]
+    #[cfg(feature = "unstable")]
+    pub fn set_view(&mut self, view_id: i32) {
+        unsafe { (*(*self.doc).pClass).setView.unwrap()(self.doc, view_id) }
+    }
+
+    /// Runs `f` with a view as the current view, then makes the previous view
+    /// current again, even when `f` panics.
+    ///
+    /// # Arguments
+    /// * `view_id` - the id of the view to make current while `f` runs
+    /// * `f` - the operations on the view
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// let view = doc.get_view();
+    /// let other_view = doc.create_view();
+    /// doc.set_view(view);
+    ///
+    /// let other_text = doc.with_view(other_view, |doc| doc.selected_text());
+    /// assert_eq!(view, doc.get_view());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn with_view<R>(&mut self, view_id: i32, f: impl FnOnce(&mut Document) -> R) -> R {
+        let previous = self.get_view();
+        self.set_view(view_id);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(self)));
+        self.set_view(previous);
+        result.unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+
+    /// Runs `f` with a part as the current part, then makes the previous part
+    /// current again, even when `f` panics.
+    /// Fails with [Error::PartOutOfRange] for an index outside of the parts.
+    ///
+    /// # Arguments
+    /// * `part` - the index of the part to make current while `f` runs
+    /// * `f` - the operations on the part
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/three_sheets.ods")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let size = doc.with_part(2, |doc| doc.get_document_size())?;
+    /// assert_eq!(0, doc.get_part());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn with_part<R>(
+        &mut self,
+        part: i32,
+        f: impl FnOnce(&mut Document) -> R,
+    ) -> Result<R, Error> {
+        let previous = self.get_part();
+        self.set_part(part)?;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| f(self)));
+        let restored = self.set_part(previous);
+        let result = result.unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+        restored.map(|_| result)
+    }
+
+    /// Sets the language of a view, used e.g. for localized sheet function names
+    ///
+    /// # Arguments
+    /// * `view_id` - the id of the view, e.g. [Document::get_view]
+    /// * `language` - the language of the view
+    #[cfg(feature = "unstable")]
+    pub fn set_view_language(&mut self, view_id: i32, language: &LanguageTag) {
+        let c_language = CString::new(language.as_str()).unwrap();
+        unsafe {
+            (*(*self.doc).pClass).setViewLanguage.unwrap()(self.doc, view_id, c_language.as_ptr())
+        }
+    }
+
+    /// Makes a view read-only, or editable again
+    ///
+    /// # Arguments
+    /// * `view_id` - the id of the view, e.g. [Document::get_view]
+    /// * `read_only` - whether the view can't modify the document
+    #[cfg(feature = "unstable")]
+    pub fn set_view_read_only(&mut self, view_id: i32, read_only: bool) {
+        unsafe { (*(*self.doc).pClass).setViewReadOnly.unwrap()(self.doc, view_id, read_only) }
+        if read_only {
+            self.read_only_views.insert(view_id);
+        } else {
+            self.read_only_views.remove(&view_id);
+        }
+    }
+
+    /// Returns the size of the current part in twips, as `(width, height)`
+    #[cfg(feature = "unstable")]
+    pub fn get_document_size(&self) -> (i64, i64) {
+        let mut width: std::os::raw::c_long = 0;
+        let mut height: std::os::raw::c_long = 0;
+        unsafe {
+            (*(*self.doc).pClass).getDocumentSize.unwrap()(self.doc, &mut width, &mut height);
+        }
+        (width as i64, height as i64)
+    }
+
+    /// Returns the size of the current part in pixels at the given resolution,
+    /// as `(width, height)`, see [units::twips_to_px]
+    ///
+    /// # Arguments
+    /// * `dpi` - the resolution in pixels per inch
+    #[cfg(feature = "unstable")]
+    pub fn get_document_size_px(&self, dpi: u32) -> (u32, u32) {
+        let (width, height) = self.get_document_size();
+        (
+            units::twips_to_px(width, dpi),
+            units::twips_to_px(height, dpi),
+        )
+    }
+
+    /// Maps a point of a canvas showing the whole current part, e.g. a mouse click,
+    /// to document coordinates in twips, e.g. for [Document::post_mouse_event].
+    /// See [units::pixel_to_twip].
+    ///
+    /// # Arguments
+    /// * `px` - the point of the canvas, in pixels from its top left corner
+    /// * `canvas_px` - the size of the canvas in pixels, as `(width, height)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.initialize_for_rendering("");
+    ///
+    /// // a click on a 800 x 1000 pixel rendering of the document
+    /// let (x, y) = doc.pixel_to_twip((120, 80), (800, 1000));
+    /// doc.click_at(x, y);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn pixel_to_twip(&self, px: (i32, i32), canvas_px: (i32, i32)) -> (i32, i32) {
+        units::pixel_to_twip(px, canvas_px, self.get_document_size())
+    }
+
+    /// Maps document coordinates in twips, e.g. of [Document::cursor_rectangle], to a point
+    /// of a canvas showing the whole current part. See [units::twip_to_pixel].
+    ///
+    /// # Arguments
+    /// * `twip` - the point of the document, in twips
+    /// * `canvas_px` - the size of the canvas in pixels, as `(width, height)`
+    #[cfg(feature = "unstable")]
+    pub fn twip_to_pixel(&self, twip: (i32, i32), canvas_px: (i32, i32)) -> (i32, i32) {
+        units::twip_to_pixel(twip, canvas_px, self.get_document_size())
+    }
+
+    /// Sets the area of the current part (in twips) the client displays, so that
+    /// LibreOfficeKit e.g. keeps the cursor within it and knows what to invalidate
+    ///
+    /// # Arguments
+    /// * `area` - the visible area, in twips
+    #[cfg(feature = "unstable")]
+    pub fn set_client_visible_area(&mut self, area: Rect) {
+        unsafe {
+            (*(*self.doc).pClass).setClientVisibleArea.unwrap()(
+                self.doc,
+                area.x,
+                area.y,
+                area.width,
+                area.height,
+            )
+        }
+    }
+
+    /// Sets the zoom level of the tiles LibreOfficeKit renders, e.g. to adapt
+    /// the cursor and selection handles to it, via `setClientZoom`.
+    ///
+    /// The client is assumed to paint square tiles of [DocumentType::default_tile_size]
+    /// pixels on a 96 DPI screen (see [units::zoom_to_dpi]): at 100%, a 256 pixel tile
+    /// of a text document covers 3840 x 3840 twips, at 200% half of that.
+    /// Pass the same twips to [Document::paint_tile].
+    ///
+    /// # Arguments
+    /// * `percent` - the zoom level, from 10 to 3000 like in LibreOffice, e.g. 150 for 150%
+    #[cfg(feature = "unstable")]
+    pub fn set_zoom(&mut self, percent: u32) -> Result<(), Error> {
+        if !(10..=3000).contains(&percent) {
+            return Err(Error::new(format!(
+                "The zoom level {percent}% is outside of 10% to 3000%"
+            )));
+        }
+
+        let tile_px = self.get_document_type().default_tile_size() as i64;
+        let tile_twips = tile_px * units::TWIPS_PER_INCH / units::zoom_to_dpi(percent) as i64;
+        unsafe {
+            (*(*self.doc).pClass).setClientZoom.unwrap()(
+                self.doc,
+                tile_px as i32,
+                tile_px as i32,
+                tile_twips as i32,
+                tile_twips as i32,
+            )
+        }
+        Ok(())
+    }
+
+    /// Sets the visible area to the whole current part, from [Document::get_document_size],
+    /// for rendering all of it with [Document::paint_tile].
+    /// Call it again after switching to a part of another size.
+    #[cfg(feature = "unstable")]
+    pub fn set_visible_area_full(&mut self) {
+        let (width, height) = self.get_document_size();
+        self.set_client_visible_area(Rect::new(0, 0, width as i32, height as i32));
+    }
+
+    /// Returns the rectangles (in twips) of the pages of a text document
+    #[cfg(feature = "unstable")]
+    pub fn get_part_page_rectangles(&self) -> Result<Vec<Rect>, Error> {
+        let rectangles = unsafe {
+            take_lok_string((*(*self.doc).pClass).getPartPageRectangles.unwrap()(
+                self.doc,
+            ))
+        };
+        Rect::parse_list(&rectangles.unwrap_or_default())
+    }
+
+    /// Returns the rectangle (in twips) of a page of a text document,
+    /// or `None` for a page it doesn't have, see [Document::get_part_page_rectangles]
+    ///
+    /// # Arguments
+    /// * `page` - the index of the page, from 0
+    #[cfg(feature = "unstable")]
+    pub fn page_rectangle(&self, page: usize) -> Option<Rect> {
+        let rectangles = unsafe {
+            take_lok_string((*(*self.doc).pClass).getPartPageRectangles.unwrap()(
+                self.doc,
+            ))
+        };
+        nth_page_rectangle(&rectangles.unwrap_or_default(), page)
+    }
+
+    /// Returns the pixel format [Document::paint_tile] renders
+    #[cfg(feature = "unstable")]
+    pub fn get_tile_mode(&self) -> TileMode {
+        let mode = unsafe { (*(*self.doc).pClass).getTileMode.unwrap()(self.doc) };
+        TileMode::try_from(mode).unwrap_or(TileMode::Rgba)
+    }
+
+    /// Returns the type of the current selection
+    #[cfg(feature = "unstable")]
+    pub fn get_selection_type(&self) -> Result<SelectionType, Error> {
+        SelectionType::try_from(unsafe {
+            (*(*self.doc).pClass).getSelectionType.unwrap()(self.doc)
+        })
+    }
+
+    /// Returns the state of the digital signatures of the document
+    #[cfg(feature = "unstable")]
+    pub fn get_signature_state(&self) -> Result<SignatureState, Error> {
+        SignatureState::try_from(unsafe {
+            (*(*self.doc).pClass).getSignatureState.unwrap()(self.doc)
+        })
+    }
+
+    /// Returns the edit mode of a presentation, other documents are in [EditMode::Normal]
+    ///
+    /// The edit mode is about editing slides or master slides, and says nothing
+    /// about whether the document can be modified: see [Document::is_read_only].
+    #[cfg(feature = "unstable")]
+    pub fn get_edit_mode(&self) -> Result<EditMode, Error> {
+        EditMode::try_from(unsafe { (*(*self.doc).pClass).getEditMode.unwrap()(self.doc) })
+    }
+
+    /// Paints a tile of the current part into a buffer of premultiplied pixels,
+    /// in the format given by [Document::get_tile_mode].
+    ///
+    /// The area of the document between (`tile_pos_x`, `tile_pos_y`) and
+    /// (`tile_pos_x + tile_width`, `tile_pos_y + tile_height`), in twips,
+    /// is scaled to the `canvas_width` x `canvas_height` pixels of the buffer.
+    ///
+    /// # Arguments
+    /// * `buffer` - the pixels, at least `canvas_width * canvas_height * 4` bytes
+    /// * `canvas_width` - the width of the buffer in pixels
+    /// * `canvas_height` - the height of the buffer in pixels
+    /// * `tile_pos_x` - the left of the painted area in twips
+    /// * `tile_pos_y` - the top of the painted area in twips
+    /// * `tile_width` - the width of the painted area in twips
+    /// * `tile_height` - the height of the painted area in twips
+    #[cfg(feature = "unstable")]
+    pub fn paint_tile(
+        &mut self,
+        buffer: &mut [u8],
+        canvas_width: i32,
+        canvas_height: i32,
+        tile_pos_x: i32,
+        tile_pos_y: i32,
+        tile_width: i32,
+        tile_height: i32,
+    ) -> Result<(), Error> {
+        if canvas_width <= 0 || canvas_height <= 0 {
+            return Err(Error::new(format!(
+                "Invalid canvas size {canvas_width}x{canvas_height}"
+            )));
+        }
+        let required = canvas_width as usize * canvas_height as usize * 4;
+        if buffer.len() < required {
+            return Err(Error::new(format!(
+                "Tile buffer holds {} bytes, {required} are required",
+                buffer.len()
+            )));
+        }
+        unsafe {
+            (*(*self.doc).pClass).paintTile.unwrap()(
+                self.doc,
+                buffer.as_mut_ptr(),
+                canvas_width,
+                canvas_height,
+                tile_pos_x,
+                tile_pos_y,
+                tile_width,
+                tile_height,
+            );
+        }
+        Ok(())
+    }
+
+    /// Paints a tile like [Document::paint_tile], into a buffer whose rows are `stride`
+    /// bytes apart, e.g. a region of a larger framebuffer.
+    ///
+    /// LibreOfficeKit paints tightly packed rows, so the tile is painted into a scratch
+    /// buffer first, then copied row by row. The bytes between the rows are left untouched.
+    ///
+    /// # Arguments
+    /// * `buffer` - the pixels, at least `stride * (canvas_height - 1) + canvas_width * 4` bytes
+    /// * `stride` - the distance between the starts of two rows in bytes,
+    ///              at least `canvas_width * 4`
+    /// * `canvas_width` - the width of the tile in pixels
+    /// * `canvas_height` - the height of the tile in pixels
+    /// * `tile_pos_x` - the left of the painted area in twips
+    /// * `tile_pos_y` - the top of the painted area in twips
+    /// * `tile_width` - the width of the painted area in twips
+    /// * `tile_height` - the height of the painted area in twips
+    #[cfg(feature = "unstable")]
+    pub fn paint_tile_into(
+        &mut self,
+        buffer: &mut [u8],
+        stride: usize,
+        canvas_width: i32,
+        canvas_height: i32,
+        tile_pos_x: i32,
+        tile_pos_y: i32,
+        tile_width: i32,
+        tile_height: i32,
+    ) -> Result<(), Error> {
+        if canvas_width <= 0 || canvas_height <= 0 {
+            return Err(Error::new(format!(
+                "Invalid canvas size {canvas_width}x{canvas_height}"
+            )));
+        }
+        let row_len = canvas_width as usize * 4;
+        let rows = canvas_height as usize;
+        if stride < row_len {
+            return Err(Error::new(format!(
+                "Stride of {stride} bytes is shorter than a row of {row_len} bytes"
+            )));
+        }
+        let required = stride * (rows - 1) + row_len;
+        if buffer.len() < required {
+            return Err(Error::new(format!(
+                "Tile buffer holds {} bytes, {required} are required",
+                buffer.len()
+            )));
+        }
+
+        let mut scratch = vec![0u8; row_len * rows];
+        self.paint_tile(
+            &mut scratch,
+            canvas_width,
+            canvas_height,
+            tile_pos_x,
+            tile_pos_y,
+            tile_width,
+            tile_height,
+        )?;
+        for (row, pixels) in scratch.chunks_exact(row_len).enumerate() {
+            buffer[row * stride..row * stride + row_len].copy_from_slice(pixels);
+        }
+        Ok(())
+    }
+
+    /// Prepares the document for rendering and starts capturing the
+    /// callbacks of the current view, which some methods rely on.
+    ///
+    /// # Arguments
+    /// * `args` - JSON rendering arguments, e.g. `{".uno:HideWhitespace":{"type":"boolean","value":"true"}}`,
+    ///            or an empty string for the defaults
+    #[cfg(feature = "unstable")]
+    pub fn initialize_for_rendering(&mut self, args: &str) {
+        let c_args = CString::new(args).unwrap();
+        unsafe {
+            (*(*self.doc).pClass).initializeForRendering.unwrap()(self.doc, c_args.as_ptr());
+        }
+        self.rendering_initialized = true;
+        self.register_callback_buffer();
+    }
+
+    /// Posts a UNO command to the document.
+    ///
+    /// Fails when the command or its arguments contain a NUL character.
+    ///
+    /// # Arguments
+    /// * `command` - the command, e.g. `.uno:SelectAll`
+    /// * `args` - the JSON arguments of the command, built with [UnoArgs], or an empty string
+    /// * `notify_when_finished` - whether to report the result via `LOK_CALLBACK_UNO_COMMAND_RESULT`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, UnoArgs, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.initialize_for_rendering("");
+    /// doc.post_uno_command(".uno:InsertText", UnoArgs::new().string("Text", "Hello"), false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn post_uno_command(
+        &mut self,
+        command: &str,
+        args: impl AsRef<str>,
+        notify_when_finished: bool,
+    ) -> Result<(), Error> {
+        self.dispatch_uno_command(command, args.as_ref(), notify_when_finished)
+    }
+
+    /// Posts a UNO command, see [Document::post_uno_command]. Only takes `&self`
+    /// for the commands that just move the cursor or the selection, e.g. `.uno:GoRight`
+    #[cfg(feature = "unstable")]
+    fn dispatch_uno_command(
+        &self,
+        command: &str,
+        args: &str,
+        notify_when_finished: bool,
+    ) -> Result<(), Error> {
+        let (Ok(c_command), Ok(c_args)) = (CString::new(command), CString::new(args)) else {
+            return Err(Error::new(format!(
+                "The UNO command {command:?} or its arguments {args:?} contain a NUL character"
+            )));
+        };
+        unsafe {
+            (*(*self.doc).pClass).postUnoCommand.unwrap()(
+                self.doc,
+                c_command.as_ptr(),
+                c_args.as_ptr(),
+                notify_when_finished,
+            );
+        }
+        Ok(())
+    }
+
+    /// Posts a UNO command to the document like [Document::post_uno_command], and waits
+    /// until LibreOffice reports that it finished, returning the payload of its
+    /// `LOK_CALLBACK_UNO_COMMAND_RESULT`, e.g. `{"commandName": ".uno:Bold", "success": true}`.
+    ///
+    /// LibreOffice delivers callbacks from its main loop, after the call causing them
+    /// returned, and in order: once the result arrived, the other callbacks of the command
+    /// (e.g. the new selection) arrived too, see [Document::get_selection_rectangles].
+    /// Fails when the result doesn't arrive within `timeout`.
+    ///
+    /// # Arguments
+    /// * `command` - the command, e.g. `.uno:SelectAll`
+    /// * `args` - the JSON arguments of the command, built with [UnoArgs], or an empty string
+    /// * `timeout` - how long to wait for the result
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, UnoArgs, urls};
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let result = doc.post_uno_command_and_wait(".uno:SelectAll", "", Duration::from_secs(5))?;
+    /// assert_eq!(Some(true), result["success"].as_bool());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn post_uno_command_and_wait(
+        &mut self,
+        command: &str,
+        args: impl AsRef<str>,
+        timeout: std::time::Duration,
+    ) -> Result<serde_json::Value, Error> {
+        self.register_callback_buffer();
+        self.callbacks.clear(CallbackType::UnoCommandResult);
+        self.post_uno_command(command, args, true)?;
+
+        let ty = CallbackType::UnoCommandResult as std::os::raw::c_int;
+        self.callbacks
+            .wait_for(timeout, |payloads| {
+                let result = serde_json::from_str::<serde_json::Value>(payloads.get(&ty)?).ok()?;
+                (result["commandName"].as_str() == Some(command)).then_some(result)
+            })
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "No result was reported for the command {command} within {timeout:?}"
+                ))
+            })
+    }
+
+    /// Inserts text at the cursor position, replacing the current selection.
+    ///
+    /// Every `\n` starts a new paragraph, via `.uno:InsertPara`, the lines
+    /// themselves are inserted with `.uno:InsertText`.
+    ///
+    /// # Arguments
+    /// * `text` - the text to insert
+    #[cfg(feature = "unstable")]
+    pub fn insert_text(&mut self, text: &str) -> Result<(), Error> {
+        if text.contains('\0') {
+            return Err(Error::new("Text to insert contains a NUL character".into()));
+        }
+
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.post_uno_command(".uno:InsertPara", "", false)?;
+            }
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if !line.is_empty() {
+                let args = UnoArgs::new().string("Text", line);
+                self.post_uno_command(".uno:InsertText", args, false)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces every occurrence of `search` by `replace`, via `.uno:ExecuteSearch`,
+    /// and returns the number of replacements.
+    ///
+    /// The count comes from the `LOK_CALLBACK_SEARCH_RESULT_SELECTION` (or
+    /// `LOK_CALLBACK_SEARCH_NOT_FOUND`) callback the search triggers, an error is
+    /// returned when neither was received.
+    ///
+    /// # Arguments
+    /// * `search` - the text, or regular expression, to search for
+    /// * `replace` - the replacement, which may refer to groups (`$1`) for regular expressions
+    /// * `case_sensitive` - whether the search matches case
+    /// * `regex` - whether `search` is a regular expression rather than literal text
+    #[cfg(feature = "unstable")]
+    pub fn replace_all(
+        &mut self,
+        search: &str,
+        replace: &str,
+        case_sensitive: bool,
+        regex: bool,
+    ) -> Result<u32, Error> {
+        /// `SvxSearchCmd::REPLACE_ALL`
+        const REPLACE_ALL: i32 = 3;
+
+        let result = self.execute_search(search, replace, REPLACE_ALL, case_sensitive, regex)?;
+        let count = result.map_or(0, |result| {
+            result["searchResultSelection"]
+                .as_array()
+                .map_or(0, |selections| selections.len())
+        });
+        Ok(count as u32)
+    }
+
+    /// Returns the target and the bounding rectangle (in twips) of every hyperlink
+    /// of a text document, in document order.
+    ///
+    /// Writer stores hyperlinks as the `HyperLinkURL` attribute of text portions, which
+    /// only the UNO API exposes, and `getCommandValues` has no key for them. So they are
+    /// read from the `<a>` elements of the HTML of a select-all, see
+    /// [Document::get_text_selection], and located by searching their text: the n-th
+    /// link with a given text gets the rectangle of the n-th occurrence of that text,
+    /// which is wrong when the same text also appears outside of a link before it. Links whose text isn't found get an empty rectangle.
+    /// The selection is changed in the process.
+    #[cfg(feature = "unstable")]
+    pub fn hyperlinks(&mut self) -> Result<Vec<(String, Rect)>, Error> {
+        /// `SvxSearchCmd::FIND_ALL`
+        const FIND_ALL: i32 = 1;
+
+        self.select_all()?;
+        let html = self.selected_html().unwrap_or_default();
+
+        let mut occurrences: HashMap<String, Vec<Rect>> = HashMap::new();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        let mut links = Vec::new();
+        for (target, text) in html_links(&html) {
+            if !occurrences.contains_key(&text) {
+                let rects = match self.execute_search(&text, "", FIND_ALL, true, false)? {
+                    Some(result) => search_result_rects(&result)?,
+                    None => Vec::new(),
+                };
+                occurrences.insert(text.clone(), rects);
+            }
+            let index = seen.entry(text.clone()).or_insert(0);
+            let rect = occurrences[&text].get(*index).copied().unwrap_or_default();
+            *index += 1;
+            links.push((target, rect));
+        }
+
+        Ok(links)
+    }
+
+    /// Runs a macro in the context of the document, e.g. a Basic macro stored in it,
+    /// returning whether it ran successfully.
+    ///
+    /// Unlike [Office::run_macro], which runs a macro of LibreOffice (or a `macro:` URL
+    /// naming a document) without any current document, the macro is dispatched to the
+    /// current view of this document: `ThisComponent` is this document, and macros stored
+    /// in it are found. Like its macros bound to events, the macros stored in the document
+    /// only run when it was loaded with macros enabled, see [Office::set_macro_security].
+    ///
+    /// The result comes from the `LOK_CALLBACK_UNO_COMMAND_RESULT` callback of the
+    /// dispatch, see [Document::post_uno_command_and_wait]: an error is returned when it
+    /// isn't received within 10 minutes, e.g. when the macro waits for a dialog.
+    ///
+    /// # Arguments
+    /// * `path` - the script URL of the macro, e.g.
+    ///            `vnd.sun.star.script:Standard.Module1.Main?language=Basic&location=document`,
+    ///            or a Basic `macro:` URL, e.g. `macro://./Standard.Module1.Main`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{MacroSecurity, Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// office.set_macro_security(MacroSecurity::Enabled);
+    /// let doc_url = urls::local_into_abs("./test_data/macro.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let ran = doc.run_macro(
+    ///     "vnd.sun.star.script:Standard.Module1.Main?language=Basic&location=document",
+    /// )?;
+    /// assert!(ran);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn run_macro(&mut self, path: &str) -> Result<bool, Error> {
+        let is_macro_url = ["vnd.sun.star.script:", "macro:"]
+            .iter()
+            .any(|scheme| path.starts_with(scheme));
+        if !is_macro_url || path.contains('\0') {
+            return Err(Error::new(format!(
+                "{path} isn't the URL of a macro, e.g. vnd.sun.star.script:Standard.Module1.Main?language=Basic&location=document"
+            )));
+        }
+
+        // e.g. `{"commandName": "vnd.sun.star.script:...", "success": true}`
+        let result = self.post_uno_command_and_wait(path, "", MACRO_TIMEOUT)?;
+        Ok(result["success"].as_bool().unwrap_or(false))
+    }
+
+    /// Runs a `.uno:ExecuteSearch` command and returns its parsed
+    /// `LOK_CALLBACK_SEARCH_RESULT_SELECTION` payload, or `None` when nothing was found
+    ///
+    /// # Arguments
+    /// * `command` - the `SvxSearchCmd`, e.g. 1 to find all or 3 to replace all
+    #[cfg(feature = "unstable")]
+    fn execute_search(
+        &mut self,
+        search: &str,
+        replace: &str,
+        command: i32,
+        case_sensitive: bool,
+        regex: bool,
+    ) -> Result<Option<serde_json::Value>, Error> {
+        /// `TransliterationFlags::IGNORE_CASE`
+        const IGNORE_CASE: i32 = 256;
+
+        let args = UnoArgs::new()
+            .string("SearchItem.SearchString", search)
+            .string("SearchItem.ReplaceString", replace)
+            .long("SearchItem.Command", command)
+            .boolean("SearchItem.Backward", false)
+            .long(
+                "SearchItem.TransliterateFlags",
+                if case_sensitive { 0 } else { IGNORE_CASE },
+            )
+            .short("SearchItem.AlgorithmType", if regex { 1 } else { 0 })
+            .short("SearchItem.AlgorithmType2", if regex { 2 } else { 1 });
+
+        self.register_callback_buffer();
+        self.callbacks.clear(CallbackType::SearchResultSelection);
+        self.callbacks.clear(CallbackType::SearchNotFound);
+        // the search callbacks arrive before the result of the command
+        self.post_uno_command_and_wait(".uno:ExecuteSearch", args, callback::CALLBACK_TIMEOUT)?;
+
+        if let Some(payload) = self
+            .callbacks
+            .last_payload(CallbackType::SearchResultSelection)
+        {
+            let result: serde_json::Value = serde_json::from_str(&payload).map_err(|ex| {
+                Error::new(format!("Failed to parse search result {payload}! {ex}"))
+            })?;
+            return Ok(Some(result));
+        }
+        if self
+            .callbacks
+            .last_payload(CallbackType::SearchNotFound)
+            .is_some()
+        {
+            return Ok(None);
+        }
+
+        Err(Error::new(format!(
+            "No search result was reported for {search}"
+        )))
+    }
+
+    /// Selects the whole content of the document, via `.uno:SelectAll`: the text of
+    /// a text document, the cells of the current sheet or the objects of the current slide.
+    ///
+    /// Waits until LibreOffice reports that the command finished (for up to 30 seconds),
+    /// so the callbacks of the new selection have arrived, see [Document::post_uno_command_and_wait].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// doc.select_all()?;
+    /// assert_eq!(Some("This is a test"), doc.selected_text().as_deref().map(str::trim));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn select_all(&mut self) -> Result<(), Error> {
+        self.post_uno_command_and_wait(".uno:SelectAll", "", callback::CALLBACK_TIMEOUT)?;
+        Ok(())
+    }
+
+    /// Clears the current selection, e.g. after [Document::select_all]
+    #[cfg(feature = "unstable")]
+    pub fn deselect(&mut self) {
+        unsafe { (*(*self.doc).pClass).resetSelection.unwrap()(self.doc) }
+    }
+
+    /// Returns the current selection converted to the given MIME type,
+    /// or `None` when LibreOfficeKit can't provide it.
+    ///
+    /// # Arguments
+    /// * `mime_type` - the requested format, e.g. `text/plain;charset=utf-8` or `text/html`
+    #[cfg(feature = "unstable")]
+    pub fn get_text_selection(&self, mime_type: &str) -> Option<String> {
+        let c_mime_type = CString::new(mime_type).unwrap();
+        let mut used_mime_type: *mut std::os::raw::c_char = std::ptr::null_mut();
+        unsafe {
+            let text = (*(*self.doc).pClass).getTextSelection.unwrap()(
+                self.doc,
+                c_mime_type.as_ptr(),
+                &mut used_mime_type,
+            );
+            take_lok_string(used_mime_type);
+            take_lok_string(text)
+        }
+    }
+
+    /// Returns the current selection as UTF-8 plain text,
+    /// see [Document::get_text_selection] for other formats.
+    #[cfg(feature = "unstable")]
+    pub fn selected_text(&self) -> Option<String> {
+        self.get_text_selection("text/plain;charset=utf-8")
+    }
+
+    /// Returns the current selection as HTML, see [Document::get_text_selection].
+    ///
+    /// This is the HTML LibreOffice puts on the clipboard: a complete document
+    /// with its `<html>`, `<head>` (meta data and styles) and `<body>` wrapper
+    /// markup around the selected content, not only a fragment of it.
+    #[cfg(feature = "unstable")]
+    pub fn selected_html(&self) -> Option<String> {
+        self.get_text_selection("text/html")
+    }
+
+    /// Sets the font color of the current selection, via `.uno:Color`
+    ///
+    /// # Arguments
+    /// * `color` - the font color
+    #[cfg(feature = "unstable")]
+    pub fn set_font_color(&mut self, color: Color) -> Result<(), Error> {
+        let args = UnoArgs::new().long("Color", color.to_lok_value() as i32);
+        self.post_uno_command(".uno:Color", args, false)
+    }
+
+    /// Sets the highlighting (character background) color of the current
+    /// selection, via `.uno:CharBackColor`
+    ///
+    /// # Arguments
+    /// * `color` - the highlighting color
+    #[cfg(feature = "unstable")]
+    pub fn set_highlight_color(&mut self, color: Color) -> Result<(), Error> {
+        let args = UnoArgs::new().long("CharBackColor", color.to_lok_value() as i32);
+        self.post_uno_command(".uno:CharBackColor", args, false)
+    }
+
+    /// Sets a diagonal text watermark on every page of a text document, via `.uno:Watermark`,
+    /// so that it appears in later exports, e.g. to PDF.
+    ///
+    /// The watermark is a shape in the page headers of the document, like the one of
+    /// *Format > Watermark* in Writer: it is only supported by text documents,
+    /// and saving the document in its own format keeps it.
+    /// An empty text removes the watermark.
+    ///
+    /// # Arguments
+    /// * `text` - the text of the watermark, e.g. `CONFIDENTIAL`
+    /// * `opacity` - the opacity of the watermark in percent, from 0 (invisible) to 100
     ///
     /// # Example
     ///
     /// ```
-    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures, urls};
-    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use libreoffice_rs::{Office, urls};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let doc_url = urls::local_into_abs("./test_data/test_password.odt")?;
-    /// let password = "test";
-    /// let password_was_set = AtomicBool::new(false);
     /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
-    ///
-    /// office.set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])?;
-    /// office.register_callback({
-    ///     let mut office = office.clone();
-    ///     let doc_url = doc_url.clone();
-    ///     move |_, _| {
-    ///         if !password_was_set.load(Ordering::Acquire) {
-    ///             let ret = office.set_document_password(doc_url.clone(), &password);
-    ///             password_was_set.store(true, Ordering::Release);
-    ///         }
-    ///     }
-    /// })?;
-    ///
-    /// let mut _doc = office.document_load(doc_url)?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.set_watermark("CONFIDENTIAL", 50)?;
+    /// doc.save_as("/tmp/confidential.pdf", "pdf", None);
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub fn set_document_password(&mut self, url: DocUrl, password: &str) -> Result<(), Error> {
-        let c_url = CString::new(url.to_string()).unwrap();
-        let c_password = CString::new(password).unwrap();
+    #[cfg(feature = "unstable")]
+    pub fn set_watermark(&mut self, text: &str, opacity: u8) -> Result<(), Error> {
+        if opacity > 100 {
+            return Err(Error::new(format!(
+                "The watermark opacity {opacity} is not a percentage"
+            )));
+        }
+        let ty = self.get_document_type();
+        if ty != DocumentType::Text {
+            return Err(Error::new(format!(
+                "Watermarks are only supported by text documents, not {ty:?}"
+            )));
+        }
+
+        let args = UnoArgs::new()
+            .string("Text", text)
+            .short("Angle", 45)
+            .short("Transparency", 100 - opacity as i16)
+            .long(
+                "Color",
+                Color::from_rgb(0xc0, 0xc0, 0xc0).to_lok_value() as i32,
+            );
+        self.post_uno_command(".uno:Watermark", args, false)
+    }
+
+    /// Posts a mouse event to the current view, like a click in the document window.
+    ///
+    /// A click is a `ButtonDown` then a `ButtonUp` event with a `count` of 1, a double
+    /// click follows them with a second pair with a `count` of 2, see [Document::click_at]
+    /// and [Document::double_click_at].
+    ///
+    /// # Arguments
+    /// * `ty` - the type of the event
+    /// * `x` - the horizontal position of the mouse, in twips
+    /// * `y` - the vertical position of the mouse, in twips
+    /// * `count` - the number of clicks of the event, e.g. 2 for a double click
+    /// * `buttons` - the pressed buttons, `MOUSE_LEFT` (1), `MOUSE_MIDDLE` (2) and `MOUSE_RIGHT` (4)
+    /// * `modifier` - the pressed modifier keys, `KEY_SHIFT` (0x1000), `KEY_MOD1` (0x2000,
+    ///                Ctrl) and `KEY_MOD2` (0x4000, Alt)
+    #[cfg(feature = "unstable")]
+    pub fn post_mouse_event(
+        &mut self,
+        ty: MouseEventType,
+        x: i32,
+        y: i32,
+        count: i32,
+        buttons: i32,
+        modifier: i32,
+    ) {
         unsafe {
-            (*self.lok_clz).setDocumentPassword.unwrap()(
-                self.lok,
-                c_url.as_ptr(),
-                c_password.as_ptr(),
+            (*(*self.doc).pClass).postMouseEvent.unwrap()(
+                self.doc, ty as i32, x, y, count, buttons, modifier,
             );
-            let error = self.get_error();
-            if error != "" {
-                return Err(Error::new(error));
-            }
-            Ok(())
         }
     }
 
-    /// This method provides a defense mechanism against infinite loops, upon password entry failures:
-    /// * Loading the document is blocked until a valid password is set within callbacks
-    /// * A wrong password will result into infinite repeated callback loops
-    /// * This method advises `LibreOfficeKit` to stop requesting a password *"as soon as possible"*
-    ///
-    /// It is safe for this method to be invoked even if the originally provided password was correct:
-    /// - `LibreOfficeKit` appears to maintain thread-local values of the password. It will stick to the first password entry value.
-    /// That will translate into a a successfully loaded document.
-    /// - `LibreOfficeKit` seems to send an "excessive" number of callbacks (potential internal issues with locks/monitors)
+    /// Clicks the left mouse button at a position, e.g. to move the cursor there,
+    /// see [Document::post_mouse_event]
     ///
     /// # Arguments
-    ///  * `url` - the URL of the document, as sent to the callback
+    /// * `x` - the horizontal position of the click, in twips
+    /// * `y` - the vertical position of the click, in twips
     ///
     /// # Example
     ///
     /// ```
-    /// use libreoffice_rs::{Office, LibreOfficeKitOptionalFeatures, urls};
-    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use libreoffice_rs::{Office, urls};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let doc_url = urls::local_into_abs("./test_data/test_password.odt")?;
-    /// let password = "forgotten_invalid_password_which_is_just_test";
-    /// let password_was_set = AtomicBool::new(false);
-    /// let failed_password_attempt = AtomicBool::new(false);
     /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.initialize_for_rendering("");
     ///
-    /// office.set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])?;
-    /// office.register_callback({
-    ///     let mut office = office.clone();
-    ///     let doc_url = doc_url.clone();
-    ///     move |_, _| {
-    ///         if !password_was_set.load(Ordering::Acquire) {
-    ///             let ret = office.set_document_password(doc_url.clone(), &password);
-    ///             password_was_set.store(true, Ordering::Release);
-    ///         } else {
-    ///             if !failed_password_attempt.load(Ordering::Acquire) {
-    ///                 let ret = office.unset_document_password(doc_url.clone());
-    ///                 failed_password_attempt.store(true, Ordering::Release);
-    ///             }
-    ///         }
-    ///     }
-    /// })?;
-    ///
-    /// assert!(office.document_load(doc_url).is_err(),
-    ///         "Document loaded successfully with a wrong password!");
-    ///
+    /// // the start of the first line, 2 cm from the top left corner of the page
+    /// doc.click_at(1134, 1134);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn unset_document_password(&mut self, url: DocUrl) -> Result<(), Error> {
-        let c_url = CString::new(url.to_string()).unwrap();
+    #[cfg(feature = "unstable")]
+    pub fn click_at(&mut self, x: i32, y: i32) {
+        self.click(x, y, 1);
+    }
+
+    /// Double-clicks the left mouse button at a position, e.g. to select a word,
+    /// see [Document::post_mouse_event]
+    ///
+    /// # Arguments
+    /// * `x` - the horizontal position of the double click, in twips
+    /// * `y` - the vertical position of the double click, in twips
+    #[cfg(feature = "unstable")]
+    pub fn double_click_at(&mut self, x: i32, y: i32) {
+        self.click(x, y, 1);
+        self.click(x, y, 2);
+    }
+
+    /// Presses and releases the left mouse button, the `count`th time in a row
+    #[cfg(feature = "unstable")]
+    fn click(&mut self, x: i32, y: i32, count: i32) {
+        /// `MOUSE_LEFT` of vcl
+        const MOUSE_LEFT: i32 = 1;
+
+        self.post_mouse_event(MouseEventType::ButtonDown, x, y, count, MOUSE_LEFT, 0);
+        self.post_mouse_event(MouseEventType::ButtonUp, x, y, count, MOUSE_LEFT, 0);
+    }
+
+    /// Returns the rectangle (in twips) of the text cursor of the current view,
+    /// `None` when it is hidden or wasn't reported yet.
+    ///
+    /// The rectangle is parsed from the latest `LOK_CALLBACK_INVALIDATE_VISIBLE_CURSOR`
+    /// payload of the current view. Callbacks are captured from the first call of
+    /// [Document::initialize_for_rendering] (or of this method) on, so move the
+    /// cursor after that.
+    #[cfg(feature = "unstable")]
+    pub fn cursor_rectangle(&self) -> Option<Rect> {
+        self.register_callback_buffer();
+        let payload = self
+            .callbacks
+            .last_payload(CallbackType::InvalidateVisibleCursor)?;
+        // e.g. `1418, 1418, 0, 276`, or `{"viewId": "0", "rectangle": "1418, 1418, 0, 276"}`
+        // with `LOK_FEATURE_VIEWID_IN_VISCURSOR_INVALIDATION_CALLBACK`
+        let rectangle = match serde_json::from_str::<serde_json::Value>(&payload) {
+            Ok(json) => json["rectangle"].as_str()?.to_string(),
+            Err(_) => payload,
+        };
+        Rect::parse_list(&rectangle).ok()?.into_iter().next()
+    }
+
+    /// Returns the rectangles (in twips) of the current text selection,
+    /// empty when nothing is selected.
+    ///
+    /// The rectangles are parsed from the latest `LOK_CALLBACK_TEXT_SELECTION`
+    /// payload of the current view. Callbacks are captured from the first call of
+    /// [Document::initialize_for_rendering] (or of this method) on, so make the
+    /// selection after that.
+    ///
+    /// LibreOffice delivers the callbacks from its main loop, a while after the command
+    /// changing the selection returned: make the selection with a method that waits for
+    /// them, e.g. [Document::select_all] or [Document::post_uno_command_and_wait],
+    /// rather than with [Document::post_uno_command].
+    #[cfg(feature = "unstable")]
+    pub fn get_selection_rectangles(&self) -> Vec<Rect> {
+        self.register_callback_buffer();
+        self.callbacks
+            .last_payload(CallbackType::TextSelection)
+            .and_then(|payload| Rect::parse_list(&payload).ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns the JSON values of a command, e.g. `.uno:CharFontName`,
+    /// or `None` when LibreOfficeKit returns nothing for it, or the command
+    /// contains a NUL character.
+    ///
+    /// # Arguments
+    /// * `command` - the command to query
+    #[cfg(feature = "unstable")]
+    pub fn get_command_values(&self, command: &str) -> Option<String> {
+        let c_command = CString::new(command).ok()?;
         unsafe {
-            (*self.lok_clz).setDocumentPassword.unwrap()(
-                self.lok,
-                c_url.as_ptr(),
-                std::ptr::null(),
-            );
-            let error = self.get_error();
-            if error != "" {
-                return Err(Error::new(error));
-            }
-            Ok(())
+            let values =
+                (*(*self.doc).pClass).getCommandValues.unwrap()(self.doc, c_command.as_ptr());
+            take_lok_string(values)
         }
     }
 
-    /// Loads a document from a URL with additional options.
+    /// Returns the values of a command deserialized into `T`, see [Document::get_command_values]
     ///
     /// # Arguments
-    /// * `url` - The URL to load.
-    /// * `options` - options for the import filter, e.g. SkipImages.
-    ///               Another useful FilterOption is "Language=...".  It is consumed
-    ///               by the documentLoad() itself, and when provided, LibreOfficeKit
-    ///               switches the language accordingly first.
+    /// * `command` - the command to query, e.g. `.uno:ViewRowColumnHeaders`
     ///
     /// # Example
     ///
     /// ```
     /// use libreoffice_rs::{Office, urls};
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Header {
+    ///     text: String,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Headers {
+    ///     columns: Vec<Header>,
+    /// }
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
-    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
-    /// office.document_load_with(doc_url, "en-US")?;
+    /// let doc_url = urls::local_into_abs("./test_data/table.ods")?;
+    /// let doc = office.document_load(doc_url)?;
     ///
+    /// let headers: Headers = doc.get_command_values_as(".uno:ViewRowColumnHeaders")?;
+    /// assert_eq!("A", headers.columns[0].text);
     /// # Ok(())
     /// # }
     /// ```
-    pub fn document_load_with(&mut self, url: DocUrl, options: &str) -> Result<Document, Error> {
-        let c_url = CString::new(url.to_string()).unwrap();
+    #[cfg(feature = "serde")]
+    pub fn get_command_values_as<T: serde::de::DeserializeOwned>(
+        &self,
+        command: &str,
+    ) -> Result<T, Error> {
+        let values = self
+            .get_command_values(command)
+            .ok_or_else(|| Error::new(format!("No values were returned for {command}")))?;
+        serde_json::from_str(&values).map_err(|ex| {
+            Error::new(format!(
+                "Failed to deserialize the values of {command}! {ex}"
+            ))
+        })
+    }
+
+    /// Queries the values of several commands, see [Document::get_command_values].
+    ///
+    /// Returns every command paired with its JSON values, in the order of `commands`.
+    ///
+    /// # Arguments
+    /// * `commands` - the commands to query
+    #[cfg(feature = "unstable")]
+    pub fn get_command_values_batch(&self, commands: &[&str]) -> Vec<(String, Option<String>)> {
+        commands
+            .iter()
+            .map(|command| (command.to_string(), self.get_command_values(command)))
+            .collect()
+    }
+
+    /// Returns the names of the fonts available to the document, sorted and without
+    /// duplicates, from the values of `.uno:CharFontName`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let doc = office.document_load(doc_url)?;
+    /// for font in doc.available_fonts()? {
+    ///     println!("{font}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn available_fonts(&self) -> Result<Vec<String>, Error> {
+        let values = self
+            .get_command_values(".uno:CharFontName")
+            .ok_or_else(|| Error::new("No fonts were returned for .uno:CharFontName".into()))?;
+        let json: serde_json::Value = serde_json::from_str(&values)
+            .map_err(|ex| Error::new(format!("Failed to parse the font list! {ex}")))?;
+
+        // the fonts are keys mapped to their sizes, e.g.
+        // `{"commandName": ".uno:CharFontName", "commandValues": {"Liberation Sans": ["8", ...]}}`,
+        // or a list of names
+        let mut fonts: Vec<String> = match &json["commandValues"] {
+            serde_json::Value::Object(fonts) => fonts.keys().cloned().collect(),
+            serde_json::Value::Array(fonts) => fonts
+                .iter()
+                .filter_map(|font| font.as_str().or_else(|| font["name"].as_str()))
+                .map(str::to_string)
+                .collect(),
+            _ => return Err(Error::new(format!("Unexpected font list {values}"))),
+        };
+        fonts.retain(|font| !font.is_empty());
+        fonts.sort();
+        fonts.dedup();
+        Ok(fonts)
+    }
+
+    /// Creates a new view of the document and makes it the current view,
+    /// returns the id of the new view
+    #[cfg(feature = "unstable")]
+    pub fn create_view(&mut self) -> i32 {
+        unsafe { (*(*self.doc).pClass).createView.unwrap()(self.doc) }
+    }
+
+    /// Creates a new view of the document with options and makes it the current view,
+    /// returns the id of the new view
+    ///
+    /// # Arguments
+    /// * `options` - e.g. `Language=de-DE`, or an empty string for the defaults
+    #[cfg(feature = "unstable")]
+    pub fn create_view_with_options(&mut self, options: &str) -> i32 {
         let c_options = CString::new(options).unwrap();
         unsafe {
-            let doc = (*self.lok_clz).documentLoadWithOptions.unwrap()(
-                self.lok,
-                c_url.as_ptr(),
-                c_options.as_ptr(),
-            );
-            let error = self.get_error();
-            if error != "" {
-                return Err(Error::new(error));
-            }
-            Ok(Document { doc })
+            (*(*self.doc).pClass).createViewWithOptions.unwrap()(self.doc, c_options.as_ptr())
         }
     }
 
-    /// Runs a macro stored at a specific path (within a document).
+    /// Creates a new view of the document for an author and makes it the current view,
+    /// returns the id of the new view.
+    ///
+    /// The author is used e.g. for the comments and tracked changes made in the view.
+    /// LibreOfficeKit doesn't report the author of a view, so it's remembered
+    /// for [Document::views].
     ///
     /// # Arguments
-    /// * `path` - The macro path (macro:///Standard.Module1.MyMacro).
-    pub fn run_macro(&mut self, path: &str) -> Result<(), Error> {
-        let path = CString::new(path).unwrap();
+    /// * `author` - the name of the author
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let view = doc.create_view_with_author("Alice");
+    /// let author = doc.views().into_iter().find(|v| v.id == view).and_then(|v| v.author);
+    /// assert_eq!(Some("Alice".to_string()), author);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "unstable")]
+    pub fn create_view_with_author(&mut self, author: &str) -> i32 {
+        let view = self.create_view_with_options("");
+        let args = UnoArgs::new().string(".uno:Author", author);
+        let c_args = CString::new(args.as_ref()).unwrap();
         unsafe {
-            let x = (*self.lok_clz).runMacro.unwrap()(self.lok, path.as_ptr());
-            if x == 0 {
-                let error = self.get_error();
-                if error != "" {
-                    return Err(Error::new(error));
-                }
-            }
-            Ok(())
+            (*(*self.doc).pClass).initializeForRendering.unwrap()(self.doc, c_args.as_ptr());
         }
+        self.view_authors.insert(view, author.to_owned());
+        view
     }
-}
 
-impl Drop for Office {
-    fn drop(&mut self) {
-        self.destroy()
+    /// Returns the views of the document, ordered by id, along with their authors
+    /// when they were created with [Document::create_view_with_author]
+    #[cfg(feature = "unstable")]
+    pub fn views(&self) -> Vec<ViewInfo> {
+        let count = unsafe { (*(*self.doc).pClass).getViewsCount.unwrap()(self.doc) };
+        let mut ids = vec![0; count.max(0) as usize];
+        let listed = unsafe {
+            (*(*self.doc).pClass).getViewIds.unwrap()(self.doc, ids.as_mut_ptr(), ids.len())
+        };
+        if !listed {
+            return Vec::new();
+        }
+        ids.sort_unstable();
+
+        ids.into_iter()
+            .map(|id| ViewInfo {
+                id,
+                author: self.view_authors.get(&id).cloned(),
+            })
+            .collect()
     }
-}
 
-impl Document {
-    /// Stores the document's persistent data to a URL and
-    /// continues to be a representation of the old URL.
+    /// Calls `callback` with the new `(width, height)` of the document in twips
+    /// whenever an edit changes its size, replacing any previous callback.
     ///
-    /// If the result is not true, then there's an error (possibly unsupported format or other errors)
+    /// The callback is fed by the callbacks the document captures for its current view,
+    /// see [Document::view_cursors], and runs on the thread LibreOfficeKit notifies from,
+    /// which needn't be the one that registered it. It may call back into the document,
+    /// but a size change it causes itself isn't reported to it again:
+    /// [Document::get_document_size] returns the current size.
     ///
     /// # Arguments
-    /// * `url` - the location where to store the document
-    /// * `format` - the format to use while exporting, when omitted, then deducted from pURL's extension
-    /// * `filter` -  options for the export filter, e.g. SkipImages.Another useful FilterOption is "TakeOwnership".  It is consumed
-    ///               by the saveAs() itself, and when provided, the document identity
-    ///               changes to the provided pUrl - meaning that '.uno:ModifiedStatus'
-    ///               is triggered as with the "Save As..." in the UI.
-    ///              "TakeOwnership" mode must not be used when saving to PNG or PDF.
+    /// * `callback` - called with the new width and height of the document
+    #[cfg(feature = "unstable")]
+    pub fn on_size_changed(&mut self, callback: impl FnMut(i64, i64) + Send + 'static) {
+        self.callbacks.set_size_listener(Box::new(callback));
+        self.register_callback_buffer();
+    }
+
+    /// Calls `callback` with the rectangles (in twips) of the document that need to be
+    /// repainted, at most once per `window` rather than for every `INVALIDATE_TILES`
+    /// callback, replacing any previous callback.
+    ///
+    /// The invalidations of a window are merged by an [InvalidationCoalescer], and delivered
+    /// once the window is over, by a timer running on a thread of its own, or by the first
+    /// invalidation arriving after it. Call [Document::flush_invalidations] to get the pending
+    /// ones before, e.g. after an edit. The callback may call back into the document.
+    ///
+    /// LibreOfficeKit reports invalidations to the document callbacks rather than to the
+    /// [Office] callback, so the callback is fed by the callbacks the document captures
+    /// for its current view, see [Document::view_cursors].
+    ///
+    /// # Arguments
+    /// * `window` - the minimum time between two calls of `callback`
+    /// * `callback` - called with the merged rectangles to repaint
     ///
     /// # Example
     ///
     /// ```
-    /// use libreoffice_rs::Office;
-    /// use libreoffice_rs::urls;
+    /// use libreoffice_rs::{Office, urls};
+    /// use std::time::Duration;
     ///
-    /// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
     /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
     /// let mut doc = office.document_load(doc_url)?;
-    /// let output_path = std::env::temp_dir().join("libreoffice_rs_save_as.png");
-    /// let output_location = output_path.display().to_string();
-    /// let previously_saved = doc.save_as(&output_location, "png", None);
-    /// let _ = std::fs::remove_file(&output_path);
-    ///
-    /// assert!(previously_saved, "{}", office.get_error());
+    /// doc.initialize_for_rendering("");
+    /// doc.register_coalescing_callback(Duration::from_millis(40), |rects| {
+    ///     println!("repaint {rects:?}");
+    /// });
     ///
-    /// #  Ok(())
+    /// # Ok(())
     /// # }
     /// ```
-    pub fn save_as(&mut self, url: &str, format: &str, filter: Option<&str>) -> bool {
-        let c_url = CString::new(url).unwrap();
-        let c_format: CString = CString::new(format).unwrap();
-        let c_filter: CString = CString::new(filter.unwrap_or_default()).unwrap();
-        let ret = unsafe {
-            (*(*self.doc).pClass).saveAs.unwrap()(
-                self.doc,
-                c_url.as_ptr(),
-                c_format.as_ptr(),
-                c_filter.as_ptr(),
-            )
-        };
+    #[cfg(feature = "unstable")]
+    pub fn register_coalescing_callback(
+        &mut self,
+        window: std::time::Duration,
+        callback: impl FnMut(&[Rect]) + Send + 'static,
+    ) {
+        self.callbacks
+            .set_invalidation_listener(InvalidationCoalescer::new(window), Box::new(callback));
+        self.register_callback_buffer();
+    }
 
-        ret != 0
+    /// Hands the pending invalidations to the callback of
+    /// [Document::register_coalescing_callback] right away
+    #[cfg(feature = "unstable")]
+    pub fn flush_invalidations(&self) {
+        self.callbacks.flush_invalidations();
+    }
+
+    /// Returns the latest cursor position (in twips) of every other view of the document,
+    /// as `(view id, cursor rectangle)` ordered by view id.
+    ///
+    /// The cursors come from the `INVALIDATE_VIEW_CURSOR` callbacks of the view that was
+    /// current when the document started capturing callbacks, i.e. at the first call of this
+    /// method or of [Document::initialize_for_rendering]. Only the latest cursor of every
+    /// view is kept, and views whose cursor was hidden (an `EMPTY` rectangle) are left out.
+    /// The view capturing the callbacks reports its own cursor differently, so it is never listed.
+    #[cfg(feature = "unstable")]
+    pub fn view_cursors(&self) -> Vec<(i32, Rect)> {
+        self.register_callback_buffer();
+        self.callbacks.view_cursors()
+    }
+
+    /// Registers the callback filling the document's [CallbackBuffer], once
+    #[cfg(feature = "unstable")]
+    fn register_callback_buffer(&self) {
+        if self.callbacks_registered.replace(true) {
+            return;
+        }
+        let data: *const CallbackBuffer = &*self.callbacks;
+        unsafe {
+            (*(*self.doc).pClass).registerCallback.unwrap()(
+                self.doc,
+                Some(callback::document_callback_shim),
+                data as *mut std::os::raw::c_void,
+            );
+        }
     }
 
     fn destroy(&mut self) {
+        if self.doc.is_null() {
+            return;
+        }
         unsafe {
             (*(*self.doc).pClass).destroy.unwrap()(self.doc);
         }
+        self.doc = std::ptr::null_mut();
     }
 }
 
 impl Drop for Document {
     fn drop(&mut self) {
-        self.destroy()
+        self.destroy();
+        if let Some(path) = self.temp_file.take() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_null_office() {
+        let office = unsafe { Office::null_for_tests() };
+        let clone = office.clone();
+        drop(office);
+        drop(clone);
+    }
+
+    #[test]
+    fn test_drop_null_document() {
+        let doc = unsafe { Document::null_for_tests() };
+        drop(doc);
+    }
+
+    #[test]
+    #[cfg(feature = "unstable")]
+    fn test_nth_page_rectangle() {
+        let rectangles = "284, 284, 11906, 16838; 284, 17406, 11906, 16838";
+        assert_eq!(
+            Some(Rect::new(284, 284, 11906, 16838)),
+            nth_page_rectangle(rectangles, 0)
+        );
+        assert_eq!(
+            Some(Rect::new(284, 17406, 11906, 16838)),
+            nth_page_rectangle(rectangles, 1)
+        );
+        assert_eq!(None, nth_page_rectangle(rectangles, 2));
+        assert_eq!(None, nth_page_rectangle("", 0));
+        assert_eq!(None, nth_page_rectangle("284, 284, 11906", 0));
+    }
+
+    #[test]
+    fn test_opt_dir_version() {
+        assert_eq!(Some(vec![7, 5]), opt_dir_version("libreoffice7.5"));
+        assert_eq!(Some(vec![24, 2]), opt_dir_version("libreoffice24.2"));
+        assert_eq!(Some(vec![]), opt_dir_version("libreoffice"));
+        assert_eq!(None, opt_dir_version("openoffice4"));
+
+        let mut versions = vec![
+            opt_dir_version("libreoffice7.5"),
+            opt_dir_version("libreoffice24.2"),
+            opt_dir_version("libreoffice7.10"),
+        ];
+        versions.sort_by(|a, b| b.cmp(a));
+        assert_eq!(
+            vec![Some(vec![24, 2]), Some(vec![7, 10]), Some(vec![7, 5])],
+            versions
+        );
     }
 }
@@ -0,0 +1,55 @@
+use crate::error::Error;
+
+/// The `Key=value` options accepted when loading a document: the ones LibreOfficeKit
+/// consumes itself, and the ones it passes on to the import filter
+const LOAD_OPTIONS: [&str; 11] = [
+    "Language",
+    "Batch",
+    "DeviceFormFactor",
+    "Timezone",
+    "Theme",
+    "MacroSecurityLevel",
+    "EnableMacrosExecution",
+    "Password",
+    "FilterOptions",
+    "ReadOnly",
+    "Hidden",
+];
+
+/// Check the keys of the `Key=value` load options, see [crate::Office::document_load_with]
+pub(crate) fn validate_load_options(options: &str) -> Result<(), Error> {
+    for option in options.split(',') {
+        if let Some((key, _)) = option.split_once('=') {
+            let key = key.trim();
+            if !LOAD_OPTIONS.contains(&key) {
+                return Err(Error::new(format!(
+                    "Unknown load option {key}, expected one of {}",
+                    LOAD_OPTIONS.join(", ")
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_load_options;
+
+    #[test]
+    fn test_valid_options() {
+        validate_load_options("Language=en-US,Batch=true").unwrap();
+        validate_load_options("Password=secret, ReadOnly=true,Hidden=true").unwrap();
+        validate_load_options("FilterOptions=44,34,76").unwrap();
+        validate_load_options("SkipImages").unwrap();
+        validate_load_options("").unwrap();
+    }
+
+    #[test]
+    fn test_misspelled_option() {
+        let err = validate_load_options("Language=en-US,Langauge=en-US")
+            .err()
+            .expect("a misspelled option was accepted");
+        assert!(err.to_string().contains("Langauge"), "{err}");
+    }
+}
@@ -0,0 +1,98 @@
+use crate::error::Error;
+use crate::Office;
+
+/// The memory usage of the process running LibreOfficeKit, see [Office::memory_usage]
+///
+/// There is no figure for the caches of LibreOffice (e.g. of images and fonts):
+/// LibreOfficeKit doesn't report their size, except in the human-readable report of
+/// `dumpState`, and they are part of the resident set size like the rest of its heap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryStats {
+    /// The resident set size, the memory actually in use, in kB
+    pub rss_kb: u64,
+    /// The size of the virtual address space, in kB
+    pub virtual_kb: u64,
+}
+
+impl MemoryStats {
+    /// Parse the `VmRSS` and `VmSize` lines of `/proc/<pid>/status`
+    ///
+    /// # Arguments
+    /// * `status` - e.g. `VmSize:  812344 kB\nVmRSS:  190212 kB`
+    pub(crate) fn from_proc_status(status: &str) -> Result<MemoryStats, Error> {
+        let field = |name: &str| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+                .and_then(|value| value.trim().trim_end_matches("kB").trim().parse().ok())
+                .ok_or_else(|| Error::new(format!("No {name} in the process status")))
+        };
+
+        Ok(MemoryStats {
+            rss_kb: field("VmRSS")?,
+            virtual_kb: field("VmSize")?,
+        })
+    }
+}
+
+impl Office {
+    /// Returns the memory usage of the process.
+    ///
+    /// LibreOfficeKit runs inside the process and doesn't report its own memory
+    /// footprint (`dumpState` only produces a human-readable report), so these are
+    /// the figures of the whole process, read from `/proc/self/status`.
+    /// This is only available on Linux, other systems get an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Office;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let memory = office.memory_usage()?;
+    /// println!("{} kB in use", memory.rss_kb);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn memory_usage(&self) -> Result<MemoryStats, Error> {
+        if !cfg!(target_os = "linux") {
+            return Err(Error::new(
+                "The memory usage is only available on Linux".to_string(),
+            ));
+        }
+
+        let status = std::fs::read_to_string("/proc/self/status")
+            .map_err(|ex| Error::new(format!("Failed to read /proc/self/status! {ex}")))?;
+        MemoryStats::from_proc_status(&status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryStats;
+
+    #[test]
+    fn test_from_proc_status() {
+        let status =
+            "Name:\tcargo\nVmPeak:\t  900000 kB\nVmSize:\t  812344 kB\nVmRSS:\t  190212 kB\n";
+        assert_eq!(
+            MemoryStats {
+                rss_kb: 190212,
+                virtual_kb: 812344
+            },
+            MemoryStats::from_proc_status(status).unwrap()
+        );
+        assert!(MemoryStats::from_proc_status("Name:\tcargo\n").is_err());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_proc_self_status() {
+        let status = std::fs::read_to_string("/proc/self/status").unwrap();
+        let memory = MemoryStats::from_proc_status(&status).unwrap();
+        assert!(memory.rss_kb > 0);
+        assert!(memory.virtual_kb >= memory.rss_kb);
+    }
+}
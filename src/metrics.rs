@@ -0,0 +1,57 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The callback of [crate::Office::set_metrics_callback]
+type MetricsCallback = Box<dyn FnMut(&str, Duration) + Send>;
+
+/// The metrics callback, if any, shared by an [crate::Office], its clones and its documents
+#[derive(Clone, Default)]
+pub(crate) struct Metrics(Arc<Mutex<Option<MetricsCallback>>>);
+
+impl Metrics {
+    pub(crate) fn set(&self, callback: MetricsCallback) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Some(callback);
+    }
+
+    /// Runs an operation and reports how long it took to the callback, and
+    /// to a `libreoffice` span with the `tracing` feature
+    ///
+    /// # Arguments
+    /// * `operation` - the name of the operation, e.g. `save_as`
+    /// * `url` - the URL the operation is about, e.g. of the loaded document or the macro
+    /// * `f` - the operation
+    pub(crate) fn time<R>(
+        &self,
+        operation: &str,
+        url: &dyn fmt::Display,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "libreoffice",
+            operation,
+            url = %url,
+            elapsed_ms = tracing::field::Empty
+        )
+        .entered();
+        #[cfg(not(feature = "tracing"))]
+        let _ = url;
+
+        let started = Instant::now();
+        let result = f();
+        let elapsed = started.elapsed();
+
+        #[cfg(feature = "tracing")]
+        {
+            let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+            span.record("elapsed_ms", elapsed_ms);
+            tracing::debug!(elapsed_ms, "{operation} finished");
+        }
+
+        if let Some(callback) = self.0.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+            callback(operation, elapsed);
+        }
+        result
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+
+use crate::error::Error;
+use crate::xml::{attribute, unescape};
+use crate::{Document, DocumentType};
+
+/// An entry of the outline of a document, see [Document::outline]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    /// The level of the entry, from 1 for the top level
+    pub level: u32,
+    pub title: String,
+    /// The page (from 1) the entry is on, the slide or sheet number
+    /// for presentations, drawings and spreadsheets
+    pub page: u32,
+}
+
+impl Document {
+    /// Returns the outline of the document, e.g. to build the bookmarks of a PDF, in order:
+    /// * text documents - their headings, with their outline level
+    /// * presentations and drawings - their slides or pages, with their name
+    /// * spreadsheets - their sheets, with their name
+    ///
+    /// Writer only lists headings in its Navigator, which isn't remoted to LibreOfficeKit,
+    /// and as the `OutlineLevel` of paragraphs in the UNO API. The headings of text
+    /// documents are the `text:h` elements of a flat ODT export. The export has no page
+    /// numbers: the page of a heading is estimated by counting the `text:soft-page-break`
+    /// elements LibreOffice writes from its current layout, and the page breaks of the
+    /// styles of paragraphs and tables, so it can be off when the layout isn't finished.
+    /// The other documents use their part names, see [Document::part_names].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/headings.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// for entry in doc.outline()? {
+    ///     let indent = "  ".repeat(entry.level as usize - 1);
+    ///     println!("{indent}{} ... {}", entry.title, entry.page);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn outline(&mut self) -> Result<Vec<OutlineEntry>, Error> {
+        let ty = self.get_document_type();
+        match ty {
+            DocumentType::Text => Ok(parse_headings(&self.flat_odf_export()?)),
+            DocumentType::Spreadsheet | DocumentType::Presentation | DocumentType::Drawing => {
+                Ok(self
+                    .part_names()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(part, title)| OutlineEntry {
+                        level: 1,
+                        title,
+                        page: part as u32 + 1,
+                    })
+                    .collect())
+            }
+            _ => Err(Error::new(format!("{ty:?} documents have no outline"))),
+        }
+    }
+}
+
+/// The page breaks of a style, see [page_break_styles]
+#[derive(Default)]
+struct PageBreaks {
+    parent: Option<String>,
+    before: Option<bool>,
+    after: Option<bool>,
+}
+
+/// Returns the headings of flat ODT content
+fn parse_headings(content: &str) -> Vec<OutlineEntry> {
+    let styles = page_break_styles(content);
+    let breaks = |start_tag: &str| {
+        let style = attribute(start_tag, "text:style-name")
+            .or_else(|| attribute(start_tag, "table:style-name"));
+        let (mut before, mut after) = (None, None);
+        let mut name = style;
+        // at most one step per style, in case of a loop
+        for _ in 0..=styles.len() {
+            let Some(style) = name.as_ref().and_then(|name| styles.get(name)) else {
+                break;
+            };
+            before = before.or(style.before);
+            after = after.or(style.after);
+            name = style.parent.clone();
+        }
+        (before.unwrap_or(false), after.unwrap_or(false))
+    };
+
+    let Some((_, body)) = content.split_once("<office:text") else {
+        return Vec::new();
+    };
+    let mut entries = Vec::new();
+    let mut page = 1;
+    let mut started = false;
+    let mut break_after = false;
+    let mut rest = body;
+    while let Some(tag_start) = rest.find('<') {
+        rest = &rest[tag_start + 1..];
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let start_tag = &rest[..tag_end];
+
+        if start_tag.starts_with("text:soft-page-break") {
+            page += 1;
+        } else if ["text:p", "text:h", "table:table"]
+            .iter()
+            .any(|name| is_element(start_tag, name))
+        {
+            let (before, after) = breaks(start_tag);
+            // the first paragraph starts the first page anyway
+            if started && (before || break_after) {
+                page += 1;
+            }
+            started = true;
+            break_after = after;
+
+            if is_element(start_tag, "text:h") && !start_tag.ends_with('/') {
+                let end = rest.find("</text:h>").unwrap_or(rest.len());
+                let level = attribute(start_tag, "text:outline-level")
+                    .and_then(|level| level.parse().ok())
+                    .unwrap_or(1);
+                entries.push(OutlineEntry {
+                    level,
+                    title: element_text(&rest[tag_end + 1..end]),
+                    page,
+                });
+                rest = &rest[end..];
+            }
+        }
+    }
+
+    entries
+}
+
+/// Returns the styles of flat ODF content with their page breaks: `fo:break-before`,
+/// `fo:break-after` and `style:master-page-name`, which starts a new page
+fn page_break_styles(content: &str) -> HashMap<String, PageBreaks> {
+    let mut styles = HashMap::new();
+
+    for style in content.split("<style:style ").skip(1) {
+        let Some(tag_end) = style.find('>') else {
+            continue;
+        };
+        let start_tag = &style[..tag_end];
+        let Some(name) = attribute(start_tag, "style:name") else {
+            continue;
+        };
+        let body = if start_tag.ends_with('/') {
+            start_tag
+        } else {
+            &style[..style.find("</style:style>").unwrap_or(style.len())]
+        };
+        let is_page = |value: String| value == "page";
+
+        let master_page = attribute(start_tag, "style:master-page-name")
+            .filter(|master_page| !master_page.is_empty())
+            .map(|_| true);
+        styles.insert(
+            name,
+            PageBreaks {
+                parent: attribute(start_tag, "style:parent-style-name"),
+                before: attribute(body, "fo:break-before")
+                    .map(is_page)
+                    .or(master_page),
+                after: attribute(body, "fo:break-after").map(is_page),
+            },
+        );
+    }
+
+    styles
+}
+
+/// Whether a start tag is of an element, e.g. `text:h` for `text:h text:outline-level="1"`
+fn is_element(start_tag: &str, name: &str) -> bool {
+    start_tag.strip_prefix(name).map_or(false, |rest| {
+        rest.is_empty() || rest.starts_with([' ', '/'])
+    })
+}
+
+/// Returns the text of the content of an element, without its notes
+fn element_text(content: &str) -> String {
+    let mut text = String::new();
+    let mut rest = content;
+    while let Some(tag_start) = rest.find('<') {
+        text.push_str(&unescape(&rest[..tag_start]));
+        rest = &rest[tag_start + 1..];
+        let tag_end = rest.find('>').unwrap_or(rest.len());
+        let tag = &rest[..tag_end];
+
+        if is_element(tag, "text:s") {
+            let count = attribute(tag, "text:c")
+                .and_then(|count| count.parse().ok())
+                .unwrap_or(1);
+            text.push_str(&" ".repeat(count));
+        } else if is_element(tag, "text:tab") || is_element(tag, "text:line-break") {
+            text.push(' ');
+        } else if is_element(tag, "text:note") && !tag.ends_with('/') {
+            let end = rest.find("</text:note>").unwrap_or(rest.len());
+            rest = &rest[end..];
+            continue;
+        }
+        rest = &rest[(tag_end + 1).min(rest.len())..];
+    }
+    text.push_str(&unescape(rest));
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{element_text, page_break_styles, parse_headings, OutlineEntry};
+
+    const STYLES: &str = r#"<office:automatic-styles>
+        <style:style style:name="P1" style:family="paragraph" style:parent-style-name="P2"/>
+        <style:style style:name="P2" style:family="paragraph" style:parent-style-name="P1"/>
+        <style:style style:name="P3" style:family="paragraph">
+          <style:paragraph-properties fo:break-before="page"/>
+        </style:style>
+        <style:style style:name="P4" style:family="paragraph" style:parent-style-name="P3">
+          <style:paragraph-properties fo:break-before="auto" fo:break-after="page"/>
+        </style:style>
+        <style:style style:name="P5" style:family="paragraph" style:master-page-name="Standard"/>
+        <style:style style:name="P6" style:family="paragraph" style:master-page-name=""/>
+        </office:automatic-styles>"#;
+
+    #[test]
+    fn test_page_break_styles() {
+        let styles = page_break_styles(STYLES);
+        assert_eq!(6, styles.len());
+        assert_eq!(Some("P2"), styles["P1"].parent.as_deref());
+        assert_eq!((None, None), (styles["P1"].before, styles["P1"].after));
+        assert_eq!(
+            (Some(true), None),
+            (styles["P3"].before, styles["P3"].after)
+        );
+        assert_eq!(
+            (Some(false), Some(true)),
+            (styles["P4"].before, styles["P4"].after)
+        );
+        assert_eq!(Some(true), styles["P5"].before);
+        assert_eq!(None, styles["P6"].before);
+    }
+
+    #[test]
+    fn test_parse_headings() {
+        let content = format!(
+            r#"{STYLES}<office:body><office:text>
+            <text:h text:style-name="P1" text:outline-level="1">Tom &amp; Jerry</text:h>
+            <text:p>Text</text:p><text:soft-page-break/>
+            <text:h text:outline-level="2">Two<text:s text:c="3"/>spaces<text:note><text:note-body><text:p>Note</text:p></text:note-body></text:note></text:h>
+            <text:p text:style-name="P3"/>
+            <text:h text:style-name="P4">Third</text:h>
+            <text:h text:style-name="P5" text:outline-level="3"/>
+            </office:text></office:body>"#
+        );
+        let entry = |level, title: &str, page| OutlineEntry {
+            level,
+            title: title.to_string(),
+            page,
+        };
+
+        assert_eq!(
+            vec![
+                entry(1, "Tom & Jerry", 1),
+                entry(2, "Two   spaces", 2),
+                entry(1, "Third", 3),
+            ],
+            parse_headings(&content)
+        );
+    }
+
+    #[test]
+    fn test_element_text() {
+        assert_eq!(
+            "A<B C D  E",
+            element_text("A&lt;B<text:tab/>C<text:line-break/>D<text:s text:c=\"2\"/>E")
+        );
+        assert_eq!(
+            "Title",
+            element_text("Ti<text:span text:style-name=\"T1\">tle</text:span>")
+        );
+        assert_eq!("", element_text(""));
+    }
+}
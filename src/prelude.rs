@@ -0,0 +1,8 @@
+pub use crate::urls::{self, DocUrl};
+#[cfg(feature = "unstable")]
+pub use crate::StyleFamily;
+pub use crate::{
+    CallbackType, CancellationToken, Document, DocumentType, EditMode, Error, FilterOptions,
+    LanguageTag, LibreOfficeKitOptionalFeatures, MacroSecurity, MouseEventType, Office,
+    OfficeBuilder, OutputFormat, Rect, SelectionType, TileMode, UnoArgs,
+};
@@ -0,0 +1,182 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// A rectangle in document coordinates (twips), as reported by LibreOfficeKit
+/// callbacks and commands
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Rect {
+    /// Create a rectangle from its top left corner and its size
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Whether the rectangle has no area
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    /// Whether the rectangle entirely covers another, non-empty, rectangle
+    ///
+    /// # Arguments
+    /// * `other` - the rectangle to check
+    pub fn contains(&self, other: &Rect) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.x <= other.x
+            && self.y <= other.y
+            && self.right() >= other.right()
+            && self.bottom() >= other.bottom()
+    }
+
+    /// The smallest rectangle covering both rectangles, ignoring empty ones
+    ///
+    /// # Arguments
+    /// * `other` - the rectangle to merge with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Rect;
+    ///
+    /// let a = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(Rect::new(0, 0, 30, 15), a.union(&Rect::new(20, 5, 10, 10)));
+    /// assert_eq!(a, a.union(&Rect::default()));
+    /// ```
+    pub fn union(&self, other: &Rect) -> Rect {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *other;
+        }
+
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        Rect::new(
+            x,
+            y,
+            clamp(self.right().max(other.right()) - x as i64),
+            clamp(self.bottom().max(other.bottom()) - y as i64),
+        )
+    }
+
+    /// The area covered by both rectangles, `None` when they don't overlap,
+    /// including when they only share an edge
+    ///
+    /// # Arguments
+    /// * `other` - the rectangle to intersect with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Rect;
+    ///
+    /// let a = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(Some(Rect::new(5, 5, 5, 5)), a.intersection(&Rect::new(5, 5, 10, 10)));
+    /// assert_eq!(None, a.intersection(&Rect::new(10, 0, 10, 10)));
+    /// ```
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        if right <= x as i64 || bottom <= y as i64 {
+            return None;
+        }
+        Some(Rect::new(
+            x,
+            y,
+            clamp(right - x as i64),
+            clamp(bottom - y as i64),
+        ))
+    }
+
+    /// The x coordinate of the right edge, which doesn't overflow
+    fn right(&self) -> i64 {
+        self.x as i64 + self.width as i64
+    }
+
+    /// The y coordinate of the bottom edge, which doesn't overflow
+    fn bottom(&self) -> i64 {
+        self.y as i64 + self.height as i64
+    }
+
+    /// Parse a `;` separated list of rectangles, the format LibreOfficeKit uses
+    /// for selections and invalidations. `EMPTY` and blank payloads yield no rectangles.
+    ///
+    /// # Arguments
+    /// * `payload` - e.g. `1418, 1418, 3968, 276; 1418, 1694, 1200, 276`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::Rect;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rects = Rect::parse_list("0, 0, 100, 50; 10, 60, 30, 20")?;
+    ///
+    /// assert_eq!(vec![Rect::new(0, 0, 100, 50), Rect::new(10, 60, 30, 20)], rects);
+    /// assert!(Rect::parse_list("EMPTY")?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_list(payload: &str) -> Result<Vec<Rect>, Error> {
+        let payload = payload.trim();
+        if payload.is_empty() || payload == "EMPTY" {
+            return Ok(Vec::new());
+        }
+
+        payload
+            .split(';')
+            .filter(|rect| !rect.trim().is_empty())
+            .map(str::parse)
+            .collect()
+    }
+}
+
+/// Clamps a length to the `i32` range of rectangles
+fn clamp(length: i64) -> i32 {
+    length.clamp(0, i32::MAX as i64) as i32
+}
+
+impl FromStr for Rect {
+    type Err = Error;
+
+    /// Parse a rectangle from the `x, y, width, height` form
+    fn from_str(s: &str) -> Result<Rect, Error> {
+        let values = s
+            .split(',')
+            .map(|value| value.trim().parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|ex| Error::new(format!("Failed to parse rectangle {s}! {ex}")))?;
+
+        match values[..] {
+            [x, y, width, height] => Ok(Rect::new(x, y, width, height)),
+            _ => Err(Error::new(format!(
+                "Failed to parse rectangle {s}! Expected 4 values, got {}",
+                values.len()
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {}, {}", self.x, self.y, self.width, self.height)
+    }
+}
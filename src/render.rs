@@ -0,0 +1,425 @@
+use std::convert::TryFrom;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Mutex};
+
+use image::{imageops, DynamicImage, ImageFormat, Rgba, RgbaImage};
+
+use crate::error::Error;
+use crate::units::{twips_to_px, TWIPS_PER_INCH};
+use crate::{Document, DocumentType, OutputFormat, Rect, TileMode};
+
+/// A tile of the grid rendered by [Document::render_grid_parallel]
+#[derive(Debug, Clone)]
+pub struct RenderedTile {
+    /// The column of the tile in the grid, from 0 at the left
+    pub column: u32,
+    /// The row of the tile in the grid, from 0 at the top
+    pub row: u32,
+    /// The rendered area of the current part, in twips
+    pub area: Rect,
+    /// The pixels of the tile, with straight alpha
+    pub image: RgbaImage,
+}
+
+impl Document {
+    /// Renders every page of the document, at the given resolution.
+    ///
+    /// The pages of text documents are rendered from their page rectangles,
+    /// other documents render every part (sheet, slide or page) entirely.
+    ///
+    /// # Arguments
+    /// * `dpi` - the resolution of the images, in pixels per inch,
+    ///           [DocumentType::default_render_dpi] when `None`
+    pub fn render_all_pages(&mut self, dpi: Option<u32>) -> Result<Vec<RgbaImage>, Error> {
+        if !self.rendering_initialized {
+            self.initialize_for_rendering("");
+        }
+        let dpi = self.render_dpi(dpi);
+
+        if self.get_document_type() == DocumentType::Text {
+            let pages = self.get_part_page_rectangles()?;
+            return pages
+                .into_iter()
+                .map(|page| self.render_area(page, dpi))
+                .collect();
+        }
+
+        let current_part = self.get_part();
+        let mut images = Vec::new();
+        for part in 0..self.get_parts() {
+            self.set_part(part)?;
+            let (width, height) = self.get_document_size();
+            match self.render_area(Rect::new(0, 0, width as i32, height as i32), dpi) {
+                Ok(image) => images.push(image),
+                Err(err) => {
+                    self.set_part(current_part)?;
+                    return Err(err);
+                }
+            }
+        }
+        self.set_part(current_part)?;
+
+        Ok(images)
+    }
+
+    /// Renders every page of the document into its own image file,
+    /// see [Document::render_all_pages].
+    ///
+    /// The files are named `page-1.png`, `page-2.png`, ... after the page number
+    /// and the format, existing files are overwritten. Returns the paths of the files.
+    ///
+    /// # Arguments
+    /// * `dir` - the directory to write the images to, created if missing
+    /// * `format` - the image format, PNG, JPEG, GIF or BMP
+    /// * `dpi` - the resolution of the images, in pixels per inch,
+    ///           [DocumentType::default_render_dpi] when `None`
+    pub fn export_pages_to_images(
+        &mut self,
+        dir: &Path,
+        format: OutputFormat,
+        dpi: Option<u32>,
+    ) -> Result<Vec<PathBuf>, Error> {
+        let image_format = match format {
+            OutputFormat::Png => ImageFormat::Png,
+            OutputFormat::Jpeg => ImageFormat::Jpeg,
+            OutputFormat::Gif => ImageFormat::Gif,
+            OutputFormat::Bmp => ImageFormat::Bmp,
+            _ => {
+                return Err(Error::new(format!(
+                    "Pages can't be exported to {format} images"
+                )))
+            }
+        };
+
+        std::fs::create_dir_all(dir).map_err(|ex| {
+            Error::new(format!(
+                "Failed to create directory {}! {ex}",
+                dir.display()
+            ))
+        })?;
+
+        let pages = self.render_all_pages(dpi)?;
+        let mut paths = Vec::with_capacity(pages.len());
+        for (i, page) in pages.into_iter().enumerate() {
+            let path = dir.join(format!("page-{}.{}", i + 1, format.extension()));
+            // JPEG has no alpha channel
+            let saved = if format == OutputFormat::Jpeg {
+                DynamicImage::ImageRgba8(page)
+                    .to_rgb8()
+                    .save_with_format(&path, image_format)
+            } else {
+                page.save_with_format(&path, image_format)
+            };
+            saved.map_err(|ex| Error::new(format!("Failed to write {}! {ex}", path.display())))?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
+    /// Renders a single page into a PNG file, overwriting any existing file.
+    ///
+    /// The pages of text documents are their pages, other documents render
+    /// a part (sheet, slide or page) entirely, see [Document::render_all_pages].
+    /// Fails with [Error::PartOutOfRange] for a page the document doesn't have.
+    ///
+    /// # Arguments
+    /// * `part` - the index of the page, from 0
+    /// * `path` - the PNG file to write
+    /// * `dpi` - the resolution of the image, in pixels per inch,
+    ///           [DocumentType::default_render_dpi] when `None`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.render_page_to_png(0, std::path::Path::new("/tmp/preview.png"), None)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_page_to_png(
+        &mut self,
+        part: i32,
+        path: &Path,
+        dpi: Option<u32>,
+    ) -> Result<(), Error> {
+        if !self.rendering_initialized {
+            self.initialize_for_rendering("");
+        }
+        let dpi = self.render_dpi(dpi);
+
+        let page = if self.get_document_type() == DocumentType::Text {
+            let pages = self.get_part_page_rectangles()?;
+            let page = usize::try_from(part).ok().and_then(|i| pages.get(i));
+            match page {
+                Some(page) => self.render_area(*page, dpi)?,
+                None => {
+                    return Err(Error::PartOutOfRange {
+                        index: part,
+                        count: pages.len() as i32,
+                    })
+                }
+            }
+        } else {
+            let current_part = self.get_part();
+            self.set_part(part)?;
+            let (width, height) = self.get_document_size();
+            let page = self.render_area(Rect::new(0, 0, width as i32, height as i32), dpi);
+            self.set_part(current_part)?;
+            page?
+        };
+
+        page.save_with_format(path, ImageFormat::Png)
+            .map_err(|ex| Error::new(format!("Failed to write {}! {ex}", path.display())))
+    }
+
+    /// Renders a contact sheet: a single image with a thumbnail of every page,
+    /// laid out in a grid from left to right and top to bottom, e.g. for an overview of slides.
+    ///
+    /// The pages are rendered like [Document::render_all_pages], at
+    /// [DocumentType::default_render_dpi], and scaled down to fit square cells of
+    /// `thumb_px` pixels, centered, on a white background. The image is `columns` cells
+    /// wide and has as many rows as needed.
+    ///
+    /// # Arguments
+    /// * `columns` - the number of thumbnails per row
+    /// * `thumb_px` - the side of the cells, in pixels
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/three_slides.odp")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let sheet = doc.export_contact_sheet(2, 200)?;
+    /// assert_eq!((400, 400), sheet.dimensions());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export_contact_sheet(
+        &mut self,
+        columns: u32,
+        thumb_px: u32,
+    ) -> Result<RgbaImage, Error> {
+        if columns == 0 || thumb_px == 0 {
+            return Err(Error::new(format!(
+                "A contact sheet needs at least a column and a thumbnail pixel, not {columns} columns of {thumb_px} pixels"
+            )));
+        }
+
+        let pages = self.render_all_pages(None)?;
+        if pages.is_empty() {
+            return Err(Error::new("The document has no pages to render".into()));
+        }
+
+        let rows = (pages.len() as u32).div_ceil(columns);
+        let (Some(width), Some(height)) =
+            (columns.checked_mul(thumb_px), rows.checked_mul(thumb_px))
+        else {
+            return Err(Error::new(format!(
+                "A contact sheet of {columns} columns and {rows} rows of {thumb_px} pixels is too large"
+            )));
+        };
+        let mut sheet = RgbaImage::from_pixel(width, height, Rgba([255, 255, 255, 255]));
+        for (i, page) in pages.into_iter().enumerate() {
+            let thumbnail = DynamicImage::ImageRgba8(page)
+                .thumbnail(thumb_px, thumb_px)
+                .to_rgba8();
+            let x = (i as u32 % columns) * thumb_px + (thumb_px - thumbnail.width()) / 2;
+            let y = (i as u32 / columns) * thumb_px + (thumb_px - thumbnail.height()) / 2;
+            imageops::overlay(&mut sheet, &thumbnail, x as i64, y as i64);
+        }
+
+        Ok(sheet)
+    }
+
+    /// Renders the current part as a grid of square tiles, converting the painted
+    /// tiles on worker threads.
+    ///
+    /// LibreOfficeKit serializes its calls with a global lock, including the calls of
+    /// other views or documents, so tiles can't be painted concurrently: they are painted
+    /// one after the other on the calling thread, while `threads` worker threads convert
+    /// the tiles painted so far from premultiplied pixels into [RgbaImage]s.
+    /// The tiles on the right and bottom edges are cropped to the part. Returns the tiles
+    /// row by row, from left to right, like the serial rendering of their areas.
+    ///
+    /// # Arguments
+    /// * `tile_px` - the side of the tiles, in pixels,
+    ///               [DocumentType::default_tile_size] when `None`
+    /// * `dpi` - the resolution of the tiles, in pixels per inch,
+    ///           [DocumentType::default_render_dpi] when `None`
+    /// * `threads` - the number of worker threads converting the tiles
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// let tiles = doc.render_grid_parallel(Some(256), None, 4)?;
+    /// assert!(tiles.iter().all(|tile| tile.image.width() <= 256));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn render_grid_parallel(
+        &mut self,
+        tile_px: Option<u32>,
+        dpi: Option<u32>,
+        threads: usize,
+    ) -> Result<Vec<RenderedTile>, Error> {
+        let tile_px = tile_px.unwrap_or_else(|| self.get_document_type().default_tile_size());
+        let dpi = self.render_dpi(dpi);
+        if tile_px == 0 || dpi == 0 || threads == 0 {
+            return Err(Error::new(format!(
+                "Rendering a grid needs a tile pixel, a DPI and a thread, not {tile_px} pixels at {dpi} DPI on {threads} threads"
+            )));
+        }
+        if !self.rendering_initialized {
+            self.initialize_for_rendering("");
+        }
+
+        let (width, height) = self.get_document_size();
+        let grid = grid_tiles(
+            twips_to_px(width, dpi),
+            twips_to_px(height, dpi),
+            tile_px,
+            dpi,
+        );
+        let mode = self.get_tile_mode();
+
+        let (painted_tx, painted_rx) = mpsc::channel::<(usize, Vec<u8>, u32, u32)>();
+        let painted_rx = Mutex::new(painted_rx);
+        let (converted_tx, converted_rx) = mpsc::channel();
+        std::thread::scope(|scope| {
+            // owned by the closure, so that returning drops it and stops the workers
+            let painted_tx = painted_tx;
+            for _ in 0..threads {
+                let painted_rx = &painted_rx;
+                let converted_tx = converted_tx.clone();
+                scope.spawn(move || loop {
+                    let painted = painted_rx.lock().unwrap_or_else(|e| e.into_inner()).recv();
+                    let Ok((i, buffer, width, height)) = painted else {
+                        break;
+                    };
+                    let image = to_rgba_image(buffer, width, height, mode);
+                    if converted_tx.send((i, image)).is_err() {
+                        break;
+                    }
+                });
+            }
+            drop(converted_tx);
+
+            for (i, (_, _, area, (width, height))) in grid.iter().enumerate() {
+                let mut buffer = vec![0u8; *width as usize * *height as usize * 4];
+                self.paint_tile(
+                    &mut buffer,
+                    *width as i32,
+                    *height as i32,
+                    area.x,
+                    area.y,
+                    area.width,
+                    area.height,
+                )?;
+                // the workers only stop once the sender is dropped
+                let _ = painted_tx.send((i, buffer, *width, *height));
+            }
+            Ok(())
+        })?;
+
+        let mut images: Vec<Option<RgbaImage>> = vec![None; grid.len()];
+        for (i, image) in converted_rx {
+            images[i] = Some(image);
+        }
+        Ok(grid
+            .into_iter()
+            .zip(images)
+            .map(|((column, row, area, _), image)| RenderedTile {
+                column,
+                row,
+                area,
+                image: image.expect("every painted tile is converted"),
+            })
+            .collect())
+    }
+
+    /// Returns the resolution to render at, [DocumentType::default_render_dpi] when `None`
+    fn render_dpi(&self, dpi: Option<u32>) -> u32 {
+        dpi.unwrap_or_else(|| self.get_document_type().default_render_dpi())
+    }
+
+    /// Renders an area (in twips) of the current part at the given resolution
+    fn render_area(&mut self, area: Rect, dpi: u32) -> Result<RgbaImage, Error> {
+        let width = twips_to_px(area.width as i64, dpi);
+        let height = twips_to_px(area.height as i64, dpi);
+        let mut buffer = vec![0u8; width as usize * height as usize * 4];
+
+        self.paint_tile(
+            &mut buffer,
+            width as i32,
+            height as i32,
+            area.x,
+            area.y,
+            area.width,
+            area.height,
+        )?;
+
+        Ok(to_rgba_image(buffer, width, height, self.get_tile_mode()))
+    }
+}
+
+/// Splits a canvas into square tiles, cropped at its right and bottom edges.
+/// Returns the column, row, area in twips and size in pixels of every tile, row by row.
+fn grid_tiles(
+    canvas_width: u32,
+    canvas_height: u32,
+    tile_px: u32,
+    dpi: u32,
+) -> Vec<(u32, u32, Rect, (u32, u32))> {
+    let to_twips = |px: u32| (px as i64 * TWIPS_PER_INCH / dpi as i64) as i32;
+    let mut tiles = Vec::new();
+    for (row, y) in (0..canvas_height).step_by(tile_px as usize).enumerate() {
+        let height = tile_px.min(canvas_height - y);
+        for (column, x) in (0..canvas_width).step_by(tile_px as usize).enumerate() {
+            let width = tile_px.min(canvas_width - x);
+            let area = Rect::new(
+                to_twips(x),
+                to_twips(y),
+                to_twips(x + width) - to_twips(x),
+                to_twips(y + height) - to_twips(y),
+            );
+            tiles.push((column as u32, row as u32, area, (width, height)));
+        }
+    }
+    tiles
+}
+
+/// Converts a painted tile of premultiplied pixels into a straight alpha RGBA image
+fn to_rgba_image(mut buffer: Vec<u8>, width: u32, height: u32, mode: TileMode) -> RgbaImage {
+    for pixel in buffer.chunks_exact_mut(4) {
+        if mode == TileMode::Bgra {
+            pixel.swap(0, 2);
+        }
+        let alpha = pixel[3] as u32;
+        if alpha > 0 && alpha < 255 {
+            for channel in &mut pixel[..3] {
+                *channel = ((*channel as u32 * 255 + alpha / 2) / alpha).min(255) as u8;
+            }
+        }
+    }
+
+    RgbaImage::from_raw(width, height, buffer).expect("tile buffer matches its dimensions")
+}
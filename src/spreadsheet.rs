@@ -0,0 +1,289 @@
+use crate::error::Error;
+use crate::xml::attribute;
+use crate::{CallbackType, CellRange, Document, DocumentType, UnoArgs};
+
+impl Document {
+    /// Returns the print ranges defined on the current sheet of a spreadsheet,
+    /// e.g. `A1:C10` is `CellRange::new(0, 0, 2, 9)`. A sheet without print ranges
+    /// yields no ranges.
+    ///
+    /// Calc only exposes print ranges through `XPrintAreas::getPrintAreas` of the UNO API,
+    /// and neither `getCommandValues` nor a `.uno:` command reports them. They are the
+    /// `table:print-ranges` attribute of the sheet in a flat ODS export of the document.
+    pub fn print_ranges(&mut self) -> Result<Vec<CellRange>, Error> {
+        if self.get_document_type() != DocumentType::Spreadsheet {
+            return Err(Error::new(
+                "Print ranges are only defined in spreadsheets".into(),
+            ));
+        }
+
+        let content = self.flat_odf_export()?;
+        match sheet_print_ranges(&content, self.get_part() as usize) {
+            Some(ranges) => parse_print_ranges(&ranges),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the A1-style address of the selected cells of a spreadsheet, e.g. `A1:B2`,
+    /// or of the cell cursor when a single cell is selected, e.g. `C3`.
+    ///
+    /// The address is the latest `LOK_CALLBACK_CELL_ADDRESS` payload of the current view,
+    /// which is what the Name Box of LibreOffice shows: a selection matching a named
+    /// range is reported by its name instead. Callbacks are captured from the first call
+    /// of [Document::initialize_for_rendering] (or of this method) on, so select the
+    /// cells after that.
+    ///
+    /// LibreOffice delivers the callbacks from its main loop, a while after the command
+    /// selecting the cells returned: select them with a method that waits for them, e.g.
+    /// [Document::post_uno_command_and_wait], rather than with [Document::post_uno_command].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, UnoArgs, urls};
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/table.ods")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.initialize_for_rendering("");
+    ///
+    /// let args = UnoArgs::new().string("ToPoint", "A1:B2");
+    /// doc.post_uno_command_and_wait(".uno:GoToCell", args, Duration::from_secs(5))?;
+    /// assert_eq!("A1:B2", doc.selected_range_address()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn selected_range_address(&self) -> Result<String, Error> {
+        if self.get_document_type() != DocumentType::Spreadsheet {
+            return Err(Error::new(
+                "Cell addresses are only reported by spreadsheets".into(),
+            ));
+        }
+
+        self.register_callback_buffer();
+        match self.callbacks.last_payload(CallbackType::CellAddress) {
+            Some(address) if !address.trim().is_empty() => Ok(address.trim().to_string()),
+            _ => Err(Error::new(
+                "No cell address was reported yet, select the cells after initialize_for_rendering"
+                    .into(),
+            )),
+        }
+    }
+
+    /// Sets the content of a cell of a spreadsheet, as if typed into it, via `.uno:EnterString`.
+    ///
+    /// The value is interpreted like typed input: `=1+1` is a formula, `42` a number
+    /// and `abc` text. Prefix it with an apostrophe to keep it as text, e.g. `'=1+1`.
+    /// The cell cursor is moved to the cell, the current sheet stays the same.
+    ///
+    /// # Arguments
+    /// * `sheet` - the index of the sheet, from 0
+    /// * `cell` - the A1-style address of the cell, e.g. `B3`
+    /// * `value` - the content of the cell
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/table.ods")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    ///
+    /// doc.set_cell_value(0, "A1", "=1+1")?;
+    /// assert_eq!("2", doc.get_cell_value(0, "A1")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_cell_value(&mut self, sheet: i32, cell: &str, value: &str) -> Result<(), Error> {
+        if value.contains('\0') {
+            return Err(Error::new("Cell value contains a NUL character".into()));
+        }
+        if self.is_view_read_only() {
+            return Err(Error::new(format!(
+                "The view of the document {} is read-only, cells can't be set",
+                self.url
+            )));
+        }
+
+        self.with_cell(sheet, cell, |doc| {
+            let args = UnoArgs::new().string("StringName", value);
+            doc.post_uno_command(".uno:EnterString", args, false)
+        })
+    }
+
+    /// Returns the content of a cell of a spreadsheet as displayed, e.g. `2` for
+    /// a cell with the formula `=1+1`, or an empty string for an empty cell.
+    ///
+    /// The cell is selected and its content is read as the plain text of the selection.
+    /// The cell cursor is moved to the cell, the current sheet stays the same.
+    ///
+    /// # Arguments
+    /// * `sheet` - the index of the sheet, from 0
+    /// * `cell` - the A1-style address of the cell, e.g. `B3`
+    pub fn get_cell_value(&mut self, sheet: i32, cell: &str) -> Result<String, Error> {
+        let value = self.with_cell(sheet, cell, |doc| Ok(doc.selected_text()))?;
+        // the text of a selected cell ends with a line break
+        Ok(value
+            .unwrap_or_default()
+            .trim_end_matches(['\r', '\n'])
+            .to_string())
+    }
+
+    /// Moves the cell cursor to a cell of a sheet, runs `f` and switches back
+    /// to the current sheet
+    fn with_cell<R>(
+        &mut self,
+        sheet: i32,
+        cell: &str,
+        f: impl FnOnce(&mut Document) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        if self.get_document_type() != DocumentType::Spreadsheet {
+            return Err(Error::new("Cells are only found in spreadsheets".into()));
+        }
+        if cell.contains(':') || cell.contains('.') || cell_position(cell).is_none() {
+            return Err(Error::new(format!(
+                "Invalid cell address {cell}, expected e.g. B3"
+            )));
+        }
+
+        self.with_part(sheet, |doc| {
+            let args = UnoArgs::new().string("ToPoint", cell);
+            doc.post_uno_command(".uno:GoToCell", args, false)?;
+            f(doc)
+        })?
+    }
+}
+
+/// Returns the unescaped `table:print-ranges` attribute of a sheet in flat ODS content
+fn sheet_print_ranges(content: &str, sheet: usize) -> Option<String> {
+    let table = content.split("<table:table ").nth(sheet + 1)?;
+    attribute(&table[..table.find('>')?], "table:print-ranges")
+}
+
+/// Parses space separated cell ranges, e.g. `Sheet1.A1:Sheet1.C10 'My Sheet'.E2:.F4`
+fn parse_print_ranges(ranges: &str) -> Result<Vec<CellRange>, Error> {
+    let invalid = || Error::new(format!("Invalid print ranges {ranges}!"));
+    let mut cell_ranges = Vec::new();
+
+    for range in split_unquoted(ranges, ' ') {
+        let cells = split_unquoted(&range, ':');
+        let (start, end) = match &cells[..] {
+            [cell] => (cell_position(cell), cell_position(cell)),
+            [start, end] => (cell_position(start), cell_position(end)),
+            _ => return Err(invalid()),
+        };
+        let ((start_column, start_row), (end_column, end_row)) =
+            start.zip(end).ok_or_else(invalid)?;
+
+        cell_ranges.push(CellRange::new(start_column, start_row, end_column, end_row));
+    }
+
+    Ok(cell_ranges)
+}
+
+/// Splits on a separator outside of quoted sheet names, skipping empty parts
+fn split_unquoted(value: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut quoted = false;
+    for c in value.chars() {
+        if c == '\'' {
+            quoted = !quoted;
+        }
+        if c == separator && !quoted {
+            parts.push(String::new());
+        } else {
+            parts.last_mut().unwrap().push(c);
+        }
+    }
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// The most columns of a sheet, up to `XFD`, with LibreOffice's large spreadsheets enabled
+const MAX_COLUMNS: u32 = 16384;
+
+/// Returns the `(column, row)` of a cell reference like `$Sheet1.$B$3`, numbered from 0
+fn cell_position(reference: &str) -> Option<(u32, u32)> {
+    let cell = reference.rsplit('.').next()?.replace('$', "");
+    let digits = cell.find(|c: char| c.is_ascii_digit())?;
+    let (letters, row) = cell.split_at(digits);
+    if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let column = letters
+        .to_ascii_uppercase()
+        .bytes()
+        .try_fold(0u32, |column, letter| {
+            column
+                .checked_mul(26)?
+                .checked_add(u32::from(letter - b'A') + 1)
+                .filter(|column| *column <= MAX_COLUMNS)
+        })?;
+    let row = row.parse::<u32>().ok()?;
+    if row < 1 {
+        return None;
+    }
+
+    Some((column - 1, row - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cell_position, parse_print_ranges, sheet_print_ranges, split_unquoted};
+    use crate::CellRange;
+
+    #[test]
+    fn test_cell_position() {
+        assert_eq!(Some((0, 0)), cell_position("A1"));
+        assert_eq!(Some((27, 2)), cell_position("$Sheet1.$AB$3"));
+        assert_eq!(Some((16383, 9)), cell_position("xfd10"));
+        assert_eq!(None, cell_position("XFE1"));
+        assert_eq!(None, cell_position("ZZZZZZZ1"));
+        assert_eq!(None, cell_position("A0"));
+        assert_eq!(None, cell_position("12"));
+    }
+
+    #[test]
+    fn test_split_unquoted() {
+        assert_eq!(
+            vec!["'My Sheet'.E2:.F4", "Sheet1.A1"],
+            split_unquoted("'My Sheet'.E2:.F4  Sheet1.A1", ' ')
+        );
+        assert_eq!(vec!["'a:b'.A1", ".B2"], split_unquoted("'a:b'.A1:.B2", ':'));
+        assert!(split_unquoted("", ' ').is_empty());
+    }
+
+    #[test]
+    fn test_parse_print_ranges() {
+        assert_eq!(
+            vec![CellRange::new(0, 0, 2, 9), CellRange::new(4, 1, 5, 3)],
+            parse_print_ranges("Sheet1.A1:Sheet1.C10 'My Sheet'.E2:.F4").unwrap()
+        );
+        assert_eq!(
+            vec![CellRange::new(1, 1, 1, 1)],
+            parse_print_ranges("'My.Sheet'.$B$2").unwrap()
+        );
+        assert!(parse_print_ranges("").unwrap().is_empty());
+        assert!(parse_print_ranges("Sheet1.A1:Sheet1.B2:Sheet1.C3").is_err());
+        assert!(parse_print_ranges("Sheet1.A1:Sheet1").is_err());
+    }
+
+    #[test]
+    fn test_sheet_print_ranges() {
+        let content = r#"<office:spreadsheet>
+            <table:table table:name="Sheet1" table:style-name="ta1"><table:table-row/></table:table>
+            <table:table table:name="Tom &amp; Jerry" table:print-ranges="'Tom &amp; Jerry'.A1:.B2">
+            </table:table></office:spreadsheet>"#;
+        assert_eq!(None, sheet_print_ranges(content, 0));
+        assert_eq!(
+            Some("'Tom & Jerry'.A1:.B2".to_string()),
+            sheet_print_ranges(content, 1)
+        );
+        assert_eq!(None, sheet_print_ranges(content, 2));
+    }
+}
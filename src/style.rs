@@ -0,0 +1,125 @@
+use crate::error::Error;
+use crate::{Document, UnoArgs};
+
+/// The family of a named style, see [Document::list_styles]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StyleFamily {
+    Paragraph,
+    Character,
+    Page,
+    /// Styles of frames, e.g. of images, in text documents
+    Frame,
+    /// Styles of numbered and bulleted lists, in text documents
+    List,
+    /// Styles of cells, in spreadsheets
+    Cell,
+}
+
+impl StyleFamily {
+    /// The name of the family in the values and arguments of `.uno:StyleApply`
+    pub fn family_name(self) -> &'static str {
+        match self {
+            StyleFamily::Paragraph => "ParagraphStyles",
+            StyleFamily::Character => "CharacterStyles",
+            StyleFamily::Page => "PageStyles",
+            StyleFamily::Frame => "FrameStyles",
+            StyleFamily::List => "NumberingStyles",
+            StyleFamily::Cell => "CellStyles",
+        }
+    }
+}
+
+impl Document {
+    /// Returns the names of the styles of a family, as shown to users,
+    /// e.g. `Heading 1`, from the values of `.uno:StyleApply`.
+    ///
+    /// Returns no style for a family the document doesn't have, e.g. cell styles
+    /// in a text document.
+    ///
+    /// # Arguments
+    /// * `family` - the family of the styles
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, StyleFamily, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let doc = office.document_load(doc_url)?;
+    ///
+    /// let styles = doc.list_styles(StyleFamily::Paragraph)?;
+    /// assert!(styles.iter().any(|style| style == "Heading 1"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_styles(&self, family: StyleFamily) -> Result<Vec<String>, Error> {
+        let values = self
+            .get_command_values(".uno:StyleApply")
+            .ok_or_else(|| Error::new("No styles were returned for .uno:StyleApply".into()))?;
+        let json: serde_json::Value = serde_json::from_str(&values)
+            .map_err(|ex| Error::new(format!("Failed to parse the styles! {ex}")))?;
+
+        // e.g. `{"commandName": ".uno:StyleApply", "commandValues":
+        // {"ParagraphStyles": ["Standard", "Heading 1", ...], "Commands": [...]}}`
+        let Some(styles) = json["commandValues"][family.family_name()].as_array() else {
+            return Ok(Vec::new());
+        };
+        Ok(styles
+            .iter()
+            .filter_map(|style| style.as_str())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Applies a named style to the current selection, or to the paragraph (character
+    /// styles: the word) at the cursor position, via `.uno:StyleApply`.
+    ///
+    /// Fails when the document has no such style, see [Document::list_styles].
+    ///
+    /// # Arguments
+    /// * `style_name` - the name of the style, as listed by [Document::list_styles]
+    /// * `family` - the family of the style
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, StyleFamily, UnoArgs, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// let mut doc = office.document_load(doc_url)?;
+    /// doc.initialize_for_rendering("");
+    ///
+    /// doc.post_uno_command(".uno:SelectAll", UnoArgs::new(), false)?;
+    /// doc.apply_style("Heading 1", StyleFamily::Paragraph)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_style(&mut self, style_name: &str, family: StyleFamily) -> Result<(), Error> {
+        if self.is_view_read_only() {
+            return Err(Error::new(format!(
+                "The view of the document {} is read-only, styles can't be applied",
+                self.url
+            )));
+        }
+        if !self
+            .list_styles(family)?
+            .iter()
+            .any(|style| style == style_name)
+        {
+            return Err(Error::new(format!(
+                "The document has no {} named {style_name}",
+                family.family_name()
+            )));
+        }
+
+        let args = UnoArgs::new()
+            .string("Style", style_name)
+            .string("FamilyName", family.family_name());
+        self.post_uno_command(".uno:StyleApply", args, false)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::Error;
+use crate::{Document, Rect};
+
+/// The kind of a tracked change, see [Document::tracked_changes]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrackedChangeKind {
+    Insertion,
+    Deletion,
+    /// A change of the formatting of text or of paragraphs
+    Format,
+    /// Another change, e.g. of a table
+    Other,
+}
+
+/// A change recorded while changes were tracked, see [Document::tracked_changes]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedChange {
+    /// The id of the change, unique in the document
+    pub id: i32,
+    pub kind: TrackedChangeKind,
+    pub author: String,
+    /// When the change was made, e.g. `2023-05-04T10:20:30`
+    pub date: String,
+    /// The comment of the change, empty when it has none
+    pub comment: String,
+    /// The area (in twips) of the changed text, empty when it isn't known,
+    /// e.g. for deleted paragraphs
+    pub rect: Rect,
+}
+
+impl Document {
+    /// Returns the tracked changes of the document, in document order.
+    ///
+    /// They are read from the values of `.uno:AcceptTrackedChanges`, the command of
+    /// the *Manage Track Changes* dialog. Only text documents report the area of
+    /// the changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::{Office, urls};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut office = Office::new("/usr/lib/libreoffice/program")?;
+    /// let doc_url = urls::local_into_abs("./test_data/tracked_changes.odt")?;
+    /// let doc = office.document_load(doc_url)?;
+    ///
+    /// for change in doc.tracked_changes()? {
+    ///     println!("{:?} by {} on {}", change.kind, change.author, change.date);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tracked_changes(&self) -> Result<Vec<TrackedChange>, Error> {
+        let values = self
+            .get_command_values(".uno:AcceptTrackedChanges")
+            .ok_or_else(|| {
+                Error::new("No changes were returned for .uno:AcceptTrackedChanges".into())
+            })?;
+        let json: serde_json::Value = serde_json::from_str(&values)
+            .map_err(|ex| Error::new(format!("Failed to parse the tracked changes! {ex}")))?;
+
+        // e.g. `{"redlines": [{"index": "1", "author": "...", "type": "Insert",
+        // "comment": "", "dateTime": "2023-05-04T10:20:30", "textRange": "1418, 1418, 800, 276"}]}`
+        let Some(changes) = json["redlines"].as_array() else {
+            return Ok(Vec::new());
+        };
+        changes.iter().map(parse_tracked_change).collect()
+    }
+
+    /// Accepts every tracked change, via `.uno:AcceptAllTrackedChanges`
+    pub fn accept_all_changes(&mut self) -> Result<(), Error> {
+        self.post_uno_command(".uno:AcceptAllTrackedChanges", "", false)
+    }
+
+    /// Rejects every tracked change, via `.uno:RejectAllTrackedChanges`
+    pub fn reject_all_changes(&mut self) -> Result<(), Error> {
+        self.post_uno_command(".uno:RejectAllTrackedChanges", "", false)
+    }
+}
+
+/// Parses a change of the values of `.uno:AcceptTrackedChanges`
+fn parse_tracked_change(change: &serde_json::Value) -> Result<TrackedChange, Error> {
+    let id = match &change["index"] {
+        serde_json::Value::Number(id) => id.as_i64().map(|id| id as i32),
+        serde_json::Value::String(id) => id.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| Error::new(format!("The tracked change {change} has no index")))?;
+
+    let kind = match change["type"].as_str() {
+        Some("Insert") => TrackedChangeKind::Insertion,
+        Some("Delete") => TrackedChangeKind::Deletion,
+        Some("Format" | "ParagraphFormat" | "Attributes") => TrackedChangeKind::Format,
+        _ => TrackedChangeKind::Other,
+    };
+    let rect = Rect::parse_list(change["textRange"].as_str().unwrap_or_default())?
+        .iter()
+        .fold(Rect::default(), |area, rect| area.union(rect));
+
+    let field = |name: &str| change[name].as_str().unwrap_or_default().to_string();
+    Ok(TrackedChange {
+        id,
+        kind,
+        author: field("author"),
+        date: field("dateTime"),
+        comment: field("comment"),
+        rect,
+    })
+}
@@ -0,0 +1,98 @@
+/// Twips, the unit of document coordinates, per inch
+pub const TWIPS_PER_INCH: i64 = 1440;
+
+/// Converts a length in twips to pixels at the given resolution,
+/// `px = twips * dpi / 1440` rounded down. Negative lengths yield 0.
+///
+/// # Arguments
+/// * `twips` - the length in twips
+/// * `dpi` - the resolution in pixels per inch
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::units;
+///
+/// assert_eq!(96, units::twips_to_px(1440, 96));
+/// assert_eq!(1275, units::twips_to_px(12240, 150));
+/// ```
+pub fn twips_to_px(twips: i64, dpi: u32) -> u32 {
+    (twips.max(0) * dpi as i64 / TWIPS_PER_INCH) as u32
+}
+
+/// The resolution of a 100% zoom, in pixels per inch
+pub const SCREEN_DPI: u32 = 96;
+
+/// Converts a zoom level to the resolution it renders at, 100% being [SCREEN_DPI]
+///
+/// # Arguments
+/// * `percent` - the zoom level, e.g. 150 for 150%
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::units;
+///
+/// assert_eq!(96, units::zoom_to_dpi(100));
+/// assert_eq!(144, units::zoom_to_dpi(150));
+/// ```
+pub fn zoom_to_dpi(percent: u32) -> u32 {
+    SCREEN_DPI * percent / 100
+}
+
+/// Maps a point of a canvas showing a whole document to document coordinates,
+/// the inverse of [twip_to_pixel], rounding to the nearest twip.
+/// Yields `(0, 0)` for an empty canvas.
+///
+/// # Arguments
+/// * `px` - the point of the canvas, in pixels from its top left corner
+/// * `canvas_px` - the size of the canvas in pixels, as `(width, height)`
+/// * `document` - the size of the document in twips, as `(width, height)`,
+///                e.g. [crate::Document::get_document_size]
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::units;
+///
+/// // the middle of a US letter page shown on a 816 x 1056 canvas
+/// assert_eq!((6120, 7920), units::pixel_to_twip((408, 528), (816, 1056), (12240, 15840)));
+/// ```
+pub fn pixel_to_twip(px: (i32, i32), canvas_px: (i32, i32), document: (i64, i64)) -> (i32, i32) {
+    (
+        scale(px.0 as i64, document.0, canvas_px.0 as i64),
+        scale(px.1 as i64, document.1, canvas_px.1 as i64),
+    )
+}
+
+/// Maps document coordinates to a point of a canvas showing the whole document,
+/// the inverse of [pixel_to_twip], rounding to the nearest pixel.
+/// Yields `(0, 0)` for an empty document.
+///
+/// # Arguments
+/// * `twip` - the point of the document, in twips
+/// * `canvas_px` - the size of the canvas in pixels, as `(width, height)`
+/// * `document` - the size of the document in twips, as `(width, height)`,
+///                e.g. [crate::Document::get_document_size]
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::units;
+///
+/// assert_eq!((408, 528), units::twip_to_pixel((6120, 7920), (816, 1056), (12240, 15840)));
+/// ```
+pub fn twip_to_pixel(twip: (i32, i32), canvas_px: (i32, i32), document: (i64, i64)) -> (i32, i32) {
+    (
+        scale(twip.0 as i64, canvas_px.0 as i64, document.0),
+        scale(twip.1 as i64, canvas_px.1 as i64, document.1),
+    )
+}
+
+/// Computes `value * to / from` rounded to the nearest integer, saturating at the `i32` bounds
+fn scale(value: i64, to: i64, from: i64) -> i32 {
+    if from == 0 {
+        return 0;
+    }
+    (value as f64 * to as f64 / from as f64).round() as i32
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+use std::sync::OnceLock;
+
+use serde_json::{json, Map, Value};
+
+/// Builder of the JSON arguments of a UNO command, see [crate::Document::post_uno_command]
+///
+/// Every argument is serialized with its UNO type, the way LibreOfficeKit parses them,
+/// e.g. `{"Text":{"type":"string","value":"Hello"}}`.
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::UnoArgs;
+///
+/// let args = UnoArgs::new()
+///     .string("SearchItem.SearchString", "cat")
+///     .long("SearchItem.Command", 3)
+///     .boolean("SearchItem.Backward", false);
+///
+/// assert_eq!(
+///     r#"{"SearchItem.SearchString":{"type":"string","value":"cat"},"#.to_owned()
+///         + r#""SearchItem.Command":{"type":"long","value":3},"#
+///         + r#""SearchItem.Backward":{"type":"boolean","value":false}}"#,
+///     args.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnoArgs {
+    properties: Map<String, Value>,
+    /// The serialized arguments, for [AsRef], serialized once all arguments are added
+    json: OnceLock<String>,
+}
+
+impl UnoArgs {
+    /// Create an empty set of arguments, serialized as `{}`
+    pub fn new() -> UnoArgs {
+        UnoArgs {
+            properties: Map::new(),
+            json: OnceLock::new(),
+        }
+    }
+
+    /// Add a `string` argument
+    pub fn string(self, key: &str, value: &str) -> UnoArgs {
+        self.property(key, "string", json!(value))
+    }
+
+    /// Add a `long` (32 bit integer) argument
+    pub fn long(self, key: &str, value: i32) -> UnoArgs {
+        self.property(key, "long", json!(value))
+    }
+
+    /// Add a `short` (16 bit integer) argument
+    pub fn short(self, key: &str, value: i16) -> UnoArgs {
+        self.property(key, "short", json!(value))
+    }
+
+    /// Add a `boolean` argument
+    pub fn boolean(self, key: &str, value: bool) -> UnoArgs {
+        self.property(key, "boolean", json!(value))
+    }
+
+    /// Add a `double` argument. JSON has no infinite nor NaN numbers,
+    /// they're serialized as `null`, which LibreOffice reads as 0.
+    pub fn double(self, key: &str, value: f64) -> UnoArgs {
+        self.property(key, "double", json!(value))
+    }
+
+    /// Whether no argument was added
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    fn property(mut self, key: &str, ty: &str, value: Value) -> UnoArgs {
+        self.properties
+            .insert(key.to_owned(), json!({ "type": ty, "value": value }));
+        self.json = OnceLock::new();
+        self
+    }
+}
+
+impl PartialEq for UnoArgs {
+    fn eq(&self, other: &UnoArgs) -> bool {
+        self.properties == other.properties
+    }
+}
+
+impl Eq for UnoArgs {}
+
+impl Default for UnoArgs {
+    fn default() -> UnoArgs {
+        UnoArgs::new()
+    }
+}
+
+impl AsRef<str> for UnoArgs {
+    fn as_ref(&self) -> &str {
+        self.json.get_or_init(|| {
+            serde_json::to_string(&self.properties).expect("JSON values always serialize")
+        })
+    }
+}
+
+impl fmt::Display for UnoArgs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
@@ -1,5 +1,5 @@
 use std::fmt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use url::Url;
 
 use crate::error::Error;
@@ -8,6 +8,29 @@ use crate::error::Error;
 #[derive(Debug, Clone)]
 pub struct DocUrl(String);
 
+impl DocUrl {
+    /// Returns the local path of a `file://` URL, and `None` for other URLs
+    ///
+    /// ```
+    /// use libreoffice_rs::urls;
+    ///
+    /// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let doc_url = urls::local_into_abs("./test_data/test.odt")?;
+    /// assert_eq!(Some(std::fs::canonicalize("./test_data/test.odt")?), doc_url.to_file_path());
+    ///
+    /// assert_eq!(None, urls::remote("http://google.com")?.to_file_path());
+    /// #  Ok(())
+    /// # }
+    /// ```
+    pub fn to_file_path(&self) -> Option<PathBuf> {
+        let url = Url::parse(&self.0).ok()?;
+        if url.scheme() != "file" {
+            return None;
+        }
+        url.to_file_path().ok()
+    }
+}
+
 impl fmt::Display for DocUrl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -120,3 +143,76 @@ pub fn remote<S: Into<String>>(uri: S) -> Result<DocUrl, Error> {
 
     Ok(DocUrl(uri_location))
 }
+
+/// The scheme prefixes of the URLs internal to LibreOffice, see [internal]
+const INTERNAL_PREFIXES: [&str; 3] = ["private:", "vnd.sun.star.", "macro:"];
+
+/// Construct a type-safe DocUrl instance for a URL internal to LibreOffice,
+/// which are neither local files nor valid remote URIs
+/// - This method doesn't check the rest of the URL, LibreOffice does
+/// - The accepted scheme prefixes are `private:` (e.g. `private:stream`),
+///   `vnd.sun.star.` (e.g. `vnd.sun.star.Package:` or `vnd.sun.star.pkg://`)
+///   and `macro:` (e.g. `macro:///Standard.Module1.Main`), ignoring case
+///
+/// # Arguments
+/// * `uri` - A URL with an internal scheme
+///
+/// # Example
+///
+/// ```
+/// use libreoffice_rs::urls;
+///
+/// # fn  main() -> Result<(), Box<dyn std::error::Error>> {
+/// let stream_url = urls::internal("private:stream")?;
+/// assert_eq!("private:stream", stream_url.to_string());
+///
+/// assert!(urls::internal("http://google.com").is_err());
+///
+/// #  Ok(())
+/// # }
+/// ```
+pub fn internal<S: Into<String>>(uri: S) -> Result<DocUrl, Error> {
+    let uri_location = uri.into();
+    let is_internal = INTERNAL_PREFIXES.iter().any(|prefix| {
+        uri_location.len() > prefix.len()
+            && uri_location.is_char_boundary(prefix.len())
+            && uri_location[..prefix.len()].eq_ignore_ascii_case(prefix)
+    });
+
+    if !is_internal {
+        return Err(Error::new(format!(
+            "The URI {} isn't internal to LibreOffice, expected one starting with {}",
+            uri_location,
+            INTERNAL_PREFIXES.join(", ")
+        )));
+    }
+
+    Ok(DocUrl(uri_location))
+}
+
+/// Returns the local path of a save target, given as a `file://` URL
+/// or as an absolute path, and `None` for other targets
+pub(crate) fn local_target_path(url: &str) -> Option<PathBuf> {
+    if url.starts_with("file:") {
+        return Url::parse(url).ok()?.to_file_path().ok();
+    }
+
+    let path = Path::new(url);
+    if path.is_absolute() {
+        Some(path.to_path_buf())
+    } else {
+        None
+    }
+}
+
+/// Normalizes the URL of an existing document: local files get the URL of their
+/// canonical path (resolving symbolic links and `..`), other URLs are re-serialized
+pub(crate) fn normalize(url: &DocUrl) -> Result<DocUrl, Error> {
+    match url.to_file_path() {
+        Some(path) => local_into_abs(path.display().to_string()),
+        None => match Url::parse(&url.0) {
+            Ok(parsed) => Ok(DocUrl(parsed.to_string())),
+            Err(ex) => Err(Error::new(format!("Failed to parse URI {}! {}", url, ex))),
+        },
+    }
+}
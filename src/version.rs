@@ -0,0 +1,85 @@
+use crate::error::Error;
+
+/// The LibreOffice version information, see [crate::Office::get_version_info]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionInfo {
+    /// e.g. `LibreOffice`
+    pub product_name: String,
+    /// The major and minor version, e.g. `7.5`
+    pub product_version: String,
+    /// The rest of the version, e.g. `.2.2`
+    pub product_extension: String,
+    /// The git hash of the build
+    pub build_id: String,
+}
+
+impl VersionInfo {
+    /// Parse the JSON returned by LibreOfficeKit's `getVersionInfo`
+    ///
+    /// # Arguments
+    /// * `json` - e.g. `{"ProductName": "LibreOffice", "ProductVersion": "7.5", "ProductExtension": ".2.2", "BuildId": "..."}`
+    pub fn from_json(json: &str) -> Result<VersionInfo, Error> {
+        let info: serde_json::Value = serde_json::from_str(json)
+            .map_err(|ex| Error::new(format!("Failed to parse version info {json}! {ex}")))?;
+        let field = |name: &str| info[name].as_str().unwrap_or_default().to_owned();
+
+        Ok(VersionInfo {
+            product_name: field("ProductName"),
+            product_version: field("ProductVersion"),
+            product_extension: field("ProductExtension"),
+            build_id: field("BuildId"),
+        })
+    }
+
+    /// The full version, e.g. `7.5.2.2`
+    pub fn full_version(&self) -> String {
+        format!("{}{}", self.product_version, self.product_extension)
+    }
+
+    /// The major and minor version numbers, parsed from the leading numbers
+    /// of the full version: `7.5.2.2` is `(7, 5)` and `24.2.0.3` is `(24, 2)`.
+    /// A missing minor version is 0.
+    pub fn major_minor(&self) -> Result<(u32, u32), Error> {
+        let version = self.full_version();
+        let mut numbers = version.split('.').map(|part| {
+            let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse::<u32>()
+        });
+
+        let major = match numbers.next() {
+            Some(Ok(major)) => major,
+            _ => {
+                return Err(Error::new(format!(
+                    "Failed to parse LibreOffice version {version}"
+                )))
+            }
+        };
+        let minor = match numbers.next() {
+            Some(Ok(minor)) => minor,
+            _ => 0,
+        };
+
+        Ok((major, minor))
+    }
+
+    /// Whether the version is at least `major.minor`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use libreoffice_rs::VersionInfo;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let info = VersionInfo::from_json(
+    ///     r#"{"ProductName": "LibreOffice", "ProductVersion": "7.5", "ProductExtension": ".2.2", "BuildId": ""}"#,
+    /// )?;
+    ///
+    /// assert!(info.is_at_least(7, 4)?);
+    /// assert!(!info.is_at_least(7, 6)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_at_least(&self, major: u32, minor: u32) -> Result<bool, Error> {
+        Ok(self.major_minor()? >= (major, minor))
+    }
+}
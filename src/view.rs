@@ -0,0 +1,9 @@
+/// A view of a document, see [crate::Document::views]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ViewInfo {
+    /// The id of the view
+    pub id: i32,
+    /// The author of the view, when it was created with
+    /// [crate::Document::create_view_with_author]
+    pub author: Option<String>,
+}
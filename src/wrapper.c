@@ -1,7 +1,20 @@
 #include "wrapper.h"
+#include <stdlib.h>
 
 LibreOfficeKit *
 lok_init_wrapper(const char *install_path)
 {
   return lok_init(install_path);
+}
+
+LibreOfficeKit *
+lok_init_2_wrapper(const char *install_path, const char *user_profile_url)
+{
+  return lok_init_2(install_path, user_profile_url);
+}
+
+void
+lok_free_wrapper(void *ptr)
+{
+  free(ptr);
 }
\ No newline at end of file
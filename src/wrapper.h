@@ -3,3 +3,5 @@
 #include "LibreOfficeKit/LibreOfficeKitInit.h"
 
 LibreOfficeKit *lok_init_wrapper(const char *install_path);
+LibreOfficeKit *lok_init_2_wrapper(const char *install_path, const char *user_profile_url);
+void lok_free_wrapper(void *ptr);
@@ -0,0 +1,91 @@
+use crate::units::TWIPS_PER_INCH;
+
+/// Returns the unescaped value of an attribute of a start tag,
+/// with or without the element name, e.g. `draw:frame draw:name="Object 1"`
+pub(crate) fn attribute(start_tag: &str, name: &str) -> Option<String> {
+    let key = format!("{name}=\"");
+    let start = start_tag.match_indices(&key).find_map(|(i, _)| {
+        let whole_name = start_tag[..i]
+            .chars()
+            .next_back()
+            .map_or(true, char::is_whitespace);
+        whole_name.then_some(i + key.len())
+    })?;
+    let value = &start_tag[start..];
+    let value = &value[..value.find('"')?];
+
+    Some(unescape(value))
+}
+
+/// Unescapes the predefined entities of XML, the only ones LibreOffice writes
+pub(crate) fn unescape(value: &str) -> String {
+    value
+        .replace("&apos;", "'")
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Converts an ODF length, e.g. `2.5cm`, to twips
+pub(crate) fn to_twips(length: &str) -> Option<i32> {
+    let unit_start = length.find(|c: char| c.is_ascii_alphabetic())?;
+    let (value, unit) = length.split_at(unit_start);
+    let value: f64 = value.parse().ok()?;
+    let inches = match unit {
+        "in" => value,
+        "cm" => value / 2.54,
+        "mm" => value / 25.4,
+        "pt" => value / 72.0,
+        "pc" => value / 6.0,
+        "px" => value / 96.0,
+        _ => return None,
+    };
+
+    Some((inches * TWIPS_PER_INCH as f64).round() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attribute, to_twips, unescape};
+
+    #[test]
+    fn test_attribute() {
+        let start_tag = r#"draw:frame draw:name="Object 1" svg:x="1cm" svg:y="2cm"/"#;
+        assert_eq!(
+            Some("Object 1".to_string()),
+            attribute(start_tag, "draw:name")
+        );
+        assert_eq!(Some("2cm".to_string()), attribute(start_tag, "svg:y"));
+        assert_eq!(None, attribute(start_tag, "name"));
+        assert_eq!(None, attribute(start_tag, "svg:width"));
+        assert_eq!(
+            None,
+            attribute(r#"text:p text:style-name="P1""#, "style-name")
+        );
+        assert_eq!(
+            Some("<&lt;>".to_string()),
+            attribute(r#"text:p a="&lt;&amp;lt;&gt;""#, "a")
+        );
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(
+            r#"Tom & "Jerry's" <b>"#,
+            unescape("Tom &amp; &quot;Jerry&apos;s&quot; &lt;b&gt;")
+        );
+        assert_eq!("&amp;", unescape("&amp;amp;"));
+    }
+
+    #[test]
+    fn test_to_twips() {
+        for length in ["1in", "2.54cm", "25.4mm", "72pt", "6pc", "96px"] {
+            assert_eq!(Some(1440), to_twips(length), "{length}");
+        }
+        assert_eq!(Some(-720), to_twips("-0.5in"));
+        assert_eq!(None, to_twips("1em"));
+        assert_eq!(None, to_twips("12"));
+        assert_eq!(None, to_twips("cm"));
+    }
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard, Once};
+
+use libreoffice_rs::Office;
+
+pub const INSTALL_PATH: &str = "/usr/lib/libreoffice/program";
+
+/// Serializes the tests of a binary, which share the callback and options of the instance
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Keeps the instance alive until the process exits
+static KEEP_ALIVE: Once = Once::new();
+
+/// The [Office] of a test, holding the other tests of the binary off until it's dropped
+pub struct TestOffice {
+    office: Office,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl Deref for TestOffice {
+    type Target = Office;
+
+    fn deref(&self) -> &Office {
+        &self.office
+    }
+}
+
+impl DerefMut for TestOffice {
+    fn deref_mut(&mut self) -> &mut Office {
+        &mut self.office
+    }
+}
+
+/// Returns the instance shared by the tests of a binary, reset to its initial state.
+///
+/// LibreOfficeKit can only be initialized once per process, so the instance is
+/// never destroyed: dropping the last [Office] of a test would make every later
+/// test fail with `Error::ReinitializationUnsupported`.
+pub fn office() -> TestOffice {
+    // a failed test poisons the lock, the next one resets the instance anyway
+    let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut office = Office::new(INSTALL_PATH).unwrap();
+    KEEP_ALIVE.call_once(|| std::mem::forget(office.clone()));
+    office.reset();
+
+    TestOffice {
+        office,
+        _guard: guard,
+    }
+}
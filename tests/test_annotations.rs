@@ -0,0 +1,54 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_annotations() {
+    let doc_url = urls::local_into_abs("./test_data/comment.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    let annotations = doc.annotations().unwrap();
+    assert_eq!(1, annotations.len(), "{annotations:?}");
+    assert_eq!("Jane Reviewer", annotations[0].author);
+    assert_eq!("Please check this sentence", annotations[0].text);
+    assert!(
+        annotations[0].date.starts_with("2023-05-04"),
+        "{annotations:?}"
+    );
+    assert!(annotations[0].rect.x > 0, "{annotations:?}");
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_no_annotations() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.annotations().unwrap().is_empty());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_insert_annotation() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    doc.insert_annotation("Please check this", "Jane Reviewer")
+        .unwrap();
+    let annotations = doc.annotations().unwrap();
+    assert_eq!(1, annotations.len(), "{annotations:?}");
+    assert_eq!("Please check this", annotations[0].text);
+    assert_eq!("Jane Reviewer", annotations[0].author);
+
+    doc.reply_annotation(annotations[0].id, "Done").unwrap();
+    let annotations = doc.annotations().unwrap();
+    assert_eq!(2, annotations.len(), "{annotations:?}");
+    assert_eq!("Done", annotations[1].text);
+}
@@ -0,0 +1,53 @@
+mod common;
+
+use libreoffice_rs::urls;
+
+/// Writes a truncated copy of the test document, which LibreOffice can't load as is
+fn corrupt_document(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(name);
+    let document = std::fs::read("./test_data/test.odt").unwrap();
+    std::fs::write(&path, &document[..document.len() / 2]).unwrap();
+    path
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_async_error_surfaced() {
+    let path = corrupt_document("libreoffice_rs_async_error.odt");
+    let mut office = common::office();
+    office.reset();
+    office.register_callback(|_, _| {}).unwrap();
+
+    let doc_url = urls::local_into_abs(path.display().to_string()).unwrap();
+    let _ = office.document_load(doc_url);
+    let _ = std::fs::remove_file(&path);
+
+    let last = office.last_error_snapshot();
+    assert!(last.is_some());
+    let mut errors = Vec::new();
+    while let Some(error) = office.take_error() {
+        errors.push(error);
+    }
+    assert_eq!(last.as_ref(), errors.last());
+    assert_eq!(None, office.last_error_snapshot());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_fail_load_on_error() {
+    let path = corrupt_document("libreoffice_rs_fail_load_on_error.odt");
+    let mut office = common::office();
+    office.register_callback(|_, _| {}).unwrap();
+    office.set_fail_load_on_error(true);
+
+    let doc_url = urls::local_into_abs(path.display().to_string()).unwrap();
+    let loaded = office.document_load(doc_url);
+    let _ = std::fs::remove_file(&path);
+    office.set_fail_load_on_error(false);
+
+    assert!(loaded.is_err());
+    assert_eq!(None, office.take_error());
+
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    assert!(office.document_load(doc_url).is_ok());
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_panicking_callback_is_caught() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+
+    office
+        .register_callback(|ty, _| panic!("the callback panicked on the event {ty}"))
+        .unwrap();
+
+    let doc = office.document_load(doc_url.clone());
+    assert!(doc.is_ok(), "{:?}", doc.err());
+    drop(doc);
+
+    // the process survived, and LibreOffice keeps working
+    assert!(office.document_load(doc_url).is_ok());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_register_callback_abort_on_panic() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+
+    office.register_callback_abort_on_panic(|_, _| {}).unwrap();
+
+    let doc = office.document_load(doc_url);
+    assert!(doc.is_ok(), "{:?}", doc.err());
+}
@@ -0,0 +1,45 @@
+use libreoffice_rs::{Capabilities, LibreOfficeKitOptionalFeatures, Office, VersionInfo};
+
+fn version(product_version: &str) -> VersionInfo {
+    VersionInfo::from_json(&format!(
+        r#"{{"ProductName": "LibreOffice", "ProductVersion": "{product_version}", "ProductExtension": ".0.1", "BuildId": ""}}"#
+    ))
+    .unwrap()
+}
+
+#[test]
+fn test_capabilities_from_version() {
+    let capabilities = Capabilities::from_version(version("24.2")).unwrap();
+    assert_eq!(6, capabilities.expected_features.len());
+    assert!(capabilities.expects(
+        LibreOfficeKitOptionalFeatures::LOK_FEATURE_VIEWID_IN_VISCURSOR_INVALIDATION_CALLBACK
+    ));
+
+    let capabilities = Capabilities::from_version(version("6.0")).unwrap();
+    assert_eq!(
+        vec![
+            LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD,
+            LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD_TO_MODIFY,
+            LibreOfficeKitOptionalFeatures::LOK_FEATURE_PART_IN_INVALIDATION_CALLBACK,
+            LibreOfficeKitOptionalFeatures::LOK_FEATURE_NO_TILED_ANNOTATIONS,
+        ],
+        capabilities.expected_features
+    );
+    assert_eq!("6.0.0.1", capabilities.version.full_version());
+
+    let capabilities = Capabilities::from_version(version("5.4")).unwrap();
+    assert!(capabilities.expected_features.is_empty());
+
+    assert!(Capabilities::from_version(version("")).is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_new_with_capabilities() {
+    let (_office, capabilities) =
+        Office::new_with_capabilities("/usr/lib/libreoffice/program").unwrap();
+
+    assert_eq!("LibreOffice", capabilities.version.product_name);
+    assert!(!capabilities.version.product_version.is_empty());
+    assert!(capabilities.expects(LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::{urls, UnoArgs};
+use std::time::Duration;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_selected_range_address() {
+    let doc_url = urls::local_into_abs("./test_data/table.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    let args = UnoArgs::new().string("ToPoint", "A1:B2");
+    doc.post_uno_command_and_wait(".uno:GoToCell", args, Duration::from_secs(5))
+        .unwrap();
+    assert_eq!("A1:B2", doc.selected_range_address().unwrap());
+
+    let args = UnoArgs::new().string("ToPoint", "C3");
+    doc.post_uno_command_and_wait(".uno:GoToCell", args, Duration::from_secs(5))
+        .unwrap();
+    assert_eq!("C3", doc.selected_range_address().unwrap());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_selected_range_address_of_text() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.selected_range_address().is_err());
+}
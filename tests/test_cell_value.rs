@@ -0,0 +1,36 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_and_get_cell_value() {
+    let doc_url = urls::local_into_abs("./test_data/three_sheets.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.set_cell_value(1, "A1", "=1+1").unwrap();
+    assert_eq!("2", doc.get_cell_value(1, "A1").unwrap());
+    assert_eq!(0, doc.get_part());
+
+    // kept as text
+    doc.set_cell_value(1, "B2", "'=1+1").unwrap();
+    assert_eq!("=1+1", doc.get_cell_value(1, "B2").unwrap());
+
+    doc.set_cell_value(0, "C3", "hello").unwrap();
+    assert_eq!("hello", doc.get_cell_value(0, "C3").unwrap());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_invalid_cell_address() {
+    let doc_url = urls::local_into_abs("./test_data/three_sheets.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.set_cell_value(0, "A1:B2", "1").is_err());
+    assert!(doc.get_cell_value(0, "1A").is_err());
+    assert!(doc.get_cell_value(5, "A1").is_err());
+}
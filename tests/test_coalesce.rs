@@ -0,0 +1,51 @@
+use std::time::{Duration, Instant};
+
+use libreoffice_rs::{InvalidationCoalescer, Rect, WHOLE_DOCUMENT};
+
+#[test]
+fn test_many_invalidations_single_flush() {
+    let window = Duration::from_millis(50);
+    let mut coalescer = InvalidationCoalescer::new(window);
+    let start = Instant::now();
+
+    // a flood of invalidations of adjacent lines within the window
+    for i in 0..500 {
+        let payload = format!("1418, {}, 9000, 276, 0", 1418 + i * 276);
+        let now = start + Duration::from_micros(i as u64 * 50);
+        assert_eq!(None, coalescer.push(&payload, now), "flushed at {i}");
+    }
+    assert_eq!(Some(start + window), coalescer.deadline());
+    assert!(!coalescer.is_due(start + Duration::from_millis(49)));
+    assert!(coalescer.is_due(start + window));
+
+    let flushed = coalescer
+        .push("1418, 1418, 100, 100", start + window)
+        .expect("no flush after the window");
+    assert_eq!(vec![Rect::new(1418, 1418, 9000, 500 * 276)], flushed);
+
+    // the window restarts with the next invalidation
+    assert_eq!(None, coalescer.deadline());
+    assert!(coalescer.flush().is_empty());
+    assert_eq!(None, coalescer.push("0, 0, 10, 10", start + window * 2));
+    assert_eq!(vec![Rect::new(0, 0, 10, 10)], coalescer.flush());
+}
+
+#[test]
+fn test_disjoint_and_whole_invalidations() {
+    let mut coalescer = InvalidationCoalescer::new(Duration::from_secs(1));
+    let now = Instant::now();
+
+    coalescer.push("0, 0, 100, 100", now);
+    coalescer.push("200, 0, 100, 100", now);
+    assert_eq!(
+        vec![Rect::new(0, 0, 100, 100), Rect::new(200, 0, 100, 100)],
+        coalescer.flush()
+    );
+
+    coalescer.push("0, 0, 100, 100", now);
+    coalescer.push("EMPTY", now);
+    assert_eq!(vec![WHOLE_DOCUMENT], coalescer.flush());
+
+    coalescer.push("EMPTY, 2", now);
+    assert_eq!(vec![WHOLE_DOCUMENT], coalescer.flush());
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_coalescing_callback_delivers_last_window() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let repaints = Arc::new(Mutex::new(Vec::new()));
+
+    doc.initialize_for_rendering("");
+    doc.register_coalescing_callback(Duration::from_millis(40), {
+        let repaints = repaints.clone();
+        move |rects| repaints.lock().unwrap().push(rects.to_vec())
+    });
+    doc.insert_text("repaint me").unwrap();
+
+    // no other invalidation arrives, the timer delivers the last window
+    std::thread::sleep(Duration::from_millis(400));
+    assert!(!repaints.lock().unwrap().is_empty());
+}
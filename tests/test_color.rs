@@ -0,0 +1,31 @@
+use libreoffice_rs::Color;
+
+#[test]
+fn test_color_to_lok_value() {
+    assert_eq!(0x000000, Color::from_rgb(0, 0, 0).to_lok_value());
+    assert_eq!(0xffffff, Color::from_rgb(255, 255, 255).to_lok_value());
+    assert_eq!(16711680, Color::from_rgb(255, 0, 0).to_lok_value());
+    assert_eq!(0x00ff00, Color::from_rgb(0, 255, 0).to_lok_value());
+    assert_eq!(0x1e90ff, Color::from_rgb(0x1e, 0x90, 0xff).to_lok_value());
+}
+
+#[test]
+fn test_color_from_hex() {
+    assert_eq!(
+        Color::from_rgb(0x1e, 0x90, 0xff),
+        Color::from_hex("#1e90ff").unwrap()
+    );
+    assert_eq!(
+        Color::from_rgb(0x1e, 0x90, 0xff),
+        Color::from_hex("1E90FF").unwrap()
+    );
+    assert_eq!(
+        Color::from_rgb(0xff, 0x00, 0xcc),
+        Color::from_hex("#f0c").unwrap()
+    );
+    assert_eq!("#1e90ff", Color::from_hex("#1E90FF").unwrap().to_string());
+
+    for invalid in ["", "#", "#12345", "#1234567", "#gggggg", "#+1+2+3"] {
+        assert!(Color::from_hex(invalid).is_err(), "{invalid} was accepted");
+    }
+}
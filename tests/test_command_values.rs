@@ -0,0 +1,52 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_get_command_values_batch() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    let values = doc.get_command_values_batch(&[".uno:Bold", ".uno:Italic"]);
+
+    let commands: Vec<&str> = values.iter().map(|(c, _)| c.as_str()).collect();
+    assert_eq!(vec![".uno:Bold", ".uno:Italic"], commands);
+    for (command, value) in &values {
+        assert_eq!(&doc.get_command_values(command), value);
+    }
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_get_command_values_with_nul() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    assert_eq!(None, doc.get_command_values(".uno:CharFont\0Name"));
+    assert!(doc.get_command_values(".uno:CharFontName").is_some());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_available_fonts() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    let fonts = doc.available_fonts().unwrap();
+    assert!(
+        fonts
+            .iter()
+            .any(|font| font.starts_with("Liberation") || font.starts_with("DejaVu")),
+        "{fonts:?}"
+    );
+    let mut sorted = fonts.clone();
+    sorted.sort();
+    sorted.dedup();
+    assert_eq!(sorted, fonts);
+}
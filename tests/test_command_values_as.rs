@@ -0,0 +1,33 @@
+#![cfg(feature = "serde")]
+
+use libreoffice_rs::{urls, Office};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Header {
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RowColumnHeaders {
+    rows: Vec<Header>,
+    columns: Vec<Header>,
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_get_command_values_as_user_struct() {
+    let doc_url = urls::local_into_abs("./test_data/table.ods").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let doc = office.document_load(doc_url).unwrap();
+
+    let headers: RowColumnHeaders = doc
+        .get_command_values_as(".uno:ViewRowColumnHeaders")
+        .unwrap();
+
+    assert_eq!("1", headers.rows[0].text);
+    assert_eq!("A", headers.columns[0].text);
+    assert!(doc
+        .get_command_values_as::<RowColumnHeaders>(".uno:NoSuchCommand")
+        .is_err());
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use libreoffice_rs::OutputFormat;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_convert_dir() {
+    let input_dir = std::env::temp_dir().join("libreoffice_rs_convert_dir_input");
+    let output_dir = std::env::temp_dir().join("libreoffice_rs_convert_dir_output");
+    let _ = std::fs::remove_dir_all(&input_dir);
+    let _ = std::fs::remove_dir_all(&output_dir);
+    std::fs::create_dir_all(&input_dir).unwrap();
+
+    std::fs::copy("./test_data/test.odt", input_dir.join("a.odt")).unwrap();
+    // a truncated document, which fails to load
+    let document = std::fs::read("./test_data/test.odt").unwrap();
+    std::fs::write(input_dir.join("b.odt"), &document[..document.len() / 2]).unwrap();
+
+    let mut office = common::office();
+    let results = office
+        .convert_dir(&input_dir, &output_dir, OutputFormat::Pdf, None)
+        .unwrap();
+    let _ = std::fs::remove_dir_all(&input_dir);
+    let outputs: Vec<_> = output_dir.read_dir().unwrap().collect();
+    let _ = std::fs::remove_dir_all(&output_dir);
+
+    assert_eq!(2, results.len(), "{results:?}");
+    assert!(results[0].0.ends_with("a.odt"), "{results:?}");
+    assert!(
+        results[0].1.as_ref().unwrap().ends_with("a.pdf"),
+        "{results:?}"
+    );
+    assert!(results[1].0.ends_with("b.odt"), "{results:?}");
+    assert!(results[1].1.is_err(), "{results:?}");
+    assert_eq!(1, outputs.len());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_convert_missing_dir() {
+    let mut office = common::office();
+    let output_dir = std::env::temp_dir().join("libreoffice_rs_convert_missing_output");
+
+    let result = office.convert_dir(
+        std::path::Path::new("./does_not_exist"),
+        &output_dir,
+        OutputFormat::Pdf,
+        None,
+    );
+    assert!(result.is_err());
+}
@@ -0,0 +1,64 @@
+use std::convert::TryFrom;
+
+use libreoffice_rs::{CsvImportOptions, LanguageTag};
+
+#[test]
+fn test_csv_import_filter_string() {
+    assert_eq!(
+        "44,34,76,1,,,false,true,,,false,,true,,true",
+        CsvImportOptions::new().to_filter_string()
+    );
+
+    let options = CsvImportOptions::new()
+        .separators(&['\t', ';'])
+        .unwrap()
+        .text_delimiter('\'')
+        .unwrap()
+        .first_line(2)
+        .unwrap()
+        .text_columns(&[3, 1, 3, 0])
+        .language(&LanguageTag::try_from("fr-FR").unwrap())
+        .unwrap()
+        .quoted_as_text(true)
+        .detect_numbers(false);
+    assert_eq!(
+        "9/59,39,76,2,1/2/3/2,1036,true,false,,,false,,true,,false",
+        options.to_filter_string()
+    );
+}
+
+#[test]
+fn test_csv_import_invalid_options() {
+    assert!(CsvImportOptions::new().separators(&[]).is_err());
+    assert!(CsvImportOptions::new().separators(&['\0']).is_err());
+    assert!(CsvImportOptions::new().text_delimiter('😀').is_err());
+    assert!(CsvImportOptions::new().first_line(0).is_err());
+    assert!(CsvImportOptions::new()
+        .language(&LanguageTag::try_from("tlh-QO").unwrap())
+        .is_err());
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_load_csv() {
+    use libreoffice_rs::{urls, Office};
+
+    let doc_url = urls::local_into_abs("./test_data/european.csv").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let options = CsvImportOptions::new()
+        .separators(&[';'])
+        .unwrap()
+        .language(&LanguageTag::try_from("de-DE").unwrap())
+        .unwrap()
+        .text_columns(&[3]);
+    let mut doc = office.document_load_csv(doc_url, &options).unwrap();
+
+    // 31.12.2023 is a date and 1.234,5 a number, not text
+    doc.set_cell_value(0, "D2", "=YEAR(A2)*100+MONTH(A2)")
+        .unwrap();
+    assert_eq!("202312", doc.get_cell_value(0, "D2").unwrap());
+    doc.set_cell_value(0, "E2", "=B2*2").unwrap();
+    assert_eq!("2469", doc.get_cell_value(0, "E2").unwrap());
+    assert_eq!("01234", doc.get_cell_value(0, "C2").unwrap());
+}
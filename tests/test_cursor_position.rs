@@ -0,0 +1,69 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::{urls, UnoArgs};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_and_get_cursor_position() {
+    let doc_url = urls::local_into_abs("./test_data/headings.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert_eq!((0, 0), doc.get_cursor_position().unwrap());
+
+    doc.set_cursor_position(2, 5).unwrap();
+    assert_eq!((2, 5), doc.get_cursor_position().unwrap());
+
+    // the end of the last paragraph, "The end."
+    doc.set_cursor_position(5, 8).unwrap();
+    assert_eq!((5, 8), doc.get_cursor_position().unwrap());
+
+    doc.set_cursor_position(1, 4).unwrap();
+    doc.post_uno_command(".uno:InsertText", UnoArgs::new().string("Text", "X"), false)
+        .unwrap();
+    assert_eq!((1, 5), doc.get_cursor_position().unwrap());
+    doc.select_all().unwrap();
+    assert!(doc.selected_text().unwrap().contains("SomeX text."));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_cursor_position_counts_grapheme_clusters() {
+    let doc_url = urls::local_into_abs("./test_data/headings.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    // "e" and a combining acute accent, two code points the cursor moves over at once
+    doc.set_cursor_position(1, 0).unwrap();
+    doc.post_uno_command(
+        ".uno:InsertText",
+        UnoArgs::new().string("Text", "e\u{301}"),
+        false,
+    )
+    .unwrap();
+    assert_eq!((1, 1), doc.get_cursor_position().unwrap());
+    // reading the position leaves the cursor where it was
+    assert_eq!((1, 1), doc.get_cursor_position().unwrap());
+
+    doc.set_cursor_position(1, 11).unwrap();
+    assert_eq!((1, 11), doc.get_cursor_position().unwrap());
+    assert!(doc.set_cursor_position(1, 12).is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_invalid_cursor_position() {
+    let doc_url = urls::local_into_abs("./test_data/headings.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.set_cursor_position(-1, 0).is_err());
+    assert!(doc.set_cursor_position(1, 50).is_err());
+    assert!(doc.set_cursor_position(10, 0).is_err());
+
+    let doc_url = urls::local_into_abs("./test_data/table.ods").unwrap();
+    let doc = office.document_load(doc_url).unwrap();
+    assert!(doc.get_cursor_position().is_err());
+}
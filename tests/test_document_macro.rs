@@ -0,0 +1,25 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, MacroSecurity, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_run_macro() {
+    // the macro of the document writes this file, also when the document is opened
+    let marker = std::path::Path::new("/tmp/libreoffice_rs_macro_ran");
+
+    let doc_url = urls::local_into_abs("./test_data/macro.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    office.set_macro_security(MacroSecurity::Enabled);
+    let mut doc = office.document_load(doc_url).unwrap();
+    let _ = std::fs::remove_file(marker);
+
+    let ran = doc
+        .run_macro("vnd.sun.star.script:Standard.Module1.Main?language=Basic&location=document")
+        .unwrap();
+    assert!(ran);
+    assert!(marker.exists());
+    let _ = std::fs::remove_file(marker);
+
+    assert!(doc.run_macro("Standard.Module1.Main").is_err());
+}
@@ -0,0 +1,33 @@
+mod common;
+
+use libreoffice_rs::{urls, DocumentType};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_as_raw() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    let raw = doc.as_raw();
+    assert!(!raw.is_null());
+    let ty = unsafe { (*(*raw).pClass).getDocumentType.unwrap()(raw) };
+    assert_eq!(DocumentType::Text as i32, ty);
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_into_raw() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let doc = office.document_load(doc_url).unwrap();
+
+    let raw = doc.into_raw();
+    assert!(!raw.is_null());
+    // the document wasn't destroyed by the drop of `doc`, it's destroyed once here
+    unsafe {
+        let ty = (*(*raw).pClass).getDocumentType.unwrap()(raw);
+        assert_eq!(DocumentType::Text as i32, ty);
+        (*(*raw).pClass).destroy.unwrap()(raw);
+    }
+}
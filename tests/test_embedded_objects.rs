@@ -0,0 +1,28 @@
+mod common;
+
+use libreoffice_rs::{urls, EmbeddedObjectKind};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_embedded_chart() {
+    let doc_url = urls::local_into_abs("./test_data/chart.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let objects = doc.embedded_objects().unwrap();
+    assert_eq!(1, objects.len(), "{objects:?}");
+    assert_eq!(EmbeddedObjectKind::Chart, objects[0].kind);
+    // 8 x 6 cm
+    assert!((objects[0].rect.width - 4535).abs() < 20, "{objects:?}");
+    assert!((objects[0].rect.height - 3402).abs() < 20, "{objects:?}");
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_no_embedded_objects() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.embedded_objects().unwrap().is_empty());
+}
@@ -0,0 +1,111 @@
+use libreoffice_rs::{
+    CallbackType, DocumentType, EditMode, LibreOfficeKitOptionalFeatures, MacroSecurity,
+    MouseEventType, OutputFormat, SelectionType, SignatureState, TileMode,
+};
+use std::convert::TryFrom;
+
+// The enums are `#[non_exhaustive]`, matching them outside of the crate
+// requires a wildcard arm, see the `compile_fail` example of `DocumentType`.
+#[test]
+fn test_non_exhaustive_enums_match_with_wildcard() {
+    let doc_type = match DocumentType::Spreadsheet {
+        DocumentType::Text => "text",
+        DocumentType::Spreadsheet => "spreadsheet",
+        _ => "other",
+    };
+    assert_eq!("spreadsheet", doc_type);
+
+    let password = match CallbackType::DocumentPasswordToModify {
+        CallbackType::DocumentPassword => "open",
+        CallbackType::DocumentPasswordToModify => "modify",
+        _ => "none",
+    };
+    assert_eq!("modify", password);
+
+    let extension = match OutputFormat::Pdf {
+        OutputFormat::Pdf => "pdf",
+        OutputFormat::Png => "png",
+        _ => "other",
+    };
+    assert_eq!("pdf", extension);
+
+    let flag = LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD;
+    assert_eq!(1, flag as u64);
+}
+
+#[test]
+fn test_callback_type_try_from() {
+    assert_eq!(
+        CallbackType::DocumentPassword,
+        CallbackType::try_from(20).unwrap()
+    );
+    assert_eq!(
+        CallbackType::DocumentBackgroundColor,
+        CallbackType::try_from(52).unwrap()
+    );
+    assert!(CallbackType::try_from(-1).is_err());
+}
+
+#[test]
+fn test_document_type_try_from() {
+    assert_eq!(
+        DocumentType::Spreadsheet,
+        DocumentType::try_from(1).unwrap()
+    );
+    assert!(DocumentType::try_from(5).is_err());
+}
+
+#[test]
+fn test_tile_mode_try_from() {
+    assert_eq!(TileMode::Bgra, TileMode::try_from(1).unwrap());
+    assert!(TileMode::try_from(2).is_err());
+}
+
+#[test]
+fn test_selection_type_try_from() {
+    assert_eq!(
+        SelectionType::LargeText,
+        SelectionType::try_from(2).unwrap()
+    );
+    assert!(SelectionType::try_from(4).is_err());
+}
+
+#[test]
+fn test_signature_state_try_from() {
+    assert_eq!(
+        SignatureState::NotValidated,
+        SignatureState::try_from(4).unwrap()
+    );
+    assert!(SignatureState::try_from(7).is_err());
+}
+
+#[test]
+fn test_edit_mode_try_from() {
+    assert_eq!(EditMode::MasterPage, EditMode::try_from(1).unwrap());
+    assert!(EditMode::try_from(2).is_err());
+}
+
+#[test]
+fn test_document_type_render_defaults() {
+    for (ty, tile_size, dpi) in [
+        (DocumentType::Text, 256, 96),
+        (DocumentType::Spreadsheet, 512, 96),
+        (DocumentType::Presentation, 512, 144),
+        (DocumentType::Drawing, 512, 144),
+        (DocumentType::Other, 256, 96),
+    ] {
+        assert_eq!(tile_size, ty.default_tile_size(), "{ty:?}");
+        assert_eq!(dpi, ty.default_render_dpi(), "{ty:?}");
+    }
+}
+
+#[test]
+fn test_macro_security_default() {
+    assert_eq!(MacroSecurity::Disabled, MacroSecurity::default());
+}
+
+#[test]
+fn test_mouse_event_type_try_from() {
+    assert_eq!(MouseEventType::Move, MouseEventType::try_from(2).unwrap());
+    assert!(MouseEventType::try_from(3).is_err());
+}
@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use libreoffice_rs::{Error, Office};
+
+#[test]
+fn test_error_equality() {
+    assert_eq!(
+        Error::PartOutOfRange { index: 3, count: 3 },
+        Error::PartOutOfRange { index: 3, count: 3 }
+    );
+    assert_ne!(
+        Error::PartOutOfRange { index: 3, count: 3 },
+        Error::PartOutOfRange { index: 4, count: 3 }
+    );
+    assert_eq!(
+        Error::new("Failed".to_string()),
+        Error::Message("Failed".to_string())
+    );
+    assert_ne!(
+        Error::new("Failed".to_string()),
+        Error::new("Failed again".to_string())
+    );
+}
+
+#[test]
+fn test_error_equality_from_office() {
+    let empty_dir = std::env::temp_dir().join("libreoffice_rs_error_eq_program_dir");
+    std::fs::create_dir_all(&empty_dir).unwrap();
+
+    let err = Office::new(&empty_dir.display().to_string())
+        .err()
+        .expect("initialized from an empty directory");
+    let _ = std::fs::remove_dir(&empty_dir);
+    assert_eq!(
+        Error::InstallationNotFound {
+            searched: PathBuf::from(&empty_dir)
+        },
+        err
+    );
+}
@@ -0,0 +1,20 @@
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_pdf_into_vec() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let mut pdf = Vec::new();
+    let written = doc.export("pdf", None, &mut pdf).unwrap();
+    assert_eq!(pdf.len() as u64, written);
+    assert!(pdf.starts_with(b"%PDF"));
+
+    assert!(doc.export("../pdf", None, &mut pdf).is_err());
+    assert!(doc
+        .export("pdf", Some("SkipImages,TakeOwnership"), &mut pdf)
+        .is_err());
+    assert_eq!(written, pdf.len() as u64);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, LossSeverity, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_checked() {
+    let doc_url = urls::local_into_abs("./test_data/chart.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_export_checked.doc");
+
+    let report = doc
+        .export_checked(&output_path.display().to_string(), "doc", None)
+        .unwrap();
+    let saved = output_path.is_file();
+    let _ = std::fs::remove_file(&output_path);
+    assert!(saved);
+    assert!(report.success, "{report:?}");
+    assert!(
+        report.max_severity() >= Some(LossSeverity::Minor),
+        "{report:?}"
+    );
+    assert!(
+        report
+            .warnings
+            .iter()
+            .all(|warning| !warning.message.is_empty()),
+        "{report:?}"
+    );
+
+    let output_path = std::env::temp_dir().join("libreoffice_rs_export_checked.txt");
+    let report = doc
+        .export_checked(&output_path.display().to_string(), "", None)
+        .unwrap();
+    let _ = std::fs::remove_file(&output_path);
+    assert!(report.success, "{report:?}");
+    assert_eq!(Some(LossSeverity::Major), report.max_severity());
+
+    let report = doc
+        .export_checked("/nonexistent/dir/out.doc", "doc", None)
+        .unwrap();
+    assert!(!report.success);
+
+    assert!(doc.export_checked("/tmp/out\0.doc", "doc", None).is_err());
+}
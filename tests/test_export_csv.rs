@@ -0,0 +1,37 @@
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_csv_sheet() {
+    let doc_url = urls::local_into_abs("./test_data/table.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_export_csv_sheet.csv");
+    let output_url = urls::local_as_abs(output_path.display().to_string()).unwrap();
+
+    doc.export_csv(&output_url, ';', '"', Some(1)).unwrap();
+    let csv = std::fs::read_to_string(&output_path).unwrap();
+    let _ = std::fs::remove_file(&output_path);
+
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(vec!["City;Country", "Paris;France", "Rome;Italy"], lines);
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_csv_of_text_document() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_url = urls::local_as_abs(
+        std::env::temp_dir()
+            .join("unused.csv")
+            .display()
+            .to_string(),
+    )
+    .unwrap();
+
+    assert!(doc.export_csv(&output_url, ',', '"', None).is_err());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use libreoffice_rs::{urls, EpubOptions};
+
+#[test]
+fn test_epub_filter_string() {
+    assert_eq!("", EpubOptions::new().to_filter_string());
+    assert!(EpubOptions::new().version(4).is_err());
+
+    let cover = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let options = EpubOptions::new()
+        .version(3)
+        .unwrap()
+        .split_by_heading(true)
+        .fixed_layout(true)
+        .cover_image(&cover);
+    assert_eq!(
+        format!(
+            r#"{{"EPUBVersion":{{"type":"long","value":30}},"SplitMethod":{{"type":"long","value":0}},"LayoutMethod":{{"type":"long","value":1}},"RVNGCoverImage":{{"type":"string","value":"{cover}"}}}}"#
+        ),
+        options.to_filter_string()
+    );
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_epub() {
+    let doc_url = urls::local_into_abs("./test_data/headings.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_export_epub.epub");
+    let output_url = urls::local_as_abs(output_path.display().to_string()).unwrap();
+
+    doc.export_epub(&output_url, Some(EpubOptions::new().split_by_heading(true)))
+        .unwrap();
+    let epub = std::fs::read(&output_path).unwrap();
+    let _ = std::fs::remove_file(&output_path);
+
+    // a zip archive, starting with the uncompressed `mimetype` entry
+    assert_eq!(b"PK\x03\x04", &epub[..4]);
+    assert!(epub
+        .windows(b"application/epub+zip".len())
+        .any(|window| window == b"application/epub+zip"));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_epub_with_cover() {
+    let doc_url = urls::local_into_abs("./test_data/headings.odt").unwrap();
+    let cover = urls::local_into_abs("./test_data/epub_cover.png").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_export_epub_cover.epub");
+    let output_url = urls::local_as_abs(output_path.display().to_string()).unwrap();
+
+    doc.export_epub(&output_url, Some(EpubOptions::new().cover_image(&cover)))
+        .unwrap();
+    let epub = std::fs::read(&output_path).unwrap();
+    let _ = std::fs::remove_file(&output_path);
+
+    // headings.odt has no image, and no image named `cover` lies next to it:
+    // the only PNG entry of the archive is the cover
+    assert!(epub.windows(b".png".len()).any(|window| window == b".png"));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_epub_of_spreadsheet() {
+    let doc_url = urls::local_into_abs("./test_data/table.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_url = urls::local_as_abs(
+        std::env::temp_dir()
+            .join("unused.epub")
+            .display()
+            .to_string(),
+    )
+    .unwrap();
+
+    assert!(doc.export_epub(&output_url, None).is_err());
+}
@@ -0,0 +1,220 @@
+mod common;
+
+use libreoffice_rs::{urls, FilterOptions, PdfAConformance};
+
+#[test]
+fn test_page_range_validation() {
+    for range in ["1", "2-4", "1,3,5-7", "2-2"] {
+        assert!(
+            FilterOptions::new().page_range(range).is_ok(),
+            "{range} was rejected"
+        );
+    }
+    for range in ["", "0", "4-2", "a-b", "1,,2", "-3", "2-"] {
+        assert!(
+            FilterOptions::new().page_range(range).is_err(),
+            "{range} was accepted"
+        );
+    }
+}
+
+#[test]
+fn test_page_range_serialization() {
+    let options = FilterOptions::new().page_range("2-4").unwrap();
+    let expected = r#"{"PageRange":{"type":"string","value":"2-4"}}"#;
+
+    assert_eq!(expected, options.to_filter_string("pdf").unwrap());
+    assert_eq!(expected, options.to_filter_string("PNG").unwrap());
+    assert!(options.to_filter_string("docx").is_err());
+    assert_eq!("", FilterOptions::new().to_filter_string("pdf").unwrap());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_with_page_range() {
+    let doc_url = urls::local_into_abs("./test_data/multipage.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_page_range.pdf");
+    let options = FilterOptions::new().page_range("2-4").unwrap();
+
+    doc.save_as_with(&output_path.display().to_string(), "pdf", &options)
+        .unwrap();
+    let pdf = std::fs::read(&output_path).unwrap();
+    let _ = std::fs::remove_file(&output_path);
+
+    // every page object is a dictionary with `/Type/Page`, the page tree uses `/Type/Pages`
+    let pdf = String::from_utf8_lossy(&pdf);
+    let pages = pdf.matches("/Type/Page").count() - pdf.matches("/Type/Pages").count();
+    assert_eq!(3, pages);
+}
+
+#[test]
+fn test_pdf_a_serialization() {
+    for (conformance, version) in [
+        (PdfAConformance::A1b, 1),
+        (PdfAConformance::A2b, 2),
+        (PdfAConformance::A3b, 3),
+    ] {
+        let options = FilterOptions::new().pdf_a(conformance);
+        assert_eq!(
+            format!(r#"{{"SelectPdfVersion":{{"type":"long","value":{version}}}}}"#),
+            options.to_filter_string("pdf").unwrap()
+        );
+        assert!(options.to_filter_string("png").is_err());
+        assert!(options.to_filter_string("docx").is_err());
+    }
+
+    let options = FilterOptions::new()
+        .page_range("1")
+        .unwrap()
+        .pdf_a(PdfAConformance::A1b);
+    assert_eq!(
+        r#"{"PageRange":{"type":"string","value":"1"},"SelectPdfVersion":{"type":"long","value":1}}"#,
+        options.to_filter_string("pdf").unwrap()
+    );
+}
+
+#[test]
+fn test_encoding_serialization() {
+    let options = FilterOptions::new().encoding("utf8").unwrap();
+    assert_eq!("UTF8", options.to_filter_string("txt").unwrap());
+    assert_eq!("44,34,76", options.to_filter_string("CSV").unwrap());
+    assert!(options.to_filter_string("pdf").is_err());
+
+    let options = FilterOptions::new().encoding("ISO_8859_1").unwrap();
+    assert_eq!("ISO_8859_1", options.to_filter_string("txt").unwrap());
+    assert_eq!("44,34,12", options.to_filter_string("csv").unwrap());
+
+    assert!(FilterOptions::new().encoding("KLINGON").is_err());
+    assert!(FilterOptions::new()
+        .page_range("1")
+        .unwrap()
+        .to_filter_string("txt")
+        .is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_with_encoding() {
+    let doc_url = urls::local_into_abs("./test_data/unicode.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_encoding.txt");
+    let options = FilterOptions::new().encoding("UTF8").unwrap();
+
+    doc.save_as_with(&output_path.display().to_string(), "txt", &options)
+        .unwrap();
+    let text = std::fs::read(&output_path).unwrap();
+    let _ = std::fs::remove_file(&output_path);
+
+    let expected = "Grüße, señor — 日本語".as_bytes();
+    assert!(
+        text.windows(expected.len())
+            .any(|window| window == expected),
+        "{}",
+        String::from_utf8_lossy(&text)
+    );
+}
+
+#[test]
+fn test_jpeg_quality_serialization() {
+    let options = FilterOptions::new().jpeg_quality(40).unwrap();
+    assert_eq!(
+        r#"{"Quality":{"type":"long","value":40}}"#,
+        options.to_filter_string("JPEG").unwrap()
+    );
+    assert!(options.to_filter_string("png").is_err());
+
+    let options = options.page_range("2").unwrap();
+    assert_eq!(
+        r#"{"PageRange":{"type":"string","value":"2"},"Quality":{"type":"long","value":40}}"#,
+        options.to_filter_string("jpg").unwrap()
+    );
+
+    for quality in [0, 101] {
+        assert!(FilterOptions::new().jpeg_quality(quality).is_err());
+    }
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_with_jpeg_quality() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let mut sizes = Vec::new();
+    for quality in [10, 100] {
+        let output_path =
+            std::env::temp_dir().join(format!("libreoffice_rs_jpeg_quality_{quality}.jpg"));
+        let options = FilterOptions::new().jpeg_quality(quality).unwrap();
+        doc.save_as_with(&output_path.display().to_string(), "jpg", &options)
+            .unwrap();
+        sizes.push(std::fs::metadata(&output_path).unwrap().len());
+        let _ = std::fs::remove_file(&output_path);
+    }
+
+    assert!(sizes[0] < sizes[1], "{sizes:?}");
+}
+
+#[test]
+fn test_image_size_serialization() {
+    let options = FilterOptions::new()
+        .pixel_width(800)
+        .unwrap()
+        .pixel_height(600)
+        .unwrap();
+    assert_eq!(
+        r#"{"PixelWidth":{"type":"long","value":800},"PixelHeight":{"type":"long","value":600}}"#,
+        options.to_filter_string("png").unwrap()
+    );
+    assert!(options.to_filter_string("pdf").is_err());
+
+    // an A4 page, about 210 x 297 mm
+    let options = FilterOptions::new().dpi(96, (11906, 16838)).unwrap();
+    assert_eq!(
+        concat!(
+            r#"{"PixelWidth":{"type":"long","value":794},"PixelHeight":{"type":"long","value":1123},"#,
+            r#""LogicalWidth":{"type":"long","value":21001},"LogicalHeight":{"type":"long","value":29700}}"#
+        ),
+        options.to_filter_string("png").unwrap()
+    );
+
+    for pixels in [0, 16385] {
+        assert!(FilterOptions::new().pixel_width(pixels).is_err());
+        assert!(FilterOptions::new().pixel_height(pixels).is_err());
+    }
+    assert!(FilterOptions::new().dpi(0, (11906, 16838)).is_err());
+    assert!(FilterOptions::new().dpi(2401, (11906, 16838)).is_err());
+    assert!(FilterOptions::new().dpi(96, (0, 16838)).is_err());
+    // a 12 m wide page at 2400 DPI
+    assert!(FilterOptions::new().dpi(2400, (680315, 16838)).is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+#[cfg(feature = "unstable")]
+fn test_save_as_with_dpi() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+    let page = doc.get_part_page_rectangles().unwrap()[0];
+
+    let mut widths = Vec::new();
+    for dpi in [96, 300] {
+        let output_path = std::env::temp_dir().join(format!("libreoffice_rs_dpi_{dpi}.png"));
+        let options = FilterOptions::new()
+            .dpi(dpi, (page.width as i64, page.height as i64))
+            .unwrap();
+        doc.save_as_with(&output_path.display().to_string(), "png", &options)
+            .unwrap();
+        let png = std::fs::read(&output_path).unwrap();
+        let _ = std::fs::remove_file(&output_path);
+        // the width in the IHDR chunk of the PNG
+        widths.push(u32::from_be_bytes([png[16], png[17], png[18], png[19]]));
+    }
+
+    assert!(widths[0] < widths[1], "{widths:?}");
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use libreoffice_rs::{urls, FormFieldKind};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_form_fields() {
+    let doc_url = urls::local_into_abs("./test_data/form.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let fields = doc.form_fields().unwrap();
+    let names: Vec<_> = fields.iter().map(|field| field.name.as_str()).collect();
+    assert_eq!(
+        vec!["FirstName", "Subscribe", "Country", "Agree", "Size"],
+        names
+    );
+
+    assert_eq!(FormFieldKind::Text, fields[0].kind);
+    assert_eq!("Jane", fields[0].value);
+    assert!(fields[0].rect.width > 0, "{fields:?}");
+    assert_eq!(FormFieldKind::CheckBox, fields[1].kind);
+    assert_eq!("checked", fields[1].value);
+    assert_eq!(FormFieldKind::ListBox, fields[2].kind);
+    assert_eq!("Germany", fields[2].value);
+    assert_eq!(FormFieldKind::CheckBox, fields[3].kind);
+    assert_eq!("unchecked", fields[3].value);
+    assert_eq!(FormFieldKind::ListBox, fields[4].kind);
+    assert_eq!("Large", fields[4].value);
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_no_form_fields() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.form_fields().unwrap().is_empty());
+}
@@ -0,0 +1,19 @@
+mod common;
+
+use libreoffice_rs::Office;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_from_raw_borrowed() {
+    let office = common::office();
+    let lok = office.as_raw();
+    assert!(!lok.is_null());
+
+    let borrowed = unsafe { Office::from_raw_borrowed(lok) };
+    assert_eq!(lok, borrowed.as_raw());
+    assert!(borrowed.get_version_info().is_ok());
+    drop(borrowed);
+
+    // the instance is still alive
+    assert!(office.get_version_info().is_ok());
+}
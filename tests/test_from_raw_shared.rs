@@ -0,0 +1,17 @@
+use libreoffice_rs::Office;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_from_raw_shared_with_new() {
+    let office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let lok = office.as_raw();
+
+    let adopted = unsafe { Office::from_raw(lok) };
+    drop(office);
+
+    // the adopted handle keeps the instance alive
+    let office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    assert_eq!(lok, office.as_raw());
+    assert!(office.get_version_info().is_ok());
+    drop(adopted);
+}
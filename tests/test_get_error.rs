@@ -0,0 +1,23 @@
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_error_reported_once() {
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let missing = urls::remote("file:///does/not/exist.odt").unwrap();
+
+    let first = office.document_load(missing.clone()).err().unwrap();
+    assert_ne!("", first.to_string());
+    // the error was already returned by document_load
+    assert_eq!("", office.get_error());
+    assert_eq!("", office.get_error());
+
+    // the same failure again is still reported by the failing method
+    let second = office.document_load(missing).err().unwrap();
+    assert_eq!(first, second);
+    assert_eq!("", office.get_error());
+
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let _doc = office.document_load(doc_url).unwrap();
+    assert_eq!("", office.get_error());
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_hyperlinks() {
+    let doc_url = urls::local_into_abs("./test_data/hyperlinks.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let links = doc.hyperlinks().unwrap();
+
+    let targets: Vec<&str> = links.iter().map(|(target, _)| target.as_str()).collect();
+    assert_eq!(
+        vec!["https://www.libreoffice.org/", "https://www.rust-lang.org/"],
+        targets
+    );
+    assert!(links
+        .iter()
+        .all(|(_, rect)| rect.width > 0 && rect.height > 0));
+    // both links are on the same line, the first one on the left
+    assert!(links[0].1.x < links[1].1.x);
+}
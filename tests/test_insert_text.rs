@@ -0,0 +1,35 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_insert_text() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    doc.select_all().unwrap();
+    doc.insert_text("Grüße, \"world\"\n日本語").unwrap();
+
+    doc.select_all().unwrap();
+    let text = doc
+        .get_text_selection("text/plain;charset=utf-8")
+        .unwrap_or_default();
+    assert!(text.contains("Grüße, \"world\""), "{text}");
+    assert!(text.contains("日本語"), "{text}");
+    assert!(!text.contains("This is a test"), "{text}");
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_insert_text_with_nul() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.insert_text("nul\0byte").is_err());
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_is_read_only_file() {
+    let path = std::env::temp_dir().join("libreoffice-rs-read-only.odt");
+    let _ = std::fs::remove_file(&path);
+    std::fs::copy("./test_data/test.odt", &path).unwrap();
+    let mut permissions = std::fs::metadata(&path).unwrap().permissions();
+    permissions.set_readonly(true);
+    std::fs::set_permissions(&path, permissions).unwrap();
+
+    let mut office = common::office();
+    let doc_url = urls::local_into_abs(path.display().to_string()).unwrap();
+    let doc = office.document_load(doc_url).unwrap();
+    assert!(doc.is_read_only());
+
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let doc = office.document_load(doc_url).unwrap();
+    assert!(!doc.is_read_only());
+}
+
+#[test]
+#[cfg(feature = "unstable")]
+#[ignore = "requires libreoffice to run this test"]
+fn test_is_read_only_view() {
+    use libreoffice_rs::EditMode;
+
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load_readonly(doc_url).unwrap();
+
+    // read-only, yet in the normal edit mode
+    assert!(doc.is_read_only());
+    assert_eq!(EditMode::Normal, doc.get_edit_mode().unwrap());
+
+    let view = doc.get_view();
+    doc.set_view_read_only(view, false);
+    assert!(!doc.is_read_only());
+}
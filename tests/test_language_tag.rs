@@ -0,0 +1,52 @@
+use libreoffice_rs::{urls, LanguageTag, Office};
+use std::convert::TryFrom;
+
+#[test]
+fn test_valid_language_tags() {
+    for tag in [
+        "en",
+        "en-US",
+        "pt-BR",
+        "ast",
+        "es-419",
+        "sr-Latn-RS",
+        "zh-Hant-TW",
+        "ca-ES-valencia",
+        "de-CH-1901",
+    ] {
+        let language = LanguageTag::try_from(tag);
+        assert!(language.is_ok(), "{tag} was rejected");
+        assert_eq!(tag, language.unwrap().to_string());
+    }
+}
+
+#[test]
+fn test_invalid_language_tags() {
+    for tag in [
+        "",
+        "e",
+        "en_US",
+        "EN-US",
+        "en-us",
+        "english",
+        "en-",
+        "-US",
+        "sr-latn-RS",
+        "en-US ",
+        "de-CH-toolongvariant",
+    ] {
+        assert!(LanguageTag::try_from(tag).is_err(), "{tag} was accepted");
+    }
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_load_with_language() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let language = LanguageTag::try_from("de-DE").unwrap();
+
+    office
+        .document_load_with_language(doc_url, &language)
+        .unwrap();
+}
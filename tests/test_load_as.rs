@@ -0,0 +1,20 @@
+use libreoffice_rs::{urls, DocumentType, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_load_as_without_extension() {
+    let path = std::env::temp_dir().join("libreoffice_rs_load_as_no_extension");
+    std::fs::copy("./test_data/test.odt", &path).unwrap();
+    let doc_url = urls::local_as_abs(path.display().to_string()).unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+
+    let doc = office.document_load_as(doc_url.clone(), "application/vnd.oasis.opendocument.text");
+    let ty = doc.map(|doc| doc.get_document_type());
+    // dropping the document only removes its temporary link
+    assert!(path.is_file());
+    let unknown = office.document_load_as(doc_url, "application/x-unknown");
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(Ok(DocumentType::Text), ty);
+    assert!(unknown.is_err());
+}
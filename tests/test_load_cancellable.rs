@@ -0,0 +1,26 @@
+mod common;
+
+use libreoffice_rs::{urls, CancellationToken, Error};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_load_cancelled() {
+    let mut office = common::office();
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let token = CancellationToken::new();
+    token.clone().cancel();
+
+    let loaded = office.document_load_cancellable(doc_url, &token);
+    assert_eq!(Some(Error::Cancelled), loaded.err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_load_not_cancelled() {
+    let mut office = common::office();
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let token = CancellationToken::new();
+
+    let loaded = office.document_load_cancellable(doc_url, &token);
+    assert!(loaded.is_ok(), "{:?}", loaded.err());
+}
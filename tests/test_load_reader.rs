@@ -0,0 +1,25 @@
+use std::io::Cursor;
+
+use libreoffice_rs::{DocumentType, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_load_reader() {
+    let content = std::fs::read("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+
+    for hint in [
+        Some("odt"),
+        Some("application/vnd.oasis.opendocument.text"),
+        None,
+    ] {
+        let doc = office
+            .document_load_reader(Cursor::new(&content), hint)
+            .unwrap();
+        assert_eq!(DocumentType::Text, doc.get_document_type(), "{hint:?}");
+    }
+
+    assert!(office
+        .document_load_reader(Cursor::new(&content), Some("klingon"))
+        .is_err());
+}
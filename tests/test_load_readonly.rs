@@ -0,0 +1,23 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_load_readonly() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+
+    let doc = office.document_load(doc_url.clone()).unwrap();
+    assert!(!doc.is_read_only());
+    drop(doc);
+
+    let mut doc = office.document_load_readonly(doc_url).unwrap();
+    assert!(doc.is_read_only());
+
+    // edits are ignored by the read-only view
+    let _ = doc.insert_text("ignored ");
+    doc.select_all().unwrap();
+    let text = doc.selected_text().unwrap_or_default();
+    assert_eq!("This is a test", text.trim());
+}
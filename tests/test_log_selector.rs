@@ -0,0 +1,22 @@
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice built with --enable-sal-log to run this test"]
+fn test_load_with_log_selector() {
+    let log_file = std::env::temp_dir().join("libreoffice_rs_sal.log");
+    let _ = std::fs::remove_file(&log_file);
+    // the only test of its binary: LibreOffice reads the variable once, at its first log line
+    std::env::set_var("SAL_LOG_FILE", &log_file);
+
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+
+    office.set_log_selector("+WARN+INFO").unwrap();
+    office.document_load(doc_url).unwrap();
+    // restore the default selector
+    office.set_log_selector("+WARN").unwrap();
+
+    let log = std::fs::read_to_string(&log_file).unwrap_or_default();
+    let _ = std::fs::remove_file(&log_file);
+    assert!(log.lines().any(|line| line.starts_with("info:")), "{log}");
+}
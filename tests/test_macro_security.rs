@@ -0,0 +1,16 @@
+use libreoffice_rs::{urls, MacroSecurity, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_macros_disabled_on_load() {
+    // the macro of the document, bound to its opening, writes this file
+    let marker = std::path::Path::new("/tmp/libreoffice_rs_macro_ran");
+    let _ = std::fs::remove_file(marker);
+
+    let doc_url = urls::local_into_abs("./test_data/macro.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    office.set_macro_security(MacroSecurity::Disabled);
+    let _doc = office.document_load(doc_url).unwrap();
+
+    assert!(!marker.exists(), "the macro of the document ran");
+}
@@ -0,0 +1,8 @@
+#[test]
+#[cfg(target_os = "linux")]
+#[ignore = "requires libreoffice to run this test"]
+fn test_memory_usage() {
+    let office = libreoffice_rs::Office::new("/usr/lib/libreoffice/program").unwrap();
+    let memory = office.memory_usage().unwrap();
+    assert!(memory.rss_kb > 0);
+}
@@ -0,0 +1,34 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_metrics_callback() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let timings: Arc<Mutex<Vec<(String, Duration)>>> = Arc::default();
+
+    office.set_metrics_callback({
+        let timings = timings.clone();
+        move |operation, elapsed| {
+            timings
+                .lock()
+                .unwrap()
+                .push((operation.to_string(), elapsed))
+        }
+    });
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_metrics.pdf");
+    assert!(doc.save_as(&output_path.display().to_string(), "pdf", None));
+    let _ = std::fs::remove_file(&output_path);
+
+    let timings = timings.lock().unwrap();
+    let operations: Vec<_> = timings
+        .iter()
+        .map(|(operation, _)| operation.as_str())
+        .collect();
+    assert_eq!(vec!["document_load", "save_as"], operations);
+    assert!(timings.iter().all(|(_, elapsed)| *elapsed > Duration::ZERO));
+}
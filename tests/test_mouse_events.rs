@@ -0,0 +1,41 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+/// The left and top page margins of test.odt, in twips
+const MARGIN: i32 = 1134;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_click_moves_cursor() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    doc.post_uno_command(".uno:GoToEndOfDoc", "", false)
+        .unwrap();
+    let end = doc.cursor_rectangle().unwrap();
+    assert!(end.x > MARGIN + 500, "{end:?}");
+
+    // the start of the only line
+    doc.click_at(MARGIN + 10, MARGIN + 100);
+    let cursor = doc.cursor_rectangle().unwrap();
+    assert!((cursor.x - MARGIN).abs() < 100, "{cursor:?}");
+    assert_eq!(end.y, cursor.y);
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_double_click_selects_word() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    // the first word, "This"
+    doc.double_click_at(MARGIN + 100, MARGIN + 100);
+    assert_eq!("This", doc.selected_text().unwrap().trim());
+}
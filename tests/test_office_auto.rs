@@ -0,0 +1,25 @@
+use libreoffice_rs::{Error, Office};
+
+#[test]
+fn test_new_with_empty_install_dir() {
+    let empty_dir = std::env::temp_dir().join("libreoffice_rs_empty_program_dir");
+    std::fs::create_dir_all(&empty_dir).unwrap();
+
+    let err = Office::new(&empty_dir.display().to_string())
+        .err()
+        .expect("initialized from an empty directory");
+    match &err {
+        Error::InstallationNotFound { searched } => assert_eq!(&empty_dir, searched),
+        other => panic!("unexpected error {other:?}"),
+    }
+    assert!(err.to_string().contains("program"), "{err}");
+    let _ = std::fs::remove_dir(&empty_dir);
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_install_path() {
+    let office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    assert_eq!("/usr/lib/libreoffice/program", office.install_path());
+    assert_eq!(office.install_path(), office.clone().install_path());
+}
@@ -0,0 +1,15 @@
+use libreoffice_rs::{Office, LIBREOFFICE_PROGRAM_ENV};
+
+#[test]
+fn test_new_auto_with_bogus_env_var() {
+    let bogus = std::env::temp_dir().join("libreoffice_rs_no_such_program_dir");
+    // the only test of its binary: no other test reads the environment meanwhile
+    std::env::set_var(LIBREOFFICE_PROGRAM_ENV, &bogus);
+
+    let err = Office::new_auto()
+        .err()
+        .expect("initialized from a bogus path");
+    let msg = err.to_string();
+    assert!(msg.contains(LIBREOFFICE_PROGRAM_ENV), "{msg}");
+    assert!(msg.contains(&bogus.display().to_string()), "{msg}");
+}
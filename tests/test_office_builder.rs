@@ -0,0 +1,61 @@
+mod common;
+
+use libreoffice_rs::{LibreOfficeKitOptionalFeatures, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_builder_sets_features() {
+    let _shared = common::office();
+    let office = Office::builder()
+        .feature(LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD)
+        .feature(LibreOfficeKitOptionalFeatures::LOK_FEATURE_NO_TILED_ANNOTATIONS)
+        .build(common::INSTALL_PATH)
+        .unwrap();
+
+    let features = office.get_optional_features();
+    assert_ne!(
+        0,
+        features & LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD as u64
+    );
+    assert_ne!(
+        0,
+        features & LibreOfficeKitOptionalFeatures::LOK_FEATURE_NO_TILED_ANNOTATIONS as u64
+    );
+    assert_eq!(
+        0,
+        features & LibreOfficeKitOptionalFeatures::LOK_FEATURE_RANGE_HEADERS as u64
+    );
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_builder_rejects_profile_once_initialized() {
+    let _office = common::office();
+    let profile = std::env::temp_dir().join("libreoffice_rs_builder_profile");
+
+    let built = Office::builder()
+        .user_profile(&profile)
+        .build(common::INSTALL_PATH);
+    assert!(built.is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_builder_rejects_temp_dir_once_initialized() {
+    let _office = common::office();
+    let tmpdir = std::env::var_os("TMPDIR");
+
+    let built = Office::builder()
+        .temp_dir(std::env::temp_dir())
+        .build(common::INSTALL_PATH);
+    assert!(built.is_err());
+    assert_eq!(tmpdir, std::env::var_os("TMPDIR"));
+}
+
+#[test]
+fn test_builder_rejects_missing_temp_dir() {
+    let built = Office::builder()
+        .temp_dir("/does/not/exist")
+        .build("/usr/lib/libreoffice/program");
+    assert!(built.is_err());
+}
@@ -0,0 +1,17 @@
+use libreoffice_rs::{Error, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_new_after_drop_fails() {
+    let office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let clone = office.clone();
+    drop(office);
+    // the clone keeps the instance alive
+    drop(Office::new("/usr/lib/libreoffice/program").unwrap());
+    drop(clone);
+
+    let err = Office::new("/usr/lib/libreoffice/program")
+        .err()
+        .expect("initialized LibreOfficeKit again");
+    assert_eq!(Error::ReinitializationUnsupported, err);
+}
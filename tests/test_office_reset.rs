@@ -0,0 +1,29 @@
+use libreoffice_rs::{urls, LibreOfficeKitOptionalFeatures, Office};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_reset_unregisters_callback() {
+    let doc_url = urls::local_into_abs("./test_data/test_password.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let called = Arc::new(AtomicBool::new(false));
+
+    office
+        .set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])
+        .unwrap();
+    office
+        .register_callback({
+            let called = called.clone();
+            move |_, _| called.store(true, Ordering::Release)
+        })
+        .unwrap();
+
+    office.reset();
+    // without the password feature, the load fails instead of requesting a password
+    assert!(office.document_load(doc_url).is_err());
+    assert!(!called.load(Ordering::Acquire), "a stale callback fired");
+
+    office.reset();
+    assert_eq!("", office.get_error());
+}
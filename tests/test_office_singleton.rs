@@ -0,0 +1,32 @@
+use libreoffice_rs::{Error, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_conflicting_second_init() {
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+
+    // a directory that looks like another installation
+    let other_dir = std::env::temp_dir().join("libreoffice_rs_other_program_dir");
+    std::fs::create_dir_all(&other_dir).unwrap();
+    for lib in ["libsofficeapp.so", "libsofficeapp.dylib", "sofficeapp.dll"] {
+        std::fs::write(other_dir.join(lib), b"").unwrap();
+    }
+
+    let err = Office::new(&other_dir.display().to_string())
+        .err()
+        .expect("initialized LibreOfficeKit twice");
+    let _ = std::fs::remove_dir_all(&other_dir);
+    match &err {
+        Error::AlreadyInitialized { install_path } => assert_eq!(
+            std::path::Path::new("/usr/lib/libreoffice/program"),
+            install_path
+        ),
+        other => panic!("unexpected error {other:?}"),
+    }
+
+    // the same installation shares the instance
+    let mut shared = Office::new("/usr/lib/libreoffice/program/").unwrap();
+    assert_eq!("", shared.get_error());
+    drop(shared);
+    assert_eq!("", office.get_error());
+}
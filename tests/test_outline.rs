@@ -0,0 +1,41 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::{urls, OutlineEntry};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_outline_headings() {
+    let doc_url = urls::local_into_abs("./test_data/headings.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let entry = |level, title: &str, page| OutlineEntry {
+        level,
+        title: title.to_string(),
+        page,
+    };
+    assert_eq!(
+        vec![
+            entry(1, "Introduction", 1),
+            entry(2, "Scope & goals", 1),
+            // after a page break
+            entry(1, "Results", 2),
+        ],
+        doc.outline().unwrap()
+    );
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_outline_sheets() {
+    let doc_url = urls::local_into_abs("./test_data/three_sheets.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let outline = doc.outline().unwrap();
+    let titles: Vec<_> = outline.iter().map(|entry| entry.title.as_str()).collect();
+    assert_eq!(vec!["Summary", "Data", "Notes"], titles);
+    assert_eq!(3, outline[2].page);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+fn page_count(path: &str) -> i32 {
+    let doc_url = urls::local_into_abs(path).unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.page_count().unwrap()
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_page_count_text() {
+    assert_eq!(2, page_count("./test_data/two_pages.odt"));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_page_count_spreadsheet() {
+    assert_eq!(3, page_count("./test_data/three_sheets.ods"));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_page_count_presentation() {
+    assert_eq!(3, page_count("./test_data/three_slides.odp"));
+}
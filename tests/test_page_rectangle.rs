@@ -0,0 +1,18 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_page_rectangle() {
+    let doc_url = urls::local_into_abs("./test_data/two_pages.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let doc = office.document_load(doc_url).unwrap();
+    let pages = doc.get_part_page_rectangles().unwrap();
+
+    assert_eq!(2, pages.len());
+    assert_eq!(Some(pages[0]), doc.page_rectangle(0));
+    assert_eq!(Some(pages[1]), doc.page_rectangle(1));
+    assert!(pages[0].y < pages[1].y);
+    assert_eq!(None, doc.page_rectangle(2));
+}
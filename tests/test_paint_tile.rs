@@ -0,0 +1,78 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_paint_tile_into_with_stride() {
+    const WIDTH: i32 = 64;
+    const HEIGHT: i32 = 32;
+    const PADDING: usize = 40;
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    // the top left corner of the page, where the text starts
+    let mut packed = vec![0u8; (WIDTH * HEIGHT * 4) as usize];
+    doc.paint_tile(&mut packed, WIDTH, HEIGHT, 1000, 1000, 3840, 1920)
+        .unwrap();
+
+    let row_len = WIDTH as usize * 4;
+    let stride = row_len + PADDING;
+    let mut strided = vec![0xAB; stride * HEIGHT as usize];
+    doc.paint_tile_into(&mut strided, stride, WIDTH, HEIGHT, 1000, 1000, 3840, 1920)
+        .unwrap();
+
+    for row in 0..HEIGHT as usize {
+        let start = row * stride;
+        assert_eq!(
+            &packed[row * row_len..(row + 1) * row_len],
+            &strided[start..start + row_len],
+            "row {row} differs"
+        );
+        assert!(
+            strided[start + row_len..start + stride]
+                .iter()
+                .all(|b| *b == 0xAB),
+            "the padding after row {row} was overwritten"
+        );
+    }
+
+    assert!(doc
+        .paint_tile_into(&mut strided, row_len - 4, WIDTH, HEIGHT, 0, 0, 3840, 1920)
+        .is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_visible_area_full() {
+    const SIZE: i32 = 64;
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+    doc.set_visible_area_full();
+
+    let (width, height) = doc.get_document_size();
+    let mut buffer = vec![0u8; (SIZE * SIZE * 4) as usize];
+    doc.paint_tile(&mut buffer, SIZE, SIZE, 0, 0, width as i32, height as i32)
+        .unwrap();
+    assert!(buffer.iter().any(|b| *b != 0), "the painted tile is empty");
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_zoom() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    doc.set_zoom(200).unwrap();
+    doc.set_zoom(100).unwrap();
+    assert!(doc.set_zoom(0).is_err());
+    assert!(doc.set_zoom(5000).is_err());
+}
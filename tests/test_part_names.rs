@@ -0,0 +1,67 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::{urls, Error};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_part_names() {
+    let mut office = common::office();
+
+    for path in ["./test_data/three_sheets.ods", "./test_data/test.odt"] {
+        let doc_url = urls::local_into_abs(path).unwrap();
+        let doc = office.document_load(doc_url).unwrap();
+        let names = doc.part_names();
+
+        assert_eq!(doc.get_parts() as usize, names.len());
+        for (part, name) in names.iter().enumerate() {
+            assert_eq!(
+                &doc.get_part_name(part as i32).unwrap().unwrap_or_default(),
+                name
+            );
+        }
+    }
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_part_out_of_range() {
+    let doc_url = urls::local_into_abs("./test_data/three_sheets.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    for index in [3, -1] {
+        match doc.set_part(index) {
+            Err(Error::PartOutOfRange { index: i, count }) => assert_eq!((index, 3), (i, count)),
+            other => panic!("unexpected result {other:?}"),
+        }
+        match doc.get_part_name(index) {
+            Err(Error::PartOutOfRange { index: i, count }) => assert_eq!((index, 3), (i, count)),
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+    assert_eq!(0, doc.get_part());
+
+    doc.set_part(2).unwrap();
+    assert_eq!(2, doc.get_part());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_part_name() {
+    let doc_url = urls::local_into_abs("./test_data/three_sheets.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.set_part_name(0, "Totals").unwrap();
+    assert_eq!(Some("Totals".to_string()), doc.get_part_name(0).unwrap());
+    assert_eq!(0, doc.get_part());
+
+    // the name of another sheet
+    assert!(doc.set_part_name(1, "Totals").is_err());
+    assert!(matches!(
+        doc.set_part_name(3, "Other"),
+        Err(Error::PartOutOfRange { .. })
+    ));
+}
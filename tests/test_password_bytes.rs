@@ -0,0 +1,59 @@
+#![cfg(feature = "zeroize")]
+
+mod common;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use libreoffice_rs::{urls, CallbackType, LibreOfficeKitOptionalFeatures};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_password_bytes() {
+    let doc_url = urls::local_into_abs("./test_data/test_password.odt").unwrap();
+    let password_was_set = AtomicBool::new(false);
+    let mut office = common::office();
+    office
+        .set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])
+        .unwrap();
+
+    office
+        .register_callback_with_office({
+            let doc_url = doc_url.clone();
+            move |office, ty, _| {
+                if ty == CallbackType::DocumentPassword as i32
+                    && !password_was_set.swap(true, Ordering::AcqRel)
+                {
+                    office
+                        .set_document_password_bytes(doc_url.clone(), b"test")
+                        .unwrap();
+                }
+            }
+        })
+        .unwrap();
+    let mut _doc = office.document_load(doc_url).unwrap();
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_password_bytes_not_utf8() {
+    let doc_url = urls::local_into_abs("./test_data/test_password.odt").unwrap();
+    let mut office = common::office();
+
+    // Latin-1 "tést", which LibreOffice can't decode as UTF-8
+    let password: &[u8] = &[b't', 0xe9, b's', b't'];
+
+    assert!(office
+        .set_document_password_bytes(doc_url, password)
+        .is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_password_bytes_nul() {
+    let doc_url = urls::local_into_abs("./test_data/test_password.odt").unwrap();
+    let mut office = common::office();
+
+    assert!(office
+        .set_document_password_bytes(doc_url, b"te\0st")
+        .is_err());
+}
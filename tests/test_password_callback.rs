@@ -1,6 +1,8 @@
+mod common;
+
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use libreoffice_rs::{urls, LibreOfficeKitOptionalFeatures, Office};
+use libreoffice_rs::{urls, CallbackType, LibreOfficeKitOptionalFeatures};
 
 #[test]
 #[ignore = "requires libreoffice to run this test"]
@@ -8,7 +10,7 @@ fn test_password_callback() {
     let doc_url = urls::local_into_abs("./test_data/test_password.odt").unwrap();
     let password = "test";
     let password_was_set = AtomicBool::new(false);
-    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let office = &mut *common::office();
     office
         .set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])
         .unwrap();
@@ -32,3 +34,84 @@ fn test_password_callback() {
         .unwrap();
     let mut _doc = office.document_load(doc_url).unwrap();
 }
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_password_retry_limit() {
+    let doc_url = urls::local_into_abs("./test_data/test_password.odt").unwrap();
+    let mut office = common::office();
+    office
+        .set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])
+        .unwrap();
+    office.set_password_retry_limit(1);
+
+    office
+        .register_callback_with_office({
+            let doc_url = doc_url.clone();
+            move |office, _, _| {
+                office
+                    .set_document_password(doc_url.clone(), "wrong_password")
+                    .unwrap();
+            }
+        })
+        .unwrap();
+
+    let started = std::time::Instant::now();
+    assert!(office.document_load(doc_url).is_err());
+    assert!(started.elapsed() < std::time::Duration::from_secs(30));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_password_callback_with_office_ref() {
+    let doc_url = urls::local_into_abs("./test_data/test_password.odt").unwrap();
+    let mut office = common::office();
+    office
+        .set_optional_features([LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD])
+        .unwrap();
+
+    office
+        .register_callback_with_office({
+            let doc_url = doc_url.clone();
+            move |office, ty, _| {
+                if ty == CallbackType::DocumentPassword as std::os::raw::c_int {
+                    office
+                        .set_document_password(doc_url.clone(), "test")
+                        .unwrap();
+                }
+            }
+        })
+        .unwrap();
+
+    let _doc = office.document_load(doc_url).unwrap();
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_modify_password_read_only() {
+    let doc_url = urls::local_into_abs("./test_data/test_modify_password.odt").unwrap();
+    let mut office = common::office();
+    office
+        .set_optional_features([
+            LibreOfficeKitOptionalFeatures::LOK_FEATURE_DOCUMENT_PASSWORD_TO_MODIFY,
+        ])
+        .unwrap();
+
+    let requested = std::rc::Rc::new(AtomicBool::new(false));
+    office
+        .register_callback_with_office({
+            let doc_url = doc_url.clone();
+            let requested = requested.clone();
+            move |office, ty, _| {
+                if ty == CallbackType::DocumentPasswordToModify as std::os::raw::c_int {
+                    requested.store(true, Ordering::Release);
+                    // no password: continue loading read-only rather than aborting
+                    office.set_modify_password(doc_url.clone(), None).unwrap();
+                }
+            }
+        })
+        .unwrap();
+
+    let _doc = office.document_load(doc_url).unwrap();
+    assert!(requested.load(Ordering::Acquire));
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_post_uno_command_with_nul() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.post_uno_command(".uno:Select\0All", "", false).is_err());
+    assert!(doc
+        .post_uno_command(".uno:SelectAll", "{\0}", false)
+        .is_err());
+    doc.post_uno_command(".uno:SelectAll", "", false).unwrap();
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::{urls, CellRange};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_print_ranges() {
+    let doc_url = urls::local_into_abs("./test_data/print_ranges.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.set_part(0).unwrap();
+    assert_eq!(
+        vec![CellRange::new(0, 0, 2, 9)],
+        doc.print_ranges().unwrap()
+    );
+
+    doc.set_part(1).unwrap();
+    assert_eq!(
+        vec![CellRange::new(1, 1, 3, 3), CellRange::new(5, 5, 5, 5)],
+        doc.print_ranges().unwrap()
+    );
+
+    doc.set_part(2).unwrap();
+    assert!(doc.print_ranges().unwrap().is_empty());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_print_ranges_of_text_document() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.print_ranges().is_err());
+}
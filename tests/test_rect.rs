@@ -0,0 +1,79 @@
+use libreoffice_rs::Rect;
+
+#[test]
+fn test_parse_rect() {
+    assert_eq!(
+        Rect::new(1418, 1418, 3968, 276),
+        "1418, 1418, 3968, 276".parse().unwrap()
+    );
+    assert!("1418, 1418, 3968".parse::<Rect>().is_err());
+    assert!("a, b, c, d".parse::<Rect>().is_err());
+}
+
+#[test]
+fn test_parse_rect_list() {
+    let rects = Rect::parse_list("0, 0, 10, 10; 5, 5, 10, 10;").unwrap();
+    assert_eq!(
+        vec![Rect::new(0, 0, 10, 10), Rect::new(5, 5, 10, 10)],
+        rects
+    );
+    assert!(Rect::parse_list("").unwrap().is_empty());
+    assert!(Rect::parse_list("EMPTY").unwrap().is_empty());
+    assert!(Rect::parse_list("0, 0, 10; 1, 1, 1, 1").is_err());
+}
+
+#[test]
+fn test_rect_overlapping() {
+    let a = Rect::new(0, 0, 10, 10);
+    let b = Rect::new(5, 5, 10, 10);
+
+    assert_eq!(Rect::new(0, 0, 15, 15), a.union(&b));
+    assert_eq!(Some(Rect::new(5, 5, 5, 5)), a.intersection(&b));
+    assert_eq!(a.intersection(&b), b.intersection(&a));
+    assert!(!a.contains(&b));
+    assert!(a.union(&b).contains(&a));
+    assert!(a.union(&b).contains(&b));
+    assert!(a.contains(&Rect::new(2, 2, 3, 3)));
+    assert!(a.contains(&a));
+}
+
+#[test]
+fn test_rect_adjacent() {
+    let a = Rect::new(0, 0, 10, 10);
+    let b = Rect::new(10, 0, 10, 10);
+
+    assert_eq!(Rect::new(0, 0, 20, 10), a.union(&b));
+    assert_eq!(None, a.intersection(&b));
+    assert!(!a.contains(&b));
+}
+
+#[test]
+fn test_rect_disjoint() {
+    let a = Rect::new(0, 0, 10, 10);
+    let b = Rect::new(20, 30, 5, 5);
+
+    assert_eq!(Rect::new(0, 0, 25, 35), a.union(&b));
+    assert_eq!(None, a.intersection(&b));
+    assert!(!a.contains(&b));
+    assert!(!b.contains(&a));
+}
+
+#[test]
+fn test_rect_empty() {
+    let a = Rect::new(0, 0, 10, 10);
+
+    assert!(Rect::default().is_empty());
+    assert!(Rect::new(5, 5, 0, 10).is_empty());
+    assert!(Rect::new(5, 5, 10, -1).is_empty());
+    assert!(!a.is_empty());
+    assert_eq!(a, a.union(&Rect::new(50, 50, 0, 0)));
+    assert_eq!(a, Rect::default().union(&a));
+    assert!(!a.contains(&Rect::new(5, 5, 0, 0)));
+    assert_eq!(None, a.intersection(&Rect::new(5, 5, 0, 0)));
+}
+
+#[test]
+fn test_rect_union_does_not_overflow() {
+    let huge = Rect::new(0, 0, i32::MAX, i32::MAX);
+    assert_eq!(huge, huge.union(&Rect::new(100, 100, i32::MAX, 10)));
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "image")]
+
+mod common;
+
+use libreoffice_rs::{urls, OutputFormat};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_pages_to_images() {
+    let doc_url = urls::local_into_abs("./test_data/two_pages.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let dir = std::env::temp_dir().join("libreoffice_rs_export_pages");
+
+    let paths = doc
+        .export_pages_to_images(&dir, OutputFormat::Png, Some(48))
+        .unwrap();
+    let all_exist = paths.iter().all(|path| path.is_file());
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert_eq!(2, paths.len());
+    assert!(all_exist);
+    assert!(paths[0].ends_with("page-1.png"));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_pages_to_unsupported_format() {
+    let doc_url = urls::local_into_abs("./test_data/two_pages.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc
+        .export_pages_to_images(&std::env::temp_dir(), OutputFormat::Pdf, None)
+        .is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_render_page_to_png() {
+    let doc_url = urls::local_into_abs("./test_data/two_pages.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let path = std::env::temp_dir().join("libreoffice_rs_preview.png");
+
+    doc.render_page_to_png(1, &path, Some(48)).unwrap();
+    let png = std::fs::read(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+    assert!(doc.render_page_to_png(2, &path, Some(48)).is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_export_contact_sheet() {
+    let doc_url = urls::local_into_abs("./test_data/four_slides.odp").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let sheet = doc.export_contact_sheet(2, 120).unwrap();
+    assert_eq!((240, 240), sheet.dimensions());
+
+    assert!(doc.export_contact_sheet(0, 120).is_err());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_render_at_default_resolution() {
+    let doc_url = urls::local_into_abs("./test_data/four_slides.odp").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let dpi = doc.get_document_type().default_render_dpi();
+
+    let pages = doc.render_all_pages(None).unwrap();
+    assert_eq!(
+        doc.render_all_pages(Some(dpi)).unwrap()[0].dimensions(),
+        pages[0].dimensions()
+    );
+
+    let tile_px = doc.get_document_type().default_tile_size();
+    let tiles = doc.render_grid_parallel(None, None, 2).unwrap();
+    assert!(tiles
+        .iter()
+        .all(|tile| tile.image.width() <= tile_px && tile.image.height() <= tile_px));
+    assert_eq!(tile_px, tiles[0].image.width().max(tiles[0].image.height()));
+}
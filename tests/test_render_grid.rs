@@ -0,0 +1,40 @@
+#![cfg(feature = "image")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_render_grid_parallel_conversion_matches_serial() {
+    let doc_url = urls::local_into_abs("./test_data/two_pages.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let serial = doc.render_grid_parallel(Some(256), Some(96), 1).unwrap();
+    let converted_in_parallel = doc.render_grid_parallel(Some(256), Some(96), 4).unwrap();
+
+    let (width, height) = doc.get_document_size_px(96);
+    let columns = width.div_ceil(256);
+    let rows = height.div_ceil(256);
+    assert_eq!((columns * rows) as usize, converted_in_parallel.len());
+    assert_eq!(serial.len(), converted_in_parallel.len());
+    for (serial, parallel) in serial.iter().zip(&converted_in_parallel) {
+        assert_eq!((serial.column, serial.row), (parallel.column, parallel.row));
+        assert_eq!(serial.area, parallel.area);
+        assert_eq!(serial.image, parallel.image);
+    }
+    let last = converted_in_parallel.last().unwrap();
+    assert_eq!((columns - 1, rows - 1), (last.column, last.row));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_render_grid_without_threads() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.render_grid_parallel(Some(256), Some(96), 0).is_err());
+    assert!(doc.render_grid_parallel(Some(0), Some(96), 4).is_err());
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_replace_all() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    assert_eq!(1, doc.replace_all("TEST", "success", false, false).unwrap());
+    assert_eq!(0, doc.replace_all("SUCCESS", "test", true, false).unwrap());
+    assert_eq!(1, doc.replace_all("Th(is)", "Wh$1", true, true).unwrap());
+
+    doc.select_all().unwrap();
+    let text = doc
+        .get_text_selection("text/plain;charset=utf-8")
+        .unwrap_or_default();
+    assert!(text.contains("Whis is a success"), "{text}");
+}
@@ -0,0 +1,18 @@
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_returning_url_round_trips() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_returning_url.pdf");
+    let output_url = urls::local_as_abs(output_path.display().to_string()).unwrap();
+
+    let saved_url = doc.save_as_returning_url(&output_url, "pdf", None).unwrap();
+    let saved_path = saved_url.to_file_path();
+    let expected_path = std::fs::canonicalize(&output_path).unwrap();
+    let _ = std::fs::remove_file(&output_path);
+
+    assert_eq!(Some(expected_path), saved_path);
+}
@@ -0,0 +1,26 @@
+use libreoffice_rs::{urls, Error, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_no_overwrite_existing_file() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_no_overwrite.pdf");
+    let output_url = urls::local_as_abs(output_path.display().to_string()).unwrap();
+    std::fs::write(&output_path, b"existing").unwrap();
+
+    for target in [output_path.display().to_string(), output_url.to_string()] {
+        match doc.save_as_no_overwrite(&target, "pdf", None) {
+            Err(Error::FileExists { path }) => assert_eq!(output_path, path),
+            other => panic!("{target} was not guarded: {other:?}"),
+        }
+    }
+    assert_eq!(b"existing".to_vec(), std::fs::read(&output_path).unwrap());
+
+    std::fs::remove_file(&output_path).unwrap();
+    doc.save_as_no_overwrite(&output_url.to_string(), "pdf", None)
+        .unwrap();
+    assert!(output_path.exists());
+    let _ = std::fs::remove_file(&output_path);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_with_warnings() {
+    // the HTML export of Writer warns that it leaves out the Basic macros of the document
+    let doc_url = urls::local_into_abs("./test_data/macro.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let output_path = std::env::temp_dir().join("libreoffice_rs_warnings.html");
+
+    let warnings = doc
+        .save_as_with_warnings(&output_path.display().to_string(), "html", None)
+        .unwrap();
+    let saved = output_path.is_file();
+    let _ = std::fs::remove_file(&output_path);
+    assert!(saved);
+    assert!(!warnings.is_empty());
+    assert!(
+        warnings.iter().all(|warning| !warning.is_empty()),
+        "{warnings:?}"
+    );
+
+    assert!(doc
+        .save_as_with_warnings("/nonexistent/dir/out.html", "html", None)
+        .is_err());
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_with_backup_restores_on_failure() {
+    let target = std::env::temp_dir().join("libreoffice_rs_backup_failure.odt");
+    let backup = std::env::temp_dir().join("libreoffice_rs_backup_failure.odt.bak");
+    std::fs::write(&target, b"original content").unwrap();
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    // no export filter is named like this, the save fails
+    let saved = doc.save_as_with_backup(&target.display().to_string(), "notaformat", None);
+    let content = std::fs::read(&target);
+    let backup_left = backup.exists();
+    let _ = std::fs::remove_file(&target);
+
+    assert!(saved.is_err());
+    assert_eq!(b"original content".to_vec(), content.unwrap());
+    assert!(!backup_left);
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_save_as_with_backup_keeps_backup() {
+    let target = std::env::temp_dir().join("libreoffice_rs_backup_success.odt");
+    let backup = std::env::temp_dir().join("libreoffice_rs_backup_success.odt.bak");
+    std::fs::write(&target, b"original content").unwrap();
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let saved = doc.save_as_with_backup(&target.display().to_string(), "odt", None);
+    let backup_content = std::fs::read(&backup);
+    let saved_size = std::fs::metadata(&target).map(|metadata| metadata.len());
+    let _ = std::fs::remove_file(&target);
+    let _ = std::fs::remove_file(&backup);
+
+    assert!(saved.is_ok(), "{:?}", saved.err());
+    assert_eq!(b"original content".to_vec(), backup_content.unwrap());
+    assert!(saved_size.unwrap() > 0);
+}
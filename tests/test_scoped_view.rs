@@ -0,0 +1,46 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_with_view_restores_after_panic() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let view = doc.get_view();
+    let other_view = doc.create_view();
+    doc.set_view(view);
+
+    assert_eq!(other_view, doc.with_view(other_view, |doc| doc.get_view()));
+    assert_eq!(view, doc.get_view());
+
+    let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        doc.with_view(other_view, |_| panic!("failed in the other view"))
+    }));
+    assert!(panicked.is_err());
+    assert_eq!(view, doc.get_view());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_with_part_restores_after_panic() {
+    let doc_url = urls::local_into_abs("./test_data/three_sheets.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.set_part(1).unwrap();
+
+    assert_eq!(2, doc.with_part(2, |doc| doc.get_part()).unwrap());
+    assert_eq!(1, doc.get_part());
+
+    let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        doc.with_part(0, |_| panic!("failed in the first sheet"))
+    }));
+    assert!(panicked.is_err());
+    assert_eq!(1, doc.get_part());
+
+    assert!(doc.with_part(3, |doc| doc.get_part()).is_err());
+    assert_eq!(1, doc.get_part());
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_select_all_selection_rectangles() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.initialize_for_rendering("");
+    doc.select_all().unwrap();
+
+    let rects = doc.get_selection_rectangles();
+    assert!(
+        !rects.is_empty(),
+        "no selection rectangles after select-all"
+    );
+    assert!(rects.iter().all(|r| r.width > 0 && r.height > 0));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_select_all_selected_text() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.select_all().unwrap();
+
+    let text = doc.selected_text().unwrap_or_default();
+    assert_eq!("This is a test", text.trim());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_select_all_selected_html() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.select_all().unwrap();
+
+    let html = doc.selected_html().expect("no HTML selection");
+    assert!(html.contains('<'), "{html}");
+    assert!(html.contains("This is a test"), "{html}");
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_select_all_and_deselect() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.select_all().unwrap();
+    let text = doc.selected_text().unwrap_or_default();
+    assert!(!text.trim().is_empty());
+
+    doc.deselect();
+    let text = doc.selected_text().unwrap_or_default();
+    assert!(text.is_empty(), "{text}");
+}
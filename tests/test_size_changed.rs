@@ -0,0 +1,29 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office};
+use std::sync::{Arc, Mutex};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_insert_text_changes_size() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let sizes = Arc::new(Mutex::new(Vec::new()));
+
+    doc.initialize_for_rendering("");
+    let (_, initial_height) = doc.get_document_size();
+    doc.on_size_changed({
+        let sizes = sizes.clone();
+        move |width, height| sizes.lock().unwrap().push((width, height))
+    });
+
+    // enough paragraphs to need another page
+    doc.post_uno_command(".uno:GoToEndOfDoc", "", false)
+        .unwrap();
+    doc.insert_text(&"line\n".repeat(100)).unwrap();
+
+    let sizes = sizes.lock().unwrap();
+    assert!(!sizes.is_empty(), "the size change wasn't reported");
+    assert!(sizes.last().unwrap().1 > initial_height);
+}
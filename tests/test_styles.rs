@@ -0,0 +1,43 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::{urls, StyleFamily, UnoArgs};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_apply_style() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+    doc.initialize_for_rendering("");
+
+    let styles = doc.list_styles(StyleFamily::Paragraph).unwrap();
+    assert!(
+        styles.iter().any(|style| style == "Heading 1"),
+        "{styles:?}"
+    );
+    assert!(doc.list_styles(StyleFamily::Cell).unwrap().is_empty());
+    assert!(doc.outline().unwrap().is_empty());
+
+    doc.post_uno_command(".uno:SelectAll", UnoArgs::new(), false)
+        .unwrap();
+    doc.apply_style("Heading 1", StyleFamily::Paragraph)
+        .unwrap();
+
+    let outline = doc.outline().unwrap();
+    assert!(!outline.is_empty());
+    assert_eq!(1, outline[0].level);
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_apply_missing_style() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc
+        .apply_style("No Such Style", StyleFamily::Paragraph)
+        .is_err());
+}
@@ -0,0 +1,31 @@
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_builder_temp_dir() {
+    let temp_dir = std::env::temp_dir().join("libreoffice_rs_temp_dir");
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    let mut office = Office::builder()
+        .temp_dir(&temp_dir)
+        .build("/usr/lib/libreoffice/program")
+        .unwrap();
+
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let mut pdf = Vec::new();
+    doc.export("pdf", None, &mut pdf).unwrap();
+    assert!(pdf.starts_with(b"%PDF"));
+
+    let canonical = std::fs::canonicalize(&temp_dir).unwrap();
+    assert_eq!(Some(canonical.into_os_string()), std::env::var_os("TMPDIR"));
+    // LibreOffice keeps its temporary files in a `lu*.tmp` directory of its own
+    let has_lu_dir = std::fs::read_dir(&temp_dir).unwrap().any(|entry| {
+        let entry = entry.unwrap();
+        entry.file_name().to_string_lossy().starts_with("lu") && entry.path().is_dir()
+    });
+    assert!(
+        has_lu_dir,
+        "LibreOffice wrote no temporary files to {temp_dir:?}"
+    );
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "tracing")]
+
+use libreoffice_rs::{urls, Office};
+use tracing_test::traced_test;
+
+#[test]
+#[traced_test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_document_load_span() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let _doc = office.document_load(doc_url.clone()).unwrap();
+
+    assert!(logs_contain("libreoffice{operation=\"document_load\""));
+    assert!(logs_contain(&format!("url={doc_url}")));
+    assert!(logs_contain("document_load finished elapsed_ms="));
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::{urls, TrackedChangeKind};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_tracked_changes() {
+    let doc_url = urls::local_into_abs("./test_data/tracked_changes.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    let changes = doc.tracked_changes().unwrap();
+    assert_eq!(2, changes.len(), "{changes:?}");
+    assert_eq!(TrackedChangeKind::Insertion, changes[0].kind);
+    assert_eq!("Jane Reviewer", changes[0].author);
+    assert!(changes[0].date.starts_with("2023-05-04"), "{changes:?}");
+    assert_eq!(TrackedChangeKind::Deletion, changes[1].kind);
+    assert_eq!("John Editor", changes[1].author);
+
+    doc.accept_all_changes().unwrap();
+    assert!(doc.tracked_changes().unwrap().is_empty());
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_reject_all_changes() {
+    let doc_url = urls::local_into_abs("./test_data/tracked_changes.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    doc.reject_all_changes().unwrap();
+    assert!(doc.tracked_changes().unwrap().is_empty());
+}
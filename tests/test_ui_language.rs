@@ -0,0 +1,20 @@
+#![cfg(feature = "unstable")]
+
+use std::convert::TryFrom;
+
+use libreoffice_rs::{urls, LanguageTag, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_ui_language() {
+    // needs the German translations, e.g. the libreoffice-l10n-de package
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    office
+        .set_ui_language(&LanguageTag::try_from("de-DE").unwrap())
+        .unwrap();
+
+    // the name of the sheet of a new spreadsheet is generated in the UI language
+    let doc_url = urls::internal("private:factory/scalc").unwrap();
+    let doc = office.document_load(doc_url).unwrap();
+    assert_eq!(Some("Tabelle1".to_string()), doc.get_part_name(0).unwrap());
+}
@@ -0,0 +1,65 @@
+use libreoffice_rs::units;
+
+#[test]
+fn test_twips_to_px() {
+    assert_eq!(96, units::twips_to_px(1440, 96));
+    assert_eq!(72, units::twips_to_px(1440, 72));
+    assert_eq!(300, units::twips_to_px(1440, 300));
+    // US letter, 8.5in x 11in
+    assert_eq!(816, units::twips_to_px(12240, 96));
+    assert_eq!(1056, units::twips_to_px(15840, 96));
+    // A4, 11906 x 16838 twips, rounded down
+    assert_eq!(793, units::twips_to_px(11906, 96));
+    assert_eq!(1122, units::twips_to_px(16838, 96));
+    assert_eq!(0, units::twips_to_px(14, 96));
+    assert_eq!(0, units::twips_to_px(-1440, 96));
+    assert_eq!(0, units::twips_to_px(1440, 0));
+}
+
+#[test]
+fn test_zoom_to_dpi() {
+    assert_eq!(units::SCREEN_DPI, units::zoom_to_dpi(100));
+    assert_eq!(48, units::zoom_to_dpi(50));
+    assert_eq!(144, units::zoom_to_dpi(150));
+
+    // the same tile of twips covers twice the pixels at 200%
+    let tile_twips = 3840;
+    let px_100 = units::twips_to_px(tile_twips, units::zoom_to_dpi(100));
+    let px_200 = units::twips_to_px(tile_twips, units::zoom_to_dpi(200));
+    assert_eq!(256, px_100);
+    assert_eq!(2 * px_100, px_200);
+}
+
+#[test]
+fn test_pixel_to_twip() {
+    // US letter shown at 96 DPI
+    let letter = (12240, 15840);
+    assert_eq!((0, 0), units::pixel_to_twip((0, 0), (816, 1056), letter));
+    assert_eq!(
+        (12240, 15840),
+        units::pixel_to_twip((816, 1056), (816, 1056), letter)
+    );
+    assert_eq!((15, 15), units::pixel_to_twip((1, 1), (816, 1056), letter));
+    // a canvas half the size
+    assert_eq!(
+        (6120, 7920),
+        units::pixel_to_twip((204, 264), (408, 528), letter)
+    );
+    assert_eq!((0, 0), units::pixel_to_twip((10, 10), (0, 0), letter));
+    assert_eq!((0, 0), units::twip_to_pixel((10, 10), (816, 1056), (0, 0)));
+}
+
+#[test]
+fn test_pixel_twip_round_trip() {
+    let a4 = (11906, 16838);
+    for canvas in [(793, 1122), (300, 425), (1587, 2245)] {
+        for px in [(0, 0), (1, 1), (17, 523), (canvas.0 - 1, canvas.1 - 1)] {
+            let twip = units::pixel_to_twip(px, canvas, a4);
+            assert_eq!(px, units::twip_to_pixel(twip, canvas, a4), "{canvas:?}");
+        }
+    }
+
+    // outside of the canvas, e.g. a drag past its edge
+    let twip = units::pixel_to_twip((-20, 900), (300, 425), a4);
+    assert_eq!((-20, 900), units::twip_to_pixel(twip, (300, 425), a4));
+}
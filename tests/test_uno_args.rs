@@ -0,0 +1,61 @@
+use libreoffice_rs::UnoArgs;
+
+#[test]
+fn test_uno_args_empty() {
+    let args = UnoArgs::new();
+    assert!(args.is_empty());
+    assert_eq!("{}", args.as_ref());
+}
+
+#[test]
+fn test_uno_args_string() {
+    let args = UnoArgs::new().string("Text", "Say \"hi\"\n");
+    assert_eq!(
+        r#"{"Text":{"type":"string","value":"Say \"hi\"\n"}}"#,
+        args.as_ref()
+    );
+}
+
+#[test]
+fn test_uno_args_long() {
+    let args = UnoArgs::new().long("Color", -1);
+    assert_eq!(r#"{"Color":{"type":"long","value":-1}}"#, args.as_ref());
+}
+
+#[test]
+fn test_uno_args_short() {
+    let args = UnoArgs::new().short("Angle", 45);
+    assert_eq!(r#"{"Angle":{"type":"short","value":45}}"#, args.as_ref());
+}
+
+#[test]
+fn test_uno_args_boolean() {
+    let args = UnoArgs::new().boolean("Backward", true);
+    assert_eq!(
+        r#"{"Backward":{"type":"boolean","value":true}}"#,
+        args.as_ref()
+    );
+}
+
+#[test]
+fn test_uno_args_double() {
+    let args = UnoArgs::new()
+        .double("Zoom", 1.5)
+        .double("Nothing", f64::NAN);
+    assert_eq!(
+        r#"{"Zoom":{"type":"double","value":1.5},"Nothing":{"type":"double","value":null}}"#,
+        args.to_string()
+    );
+}
+
+#[test]
+fn test_uno_args_keep_order() {
+    let args = UnoArgs::new()
+        .string("SearchItem.SearchString", "cat")
+        .long("SearchItem.Command", 3);
+    assert!(!args.is_empty());
+    assert_eq!(
+        r#"{"SearchItem.SearchString":{"type":"string","value":"cat"},"SearchItem.Command":{"type":"long","value":3}}"#,
+        args.as_ref()
+    );
+}
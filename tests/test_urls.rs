@@ -0,0 +1,27 @@
+use libreoffice_rs::urls;
+
+#[test]
+fn test_internal_urls() {
+    for uri in [
+        "private:stream",
+        "private:factory/swriter",
+        "vnd.sun.star.Package:Pictures/image.png",
+        "vnd.sun.star.pkg://file%3A%2F%2F%2Ftmp%2Ftest.odt/content.xml",
+        "VND.SUN.STAR.expand:$BRAND_BASE_DIR/program",
+        "macro:///Standard.Module1.Main",
+    ] {
+        let url = urls::internal(uri).unwrap();
+        assert_eq!(uri, url.to_string());
+        assert_eq!(None, url.to_file_path());
+    }
+
+    for uri in [
+        "",
+        "private:",
+        "file:///tmp/test.odt",
+        "http://google.com",
+        "/tmp/test.odt",
+    ] {
+        assert!(urls::internal(uri).is_err(), "{uri} was accepted");
+    }
+}
@@ -0,0 +1,39 @@
+use libreoffice_rs::VersionInfo;
+
+fn version_info(version: &str, extension: &str) -> VersionInfo {
+    VersionInfo::from_json(&format!(
+        r#"{{"ProductName": "LibreOffice", "ProductVersion": "{version}", "ProductExtension": "{extension}", "BuildId": "abc"}}"#
+    ))
+    .unwrap()
+}
+
+#[test]
+fn test_parse_version_info() {
+    let info = version_info("7.5", ".2.2");
+    assert_eq!("LibreOffice", info.product_name);
+    assert_eq!("abc", info.build_id);
+    assert_eq!("7.5.2.2", info.full_version());
+    assert_eq!((7, 5), info.major_minor().unwrap());
+    assert_eq!((24, 2), version_info("24.2", ".0.3").major_minor().unwrap());
+    assert_eq!(
+        (7, 6),
+        version_info("7.6", ".0.0.alpha1").major_minor().unwrap()
+    );
+    assert_eq!((7, 0), version_info("7", "").major_minor().unwrap());
+
+    assert!(version_info("", "").major_minor().is_err());
+    assert!(VersionInfo::from_json("not json").is_err());
+}
+
+#[test]
+fn test_version_comparison() {
+    let info = version_info("7.5", ".2.2");
+    assert!(info.is_at_least(6, 0).unwrap());
+    assert!(info.is_at_least(7, 0).unwrap());
+    assert!(info.is_at_least(7, 5).unwrap());
+    assert!(!info.is_at_least(7, 6).unwrap());
+    assert!(!info.is_at_least(8, 0).unwrap());
+    assert!(!info.is_at_least(24, 2).unwrap());
+    assert!(version_info("24.2", ".0.3").is_at_least(7, 6).unwrap());
+    assert!(version_info("7.10", "").is_at_least(7, 9).unwrap());
+}
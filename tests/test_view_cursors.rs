@@ -0,0 +1,25 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_view_cursors_of_two_views() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    // start capturing callbacks in the first view
+    doc.initialize_for_rendering("");
+    assert!(doc.view_cursors().is_empty());
+
+    let first = doc.create_view();
+    let second = doc.create_view();
+    for _ in 0..2 {
+        doc.post_uno_command(".uno:GoToEndOfDoc", "", false)
+            .unwrap();
+    }
+
+    let views: Vec<i32> = doc.view_cursors().iter().map(|(view, _)| *view).collect();
+    assert_eq!(vec![first, second], views);
+}
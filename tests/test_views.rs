@@ -0,0 +1,33 @@
+#![cfg(feature = "unstable")]
+
+use libreoffice_rs::{urls, Office, ViewInfo};
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_views_with_authors() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = Office::new("/usr/lib/libreoffice/program").unwrap();
+    let mut doc = office.document_load(doc_url).unwrap();
+    let first = doc.get_view();
+
+    let alice = doc.create_view_with_author("Alice");
+    let bob = doc.create_view_with_author("Bob");
+
+    assert_eq!(
+        vec![
+            ViewInfo {
+                id: first,
+                author: None
+            },
+            ViewInfo {
+                id: alice,
+                author: Some("Alice".into())
+            },
+            ViewInfo {
+                id: bob,
+                author: Some("Bob".into())
+            },
+        ],
+        doc.views()
+    );
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "unstable")]
+
+mod common;
+
+use libreoffice_rs::urls;
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_watermark() {
+    let doc_url = urls::local_into_abs("./test_data/test.odt").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.set_watermark("CONFIDENTIAL", 101).is_err());
+    doc.set_watermark("CONFIDENTIAL", 50).unwrap();
+
+    let output_path = std::env::temp_dir().join("libreoffice_rs_watermark.pdf");
+    assert!(doc.save_as(&output_path.display().to_string(), "pdf", None));
+    let pdf = std::fs::read(&output_path).unwrap();
+    let _ = std::fs::remove_file(&output_path);
+    assert!(pdf.starts_with(b"%PDF"));
+}
+
+#[test]
+#[ignore = "requires libreoffice to run this test"]
+fn test_set_watermark_spreadsheet() {
+    let doc_url = urls::local_into_abs("./test_data/three_sheets.ods").unwrap();
+    let mut office = common::office();
+    let mut doc = office.document_load(doc_url).unwrap();
+
+    assert!(doc.set_watermark("CONFIDENTIAL", 50).is_err());
+}